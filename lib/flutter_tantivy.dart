@@ -1,5 +1,6 @@
 library;
 
+export 'src/rust/api/error.dart';
 export 'src/rust/api/simple.dart';
 export 'src/rust/api/tantivy_api.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
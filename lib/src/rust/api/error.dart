@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `io_error_kind`, `new`, `syntax_error_position`, `tantivy_error_kind`, `tantivy_io_error`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`
+
+class TantivyError implements FrbException {
+  final TantivyErrorKind kind;
+  final String message;
+  final BigInt? position;
+
+  const TantivyError({
+    required this.kind,
+    required this.message,
+    this.position,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode ^ position.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TantivyError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message &&
+          position == other.position;
+}
+
+enum TantivyErrorKind {
+  notInitialized,
+  alreadyOpen,
+  lockBusy,
+  queryParse,
+  batchInProgress,
+  schemaMismatch,
+  fieldNotFound,
+  invalidArgument,
+  corrupted,
+  expired,
+  cancelled,
+  versionConflict,
+  encryptionKey,
+  quotaExceeded,
+  permissionDenied,
+  io,
+  internal,
+}
@@ -4,74 +4,1965 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TantivyApi`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `add_metadata_field`, `add_parent_field`, `add_version_field`, `alive_doc_addresses`, `already_open`, `analyze_terms`, `analyzer`, `analyzer`, `api_from_index`, `areas`, `as_tuple`, `base_builder`, `best_score`, `bucket_start`, `build_query`, `build_schema`, `builtin_tokenizer_manager`, `canonical_dir`, `check_file_fields`, `check_tokenizers`, `check`, `chunk_parent_field`, `civil_from_days`, `clear`, `commit_if_due`, `commit_pending_locked`, `commit_pending`, `commit_writer`, `compare_file`, `conflicting_option`, `create_writer`, `create`, `days_from_civil`, `decode_vector`, `default_schema`, `detect_language_tokenizers`, `detected_lang`, `detected_langs`, `directory_files`, `distance`, `doc_address`, `empty`, `encode_vector`, `encryption_key`, `end_batch`, `enforce_quota`, `exists_query`, `field_def`, `field`, `file_documents_under`, `file_size_and_modified`, `fill_language_fields`, `fill_shadow_fields`, `format_ip`, `from_index_option`, `fuse_rankings`, `fuzzy_query`, `get_index`, `highlights`, `index_option`, `index_settings`, `insert_index`, `into_document`, `into_sorted_hits`, `is_geo_field`, `is_lock_failure`, `language_field_name`, `language_fields`, `local_path`, `memory_index`, `merge`, `new`, `new`, `new`, `new`, `next_bucket`, `next_version`, `not_initialized`, `numeric_column`, `open_api`, `open_directory`, `open_writer`, `parse_ip`, `parse_json_doc`, `parse_query_in_fields`, `parse_query_lenient`, `parse_query_with`, `parse_query`, `phrase_query`, `prefix_query`, `protected_data_wait`, `push`, `query_parser_config`, `query_parser_for`, `query_parser`, `query_settings_with`, `range_query`, `range_term`, `rank_groups`, `read`, `record_pending`, `register_index`, `register_memory_index`, `register_shared`, `register`, `reload_for_search`, `remove_old_dir`, `reregister_index`, `revert_index_dir`, `same_fields`, `search_bm25`, `search_page`, `search_sorted`, `search_weighted`, `search`, `searcher`, `sentence_end`, `shadow_field_name`, `shadow_fields`, `shared_api`, `similarity`, `size_bytes`, `sort_geo_hits`, `split_chunks`, `stamp_version`, `start`, `storage_backend`, `storage_directory`, `stored_fields`, `stored_version`, `swap_index_dir`, `sync`, `tantivy_language`, `term_query`, `text_indexing`, `text_search_field`, `text_tokenizer`, `to_document_with_fields`, `to_document`, `to_search_results_with_fields`, `to_search_results`, `to_tantivy_doc`, `tokenizer_manager`, `tokenizer_name`, `validate`, `with_filter`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DateBuckets`, `DateHistogramCollector`, `DateHistogramSegmentCollector`, `FileChange`, `GeoCollector`, `GeoHit`, `GeoSegmentCollector`, `GroupCollector`, `GroupHits`, `GroupSegmentCollector`, `Highlighter`, `KnnCollector`, `KnnSegmentCollector`, `LanguageFields`, `Operation`, `PendingChanges`, `QuerySettings`, `TantivyApi`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `collect`, `collect`, `collect`, `collect`, `drop`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `for_segment`, `for_segment`, `for_segment`, `for_segment`, `harvest`, `harvest`, `harvest`, `harvest`, `merge_fruits`, `merge_fruits`, `merge_fruits`, `merge_fruits`, `requires_scoring`, `requires_scoring`, `requires_scoring`, `requires_scoring`
 
-void initTantivy({required String dirPath}) =>
-    RustLib.instance.api.crateApiTantivyApiInitTantivy(dirPath: dirPath);
+void initTantivy({
+  required String dirPath,
+  TextLanguage? language,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiInitTantivy(
+  dirPath: dirPath,
+  language: language,
+  writerOptions: writerOptions,
+);
+
+void initTantivyWithSchema({
+  required String dirPath,
+  required List<FieldDef> fields,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiInitTantivyWithSchema(
+  dirPath: dirPath,
+  fields: fields,
+  writerOptions: writerOptions,
+);
+
+void registerTokenizer({required String name, required TokenizerConfig config}) =>
+    RustLib.instance.api.crateApiTantivyApiRegisterTokenizer(
+      name: name,
+      config: config,
+    );
+
+void openIndex({
+  required String name,
+  required String dirPath,
+  TextLanguage? language,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiOpenIndex(
+  name: name,
+  dirPath: dirPath,
+  language: language,
+  writerOptions: writerOptions,
+);
+
+void openIndexWithSchema({
+  required String name,
+  required String dirPath,
+  required List<FieldDef> fields,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiOpenIndexWithSchema(
+  name: name,
+  dirPath: dirPath,
+  fields: fields,
+  writerOptions: writerOptions,
+);
+
+void initTantivyInMemory({TextLanguage? language, WriterOptions? writerOptions}) =>
+    RustLib.instance.api.crateApiTantivyApiInitTantivyInMemory(
+      language: language,
+      writerOptions: writerOptions,
+    );
+
+void initTantivyInMemoryWithSchema({
+  required List<FieldDef> fields,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiInitTantivyInMemoryWithSchema(
+  fields: fields,
+  writerOptions: writerOptions,
+);
+
+void openIndexInMemory({
+  required String name,
+  TextLanguage? language,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiOpenIndexInMemory(
+  name: name,
+  language: language,
+  writerOptions: writerOptions,
+);
+
+void openIndexInMemoryWithSchema({
+  required String name,
+  required List<FieldDef> fields,
+  WriterOptions? writerOptions,
+}) => RustLib.instance.api.crateApiTantivyApiOpenIndexInMemoryWithSchema(
+  name: name,
+  fields: fields,
+  writerOptions: writerOptions,
+);
+
+Future<void> closeIndex({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiCloseIndex(indexName: indexName);
+
+List<String> listIndexes() =>
+    RustLib.instance.api.crateApiTantivyApiListIndexes();
+
+void configureQueryParser({
+  required QueryParserConfig config,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiConfigureQueryParser(
+  config: config,
+  indexName: indexName,
+);
+
+Future<void> reopenIndex({required String dirPath, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiReopenIndex(
+      dirPath: dirPath,
+      indexName: indexName,
+    );
+
+Future<void> addDocument({required Document doc, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiAddDocument(
+      doc: doc,
+      indexName: indexName,
+    );
+
+Future<List<SearchResult>> searchDocuments({
+  required String query,
+  required BigInt topK,
+  List<FieldBoost>? fields,
+  SearchQuery? filter,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocuments(
+  query: query,
+  topK: topK,
+  fields: fields,
+  filter: filter,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsWithParser({
+  required String query,
+  required BigInt topK,
+  required QueryParserConfig config,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithParser(
+  query: query,
+  topK: topK,
+  config: config,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsWithReturnFields({
+  required String query,
+  required BigInt topK,
+  required List<String> returnFields,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithReturnFields(
+  query: query,
+  topK: topK,
+  returnFields: returnFields,
+  indexName: indexName,
+);
+
+Future<List<HitHandle>> searchHitHandles({
+  required String query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchHitHandles(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<Document>> fetchDocuments({
+  required List<HitHandle> handles,
+  List<String>? returnFields,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiFetchDocuments(
+  handles: handles,
+  returnFields: returnFields,
+  indexName: indexName,
+);
+
+Stream<List<SearchResult>> searchDocumentsStream({
+  required String query,
+  required BigInt maxResults,
+  BigInt? chunkSize,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsStream(
+  query: query,
+  maxResults: maxResults,
+  chunkSize: chunkSize,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchWithTags({
+  required String query,
+  required String tagField,
+  required List<String> requiredTags,
+  required List<String> excludedTags,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchWithTags(
+  query: query,
+  tagField: tagField,
+  requiredTags: requiredTags,
+  excludedTags: excludedTags,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<LenientSearchResponse> searchDocumentsLenient({
+  required String query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsLenient(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+String escapeQuery({required String text}) =>
+    RustLib.instance.api.crateApiTantivyApiEscapeQuery(text: text);
+
+Future<List<SearchResult>> searchDocumentsWithSnippets({
+  required String query,
+  required BigInt topK,
+  String? snippetField,
+  BigInt? maxChars,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithSnippets(
+  query: query,
+  topK: topK,
+  snippetField: snippetField,
+  maxChars: maxChars,
+  indexName: indexName,
+);
+
+Future<FacetSearchResponse> searchWithFacets({
+  required String query,
+  required String facetField,
+  String? facetRoot,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchWithFacets(
+  query: query,
+  facetField: facetField,
+  facetRoot: facetRoot,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<SearchResponse> searchDocumentsWithCount({
+  required String query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithCount(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<BigInt> countDocuments({required String query, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiCountDocuments(
+      query: query,
+      indexName: indexName,
+    );
+
+Future<List<SearchResult>> knnSearch({
+  required String field,
+  required List<double> vector,
+  required BigInt topK,
+  VectorMetric? metric,
+  SearchQuery? filter,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiKnnSearch(
+  field: field,
+  vector: vector,
+  topK: topK,
+  metric: metric,
+  filter: filter,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> hybridSearch({
+  required String query,
+  required String vectorField,
+  required List<double> vector,
+  required double alpha,
+  required BigInt topK,
+  FusionMethod? fusion,
+  SearchQuery? filter,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiHybridSearch(
+  query: query,
+  vectorField: vectorField,
+  vector: vector,
+  alpha: alpha,
+  topK: topK,
+  fusion: fusion,
+  filter: filter,
+  indexName: indexName,
+);
+
+Future<List<GeoSearchResult>> geoSearch({
+  required String query,
+  required String field,
+  required GeoFilter filter,
+  required bool sortByDistance,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiGeoSearch(
+  query: query,
+  field: field,
+  filter: filter,
+  sortByDistance: sortByDistance,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<GroupedSearchResponse> searchGrouped({
+  required String query,
+  required String groupField,
+  required BigInt topGroups,
+  required BigInt hitsPerGroup,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchGrouped(
+  query: query,
+  groupField: groupField,
+  topGroups: topGroups,
+  hitsPerGroup: hitsPerGroup,
+  indexName: indexName,
+);
+
+Future<String> aggregate({
+  required String query,
+  required String aggregations,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAggregate(
+  query: query,
+  aggregations: aggregations,
+  indexName: indexName,
+);
+
+Future<List<DateBucket>> dateHistogram({
+  required String query,
+  required String dateField,
+  required DateInterval interval,
+  int? utcOffsetMinutes,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiDateHistogram(
+  query: query,
+  dateField: dateField,
+  interval: interval,
+  utcOffsetMinutes: utcOffsetMinutes,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsSorted({
+  required String query,
+  required String sortField,
+  required bool ascending,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsSorted(
+  query: query,
+  sortField: sortField,
+  ascending: ascending,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<SearchResponse> matchAll({
+  required BigInt topK,
+  BigInt? offset,
+  String? sortField,
+  bool? ascending,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiMatchAll(
+  topK: topK,
+  offset: offset,
+  sortField: sortField,
+  ascending: ascending,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsWithBm25({
+  required String query,
+  required BigInt topK,
+  required Bm25Params params,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithBm25(
+  query: query,
+  topK: topK,
+  params: params,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsWithPopularity({
+  required String query,
+  required String popularityField,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithPopularity(
+  query: query,
+  popularityField: popularityField,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchDocumentsWithRecency({
+  required String query,
+  required String dateField,
+  required PlatformInt64 halfLifeMs,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsWithRecency(
+  query: query,
+  dateField: dateField,
+  halfLifeMs: halfLifeMs,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<SearchResponse> searchDocumentsPaged({
+  required String query,
+  required BigInt offset,
+  required BigInt limit,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchDocumentsPaged(
+  query: query,
+  offset: offset,
+  limit: limit,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> fuzzySearch({
+  required String field,
+  required String term,
+  required int maxDistance,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiFuzzySearch(
+  field: field,
+  term: term,
+  maxDistance: maxDistance,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> prefixSearch({
+  required String query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiPrefixSearch(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> findSimilar({
+  required String docId,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiFindSimilar(
+  docId: docId,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> regexSearch({
+  required String field,
+  required String pattern,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiRegexSearch(
+  field: field,
+  pattern: pattern,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<String> explainScore({
+  required String query,
+  required String docId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiExplainScore(
+  query: query,
+  docId: docId,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchWithQuery({
+  required SearchQuery query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchWithQuery(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> phraseSearch({
+  required String field,
+  required String phrase,
+  required int slop,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiPhraseSearch(
+  field: field,
+  phrase: phrase,
+  slop: slop,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<SearchResult>> searchByDateRange({
+  required String field,
+  PlatformInt64? from,
+  PlatformInt64? to,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchByDateRange(
+  field: field,
+  from: from,
+  to: to,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<String>> suggestTerms({
+  required String prefix,
+  required String field,
+  required BigInt limit,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSuggestTerms(
+  prefix: prefix,
+  field: field,
+  limit: limit,
+  indexName: indexName,
+);
+
+Document? getDocumentById({required String id, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiGetDocumentById(
+      id: id,
+      indexName: indexName,
+    );
+
+Future<List<String>> listDocumentIds({
+  required BigInt offset,
+  required BigInt limit,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiListDocumentIds(
+  offset: offset,
+  limit: limit,
+  indexName: indexName,
+);
+
+Future<List<Document>> getAllDocuments({
+  required BigInt offset,
+  required BigInt limit,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiGetAllDocuments(
+  offset: offset,
+  limit: limit,
+  indexName: indexName,
+);
+
+Future<BigInt> exportDocuments({
+  required String path,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiExportDocuments(
+  path: path,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Future<void> backupIndex({required String destPath, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiBackupIndex(
+      destPath: destPath,
+      indexName: indexName,
+    );
+
+bool indexExists({required String path}) =>
+    RustLib.instance.api.crateApiTantivyApiIndexExists(path: path);
+
+Future<IndexHealth> validateIndex({
+  required String path,
+  Uint8List? encryptionKey,
+}) => RustLib.instance.api.crateApiTantivyApiValidateIndex(
+  path: path,
+  encryptionKey: encryptionKey,
+);
+
+Future<void> forceUnlock({required String path}) =>
+    RustLib.instance.api.crateApiTantivyApiForceUnlock(path: path);
+
+Future<RepairReport> repairIndex({
+  required String path,
+  BigInt? operationId,
+  Uint8List? encryptionKey,
+}) => RustLib.instance.api.crateApiTantivyApiRepairIndex(
+  path: path,
+  operationId: operationId,
+  encryptionKey: encryptionKey,
+);
+
+Future<void> restoreIndex({required String srcPath, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiRestoreIndex(
+      srcPath: srcPath,
+      indexName: indexName,
+    );
+
+Stream<BatchProgress> reindexWithSchema({
+  required List<FieldDef> fields,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiReindexWithSchema(
+  fields: fields,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Stream<BatchProgress> addSchemaFields({
+  required List<FieldDef> fields,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddSchemaFields(
+  fields: fields,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Future<void> updateDocument({required Document doc, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiUpdateDocument(
+      doc: doc,
+      indexName: indexName,
+    );
+
+Future<BigInt> updateDocumentIfVersion({
+  required Document doc,
+  required BigInt expectedVersion,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiUpdateDocumentIfVersion(
+  doc: doc,
+  expectedVersion: expectedVersion,
+  indexName: indexName,
+);
+
+BigInt getDocumentVersion({required String id, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiGetDocumentVersion(
+      id: id,
+      indexName: indexName,
+    );
+
+Future<void> deleteDocument({required String id, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiDeleteDocument(
+      id: id,
+      indexName: indexName,
+    );
+
+Future<void> addDocumentJson({required String json, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiAddDocumentJson(
+      json: json,
+      indexName: indexName,
+    );
+
+Future<void> indexFile({
+  required String path,
+  required String docId,
+  String? mimeType,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiIndexFile(
+  path: path,
+  docId: docId,
+  mimeType: mimeType,
+  indexName: indexName,
+);
+
+List<FieldDef> fileSchemaFields() =>
+    RustLib.instance.api.crateApiTantivyApiFileSchemaFields();
+
+Future<List<MarkdownHeading>> addMarkdownDocument({
+  required Document doc,
+  String? headingsField,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddMarkdownDocument(
+  doc: doc,
+  headingsField: headingsField,
+  indexName: indexName,
+);
+
+Future<void> addDocumentsJsonBatch({
+  required List<String> jsons,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddDocumentsJsonBatch(
+  jsons: jsons,
+  indexName: indexName,
+);
+
+Future<void> addDocumentsBatch({
+  required List<Document> docs,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddDocumentsBatch(
+  docs: docs,
+  indexName: indexName,
+);
+
+Future<BigInt> addDocumentChunked({
+  required Document doc,
+  BigInt? maxChars,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddDocumentChunked(
+  doc: doc,
+  maxChars: maxChars,
+  indexName: indexName,
+);
+
+Future<void> deleteChunkedDocument({
+  required String parentId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiDeleteChunkedDocument(
+  parentId: parentId,
+  indexName: indexName,
+);
+
+Future<List<ChunkSearchResult>> searchChunked({
+  required String query,
+  required BigInt topK,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSearchChunked(
+  query: query,
+  topK: topK,
+  indexName: indexName,
+);
+
+Future<List<UpsertResult>> upsertDocumentsBatch({
+  required List<Document> docs,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiUpsertDocumentsBatch(
+  docs: docs,
+  indexName: indexName,
+);
+
+Stream<BatchProgress> addDocumentsBatchStreamed({
+  required List<Document> docs,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiAddDocumentsBatchStreamed(
+  docs: docs,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Stream<ImportProgress> importDocuments({
+  required String path,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiImportDocuments(
+  path: path,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Stream<DirectoryIndexProgress> indexDirectory({
+  required String path,
+  required List<String> globs,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiIndexDirectory(
+  path: path,
+  globs: globs,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Stream<DirectorySyncProgress> syncDirectory({
+  required String path,
+  required List<String> globs,
+  BigInt? operationId,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiSyncDirectory(
+  path: path,
+  globs: globs,
+  operationId: operationId,
+  indexName: indexName,
+);
+
+Future<void> deleteDocumentsBatch({
+  required List<String> ids,
+  String? indexName,
+}) => RustLib.instance.api.crateApiTantivyApiDeleteDocumentsBatch(
+  ids: ids,
+  indexName: indexName,
+);
+
+Future<BigInt> deleteByQuery({required String query, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiDeleteByQuery(
+      query: query,
+      indexName: indexName,
+    );
+
+List<FieldDef> getSchema({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiGetSchema(indexName: indexName);
+
+Future<IndexStats> getIndexStats({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiGetIndexStats(indexName: indexName);
+
+Future<void> clearIndex({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiClearIndex(indexName: indexName);
+
+Future<void> optimizeIndex({BigInt? operationId, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiOptimizeIndex(
+      operationId: operationId,
+      indexName: indexName,
+    );
+
+void commit({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiCommit(indexName: indexName);
+
+void rollback({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiRollback(indexName: indexName);
+
+void refreshReader({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiRefreshReader(indexName: indexName);
+
+void setAutoCommit({AutoCommitPolicy? policy, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiSetAutoCommit(
+      policy: policy,
+      indexName: indexName,
+    );
+
+BigInt beginBatch({String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiBeginBatch(indexName: indexName);
+
+void commitBatch({required BigInt batchId, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiCommitBatch(
+      batchId: batchId,
+      indexName: indexName,
+    );
+
+void abortBatch({required BigInt batchId, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiAbortBatch(
+      batchId: batchId,
+      indexName: indexName,
+    );
+
+BigInt newOperationId() =>
+    RustLib.instance.api.crateApiTantivyApiNewOperationId();
+
+void cancelOperation({required BigInt operationId}) =>
+    RustLib.instance.api.crateApiTantivyApiCancelOperation(
+      operationId: operationId,
+    );
+
+Future<void> addDocumentNoCommit({required Document doc, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiAddDocumentNoCommit(
+      doc: doc,
+      indexName: indexName,
+    );
+
+Future<void> deleteDocumentNoCommit({required String id, String? indexName}) =>
+    RustLib.instance.api.crateApiTantivyApiDeleteDocumentNoCommit(
+      id: id,
+      indexName: indexName,
+    );
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TantivyIndex>>
+abstract class TantivyIndex implements RustOpaqueInterface {
+  Future<void> addDocument({required Document doc});
+
+  Future<BigInt> addDocumentChunked({required Document doc, BigInt? maxChars});
+
+  Future<void> addDocumentsBatch({required List<Document> docs});
+
+  Future<List<MarkdownHeading>> addMarkdownDocument({
+    required Document doc,
+    String? headingsField,
+  });
+
+  Future<String> aggregate({
+    required String query,
+    required String aggregations,
+  });
+
+  Future<void> close();
+
+  void commit();
+
+  Future<BigInt> countDocuments({required String query});
+
+  Future<List<DateBucket>> dateHistogram({
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+  });
+
+  Future<BigInt> deleteByQuery({required String query});
+
+  Future<void> deleteChunkedDocument({required String parentId});
+
+  Future<void> deleteDocument({required String id});
+
+  Future<void> deleteDocumentsBatch({required List<String> ids});
+
+  Future<List<GeoSearchResult>> geoSearch({
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+  });
+
+  Document? getDocumentById({required String id});
+
+  BigInt getDocumentVersion({required String id});
+
+  Future<IndexStats> getIndexStats();
+
+  List<FieldDef> getSchema();
+
+  Future<List<SearchResult>> hybridSearch({
+    required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
+    required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
+  });
+
+  Future<List<SearchResult>> knnSearch({
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+  });
+
+  String get name;
+
+  static TantivyIndex open({
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexOpen(
+    dirPath: dirPath,
+    language: language,
+    writerOptions: writerOptions,
+  );
+
+  static TantivyIndex openWithSchema({
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexOpenWithSchema(
+    dirPath: dirPath,
+    fields: fields,
+    writerOptions: writerOptions,
+  );
+
+  void refreshReader();
+
+  void rollback();
+
+  Future<List<ChunkSearchResult>> searchChunked({
+    required String query,
+    required BigInt topK,
+  });
+
+  Future<List<SearchResult>> searchDocuments({
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+  });
+
+  Future<GroupedSearchResponse> searchGrouped({
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+  });
+
+  Future<void> updateDocument({required Document doc});
+
+  Future<BigInt> updateDocumentIfVersion({
+    required Document doc,
+    required BigInt expectedVersion,
+  });
+
+  Future<List<UpsertResult>> upsertDocumentsBatch({
+    required List<Document> docs,
+  });
+}
+
+class AutoCommitPolicy {
+  final BigInt? maxPendingDocs;
+  final BigInt? maxDelayMs;
+
+  const AutoCommitPolicy({this.maxPendingDocs, this.maxDelayMs});
+
+  @override
+  int get hashCode => maxPendingDocs.hashCode ^ maxDelayMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoCommitPolicy &&
+          runtimeType == other.runtimeType &&
+          maxPendingDocs == other.maxPendingDocs &&
+          maxDelayMs == other.maxDelayMs;
+}
+
+enum BaseTokenizer {
+  simple,
+  whitespace,
+  raw,
+  cjkBigram,
+  ngram,
+  japanese,
+  chinese,
+}
+
+class BatchProgress {
+  final BigInt processed;
+  final BigInt total;
+  final bool done;
+
+  const BatchProgress({
+    required this.processed,
+    required this.total,
+    required this.done,
+  });
+
+  @override
+  int get hashCode => processed.hashCode ^ total.hashCode ^ done.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchProgress &&
+          runtimeType == other.runtimeType &&
+          processed == other.processed &&
+          total == other.total &&
+          done == other.done;
+}
+
+class Bm25Params {
+  final double? k1;
+  final double? b;
+
+  const Bm25Params({this.k1, this.b});
+
+  @override
+  int get hashCode => k1.hashCode ^ b.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Bm25Params &&
+          runtimeType == other.runtimeType &&
+          k1 == other.k1 &&
+          b == other.b;
+}
+
+class ChunkSearchResult {
+  final String parentId;
+  final BigInt chunkIndex;
+  final SearchResult chunk;
+  final BigInt matchingChunks;
+
+  const ChunkSearchResult({
+    required this.parentId,
+    required this.chunkIndex,
+    required this.chunk,
+    required this.matchingChunks,
+  });
+
+  @override
+  int get hashCode =>
+      parentId.hashCode ^
+      chunkIndex.hashCode ^
+      chunk.hashCode ^
+      matchingChunks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChunkSearchResult &&
+          runtimeType == other.runtimeType &&
+          parentId == other.parentId &&
+          chunkIndex == other.chunkIndex &&
+          chunk == other.chunk &&
+          matchingChunks == other.matchingChunks;
+}
+
+class DateBucket {
+  final PlatformInt64 startMillis;
+  final BigInt count;
+
+  const DateBucket({required this.startMillis, required this.count});
+
+  @override
+  int get hashCode => startMillis.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DateBucket &&
+          runtimeType == other.runtimeType &&
+          startMillis == other.startMillis &&
+          count == other.count;
+}
+
+enum DateInterval { hour, day, week, month, year }
+
+class DirectoryIndexProgress {
+  final BigInt indexed;
+  final BigInt skipped;
+  final BigInt total;
+  final String? path;
+  final bool done;
+
+  const DirectoryIndexProgress({
+    required this.indexed,
+    required this.skipped,
+    required this.total,
+    this.path,
+    required this.done,
+  });
+
+  @override
+  int get hashCode =>
+      indexed.hashCode ^
+      skipped.hashCode ^
+      total.hashCode ^
+      path.hashCode ^
+      done.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DirectoryIndexProgress &&
+          runtimeType == other.runtimeType &&
+          indexed == other.indexed &&
+          skipped == other.skipped &&
+          total == other.total &&
+          path == other.path &&
+          done == other.done;
+}
+
+class DirectorySyncProgress {
+  final BigInt added;
+  final BigInt updated;
+  final BigInt removed;
+  final BigInt unchanged;
+  final BigInt skipped;
+  final BigInt total;
+  final String? path;
+  final bool done;
+
+  const DirectorySyncProgress({
+    required this.added,
+    required this.updated,
+    required this.removed,
+    required this.unchanged,
+    required this.skipped,
+    required this.total,
+    this.path,
+    required this.done,
+  });
+
+  @override
+  int get hashCode =>
+      added.hashCode ^
+      updated.hashCode ^
+      removed.hashCode ^
+      unchanged.hashCode ^
+      skipped.hashCode ^
+      total.hashCode ^
+      path.hashCode ^
+      done.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DirectorySyncProgress &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          updated == other.updated &&
+          removed == other.removed &&
+          unchanged == other.unchanged &&
+          skipped == other.skipped &&
+          total == other.total &&
+          path == other.path &&
+          done == other.done;
+}
+
+enum DocstoreCompression { none, lz4, zstd }
+
+class Document {
+  final String id;
+  final String text;
+  final List<FieldValue> fields;
+  final Map<String, String> metadata;
+
+  const Document({
+    required this.id,
+    required this.text,
+    this.fields = const [],
+    this.metadata = const {},
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ text.hashCode ^ fields.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Document &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          text == other.text &&
+          fields == other.fields &&
+          metadata == other.metadata;
+}
+
+class FacetCount {
+  final String path;
+  final BigInt count;
+
+  const FacetCount({required this.path, required this.count});
+
+  @override
+  int get hashCode => path.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FacetCount &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          count == other.count;
+}
+
+class FacetSearchResponse {
+  final List<SearchResult> results;
+  final List<FacetCount> facetCounts;
 
-Future<void> addDocument({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyApiAddDocument(doc: doc);
+  const FacetSearchResponse({required this.results, required this.facetCounts});
 
-Future<List<SearchResult>> searchDocuments({
-  required String query,
-  required BigInt topK,
-}) => RustLib.instance.api.crateApiTantivyApiSearchDocuments(
-  query: query,
-  topK: topK,
-);
+  @override
+  int get hashCode => results.hashCode ^ facetCounts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FacetSearchResponse &&
+          runtimeType == other.runtimeType &&
+          results == other.results &&
+          facetCounts == other.facetCounts;
+}
+
+class FieldBoost {
+  final String field;
+  final double? boost;
+
+  const FieldBoost({required this.field, this.boost});
 
-Document? getDocumentById({required String id}) =>
-    RustLib.instance.api.crateApiTantivyApiGetDocumentById(id: id);
+  @override
+  int get hashCode => field.hashCode ^ boost.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldBoost &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          boost == other.boost;
+}
 
-Future<void> updateDocument({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyApiUpdateDocument(doc: doc);
+class FieldDef {
+  final String name;
+  final FieldType fieldType;
+  final bool stored;
+  final bool indexed;
+  final bool fast;
+  final String? tokenizer;
+  final RecordOption? recordOption;
+  final List<String> shadowTokenizers;
+  final List<TextLanguage> detectLanguages;
 
-Future<void> deleteDocument({required String id}) =>
-    RustLib.instance.api.crateApiTantivyApiDeleteDocument(id: id);
+  const FieldDef({
+    required this.name,
+    required this.fieldType,
+    this.stored = true,
+    this.indexed = true,
+    this.fast = false,
+    this.tokenizer,
+    this.recordOption,
+    this.shadowTokenizers = const [],
+    this.detectLanguages = const [],
+  });
 
-Future<void> addDocumentsBatch({required List<Document> docs}) =>
-    RustLib.instance.api.crateApiTantivyApiAddDocumentsBatch(docs: docs);
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      fieldType.hashCode ^
+      stored.hashCode ^
+      indexed.hashCode ^
+      fast.hashCode ^
+      tokenizer.hashCode ^
+      recordOption.hashCode ^
+      shadowTokenizers.hashCode ^
+      detectLanguages.hashCode;
 
-Future<void> deleteDocumentsBatch({required List<String> ids}) =>
-    RustLib.instance.api.crateApiTantivyApiDeleteDocumentsBatch(ids: ids);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldDef &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          fieldType == other.fieldType &&
+          stored == other.stored &&
+          indexed == other.indexed &&
+          fast == other.fast &&
+          tokenizer == other.tokenizer &&
+          recordOption == other.recordOption &&
+          shadowTokenizers == other.shadowTokenizers &&
+          detectLanguages == other.detectLanguages;
+}
 
-void commit() => RustLib.instance.api.crateApiTantivyApiCommit();
+enum FieldType {
+  text,
+  string,
+  i64,
+  u64,
+  f64,
+  date,
+  facet,
+  vector,
+  geoPoint,
+  ipAddr,
+  bool,
+  bytes,
+}
 
-Future<void> addDocumentNoCommit({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyApiAddDocumentNoCommit(doc: doc);
+class FieldValue {
+  final String name;
+  final String? textValue;
+  final PlatformInt64? i64Value;
+  final BigInt? u64Value;
+  final double? f64Value;
+  final PlatformInt64? dateValue;
+  final bool? boolValue;
+  final Float32List? vectorValue;
+  final GeoPoint? geoValue;
+  final Uint8List? bytesValue;
 
-Future<void> deleteDocumentNoCommit({required String id}) =>
-    RustLib.instance.api.crateApiTantivyApiDeleteDocumentNoCommit(id: id);
+  const FieldValue({
+    required this.name,
+    this.textValue,
+    this.i64Value,
+    this.u64Value,
+    this.f64Value,
+    this.dateValue,
+    this.boolValue,
+    this.vectorValue,
+    this.geoValue,
+    this.bytesValue,
+  });
 
-class Document {
-  final String id;
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      textValue.hashCode ^
+      i64Value.hashCode ^
+      u64Value.hashCode ^
+      f64Value.hashCode ^
+      dateValue.hashCode ^
+      boolValue.hashCode ^
+      vectorValue.hashCode ^
+      geoValue.hashCode ^
+      bytesValue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldValue &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          textValue == other.textValue &&
+          i64Value == other.i64Value &&
+          u64Value == other.u64Value &&
+          f64Value == other.f64Value &&
+          dateValue == other.dateValue &&
+          boolValue == other.boolValue &&
+          vectorValue == other.vectorValue &&
+          geoValue == other.geoValue &&
+          bytesValue == other.bytesValue;
+}
+
+class FileDocument {
+  final String path;
+  final BigInt size;
+  final PlatformInt64 modified;
+  final String mimeType;
+  final String contentHash;
   final String text;
 
-  const Document({required this.id, required this.text});
+  const FileDocument({
+    required this.path,
+    required this.size,
+    required this.modified,
+    required this.mimeType,
+    required this.contentHash,
+    required this.text,
+  });
+
+  static FileDocument fromDocument({required Document doc}) =>
+      RustLib.instance.api.crateApiTantivyApiFileDocumentFromDocument(doc: doc);
 
   @override
-  int get hashCode => id.hashCode ^ text.hashCode;
+  int get hashCode =>
+      path.hashCode ^
+      size.hashCode ^
+      modified.hashCode ^
+      mimeType.hashCode ^
+      contentHash.hashCode ^
+      text.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Document &&
+      other is FileDocument &&
           runtimeType == other.runtimeType &&
-          id == other.id &&
+          path == other.path &&
+          size == other.size &&
+          modified == other.modified &&
+          mimeType == other.mimeType &&
+          contentHash == other.contentHash &&
           text == other.text;
 }
 
+enum FusionMethod { reciprocalRank, weightedScore }
+
+class GeoFilter {
+  final GeoPoint? center;
+  final double? radiusMeters;
+  final GeoPoint? southWest;
+  final GeoPoint? northEast;
+
+  const GeoFilter({
+    this.center,
+    this.radiusMeters,
+    this.southWest,
+    this.northEast,
+  });
+
+  @override
+  int get hashCode =>
+      center.hashCode ^
+      radiusMeters.hashCode ^
+      southWest.hashCode ^
+      northEast.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GeoFilter &&
+          runtimeType == other.runtimeType &&
+          center == other.center &&
+          radiusMeters == other.radiusMeters &&
+          southWest == other.southWest &&
+          northEast == other.northEast;
+}
+
+class GeoPoint {
+  final double lat;
+  final double lon;
+
+  const GeoPoint({required this.lat, required this.lon});
+
+  @override
+  int get hashCode => lat.hashCode ^ lon.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GeoPoint &&
+          runtimeType == other.runtimeType &&
+          lat == other.lat &&
+          lon == other.lon;
+}
+
+class GeoSearchResult {
+  final SearchResult result;
+  final double? distanceMeters;
+
+  const GeoSearchResult({required this.result, this.distanceMeters});
+
+  @override
+  int get hashCode => result.hashCode ^ distanceMeters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GeoSearchResult &&
+          runtimeType == other.runtimeType &&
+          result == other.result &&
+          distanceMeters == other.distanceMeters;
+}
+
+class GroupedSearchResponse {
+  final BigInt totalHits;
+  final BigInt totalGroups;
+  final List<SearchGroup> groups;
+
+  const GroupedSearchResponse({
+    required this.totalHits,
+    required this.totalGroups,
+    required this.groups,
+  });
+
+  @override
+  int get hashCode =>
+      totalHits.hashCode ^ totalGroups.hashCode ^ groups.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupedSearchResponse &&
+          runtimeType == other.runtimeType &&
+          totalHits == other.totalHits &&
+          totalGroups == other.totalGroups &&
+          groups == other.groups;
+}
+
+class HighlightRange {
+  final BigInt start;
+  final BigInt end;
+  final BigInt byteStart;
+  final BigInt byteEnd;
+
+  const HighlightRange({
+    required this.start,
+    required this.end,
+    required this.byteStart,
+    required this.byteEnd,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^ end.hashCode ^ byteStart.hashCode ^ byteEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HighlightRange &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          byteStart == other.byteStart &&
+          byteEnd == other.byteEnd;
+}
+
+class HitHandle {
+  final double score;
+  final int segmentOrd;
+  final int docId;
+  final BigInt generation;
+
+  const HitHandle({
+    required this.score,
+    required this.segmentOrd,
+    required this.docId,
+    required this.generation,
+  });
+
+  @override
+  int get hashCode =>
+      score.hashCode ^
+      segmentOrd.hashCode ^
+      docId.hashCode ^
+      generation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HitHandle &&
+          runtimeType == other.runtimeType &&
+          score == other.score &&
+          segmentOrd == other.segmentOrd &&
+          docId == other.docId &&
+          generation == other.generation;
+}
+
+class ImportProgress {
+  final BigInt imported;
+  final BigInt bytesRead;
+  final BigInt totalBytes;
+  final bool done;
+
+  const ImportProgress({
+    required this.imported,
+    required this.bytesRead,
+    required this.totalBytes,
+    required this.done,
+  });
+
+  @override
+  int get hashCode =>
+      imported.hashCode ^
+      bytesRead.hashCode ^
+      totalBytes.hashCode ^
+      done.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportProgress &&
+          runtimeType == other.runtimeType &&
+          imported == other.imported &&
+          bytesRead == other.bytesRead &&
+          totalBytes == other.totalBytes &&
+          done == other.done;
+}
+
+class IndexHealth {
+  final bool exists;
+  final bool healthy;
+  final bool locked;
+  final bool open;
+  final BigInt numSegments;
+  final BigInt numDocs;
+  final List<String> missingFiles;
+  final List<String> corruptedFiles;
+  final String? error;
+
+  const IndexHealth({
+    required this.exists,
+    required this.healthy,
+    required this.locked,
+    required this.open,
+    required this.numSegments,
+    required this.numDocs,
+    required this.missingFiles,
+    required this.corruptedFiles,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      exists.hashCode ^
+      healthy.hashCode ^
+      locked.hashCode ^
+      open.hashCode ^
+      numSegments.hashCode ^
+      numDocs.hashCode ^
+      missingFiles.hashCode ^
+      corruptedFiles.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IndexHealth &&
+          runtimeType == other.runtimeType &&
+          exists == other.exists &&
+          healthy == other.healthy &&
+          locked == other.locked &&
+          open == other.open &&
+          numSegments == other.numSegments &&
+          numDocs == other.numDocs &&
+          missingFiles == other.missingFiles &&
+          corruptedFiles == other.corruptedFiles &&
+          error == other.error;
+}
+
+class IndexStats {
+  final BigInt numDocs;
+  final BigInt numDeletedDocs;
+  final BigInt numSegments;
+  final BigInt sizeBytes;
+
+  const IndexStats({
+    required this.numDocs,
+    required this.numDeletedDocs,
+    required this.numSegments,
+    required this.sizeBytes,
+  });
+
+  @override
+  int get hashCode =>
+      numDocs.hashCode ^
+      numDeletedDocs.hashCode ^
+      numSegments.hashCode ^
+      sizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IndexStats &&
+          runtimeType == other.runtimeType &&
+          numDocs == other.numDocs &&
+          numDeletedDocs == other.numDeletedDocs &&
+          numSegments == other.numSegments &&
+          sizeBytes == other.sizeBytes;
+}
+
+class LenientSearchResponse {
+  final List<SearchResult> results;
+  final List<String> warnings;
+
+  const LenientSearchResponse({required this.results, required this.warnings});
+
+  @override
+  int get hashCode => results.hashCode ^ warnings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LenientSearchResponse &&
+          runtimeType == other.runtimeType &&
+          results == other.results &&
+          warnings == other.warnings;
+}
+
+class MarkdownHeading {
+  final int level;
+  final String title;
+  final String anchor;
+  final BigInt line;
+
+  const MarkdownHeading({
+    required this.level,
+    required this.title,
+    required this.anchor,
+    required this.line,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^ title.hashCode ^ anchor.hashCode ^ line.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MarkdownHeading &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          title == other.title &&
+          anchor == other.anchor &&
+          line == other.line;
+}
+
+enum QueryKind { boolean, queryString, term, phrase, fuzzy, range, exists }
+
+class QueryParserConfig {
+  final List<FieldBoost>? defaultFields;
+  final bool? conjunctionByDefault;
+
+  const QueryParserConfig({this.defaultFields, this.conjunctionByDefault});
+
+  @override
+  int get hashCode => defaultFields.hashCode ^ conjunctionByDefault.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryParserConfig &&
+          runtimeType == other.runtimeType &&
+          defaultFields == other.defaultFields &&
+          conjunctionByDefault == other.conjunctionByDefault;
+}
+
+class RangeBound {
+  final String? textValue;
+  final PlatformInt64? i64Value;
+  final BigInt? u64Value;
+  final double? f64Value;
+  final PlatformInt64? dateValue;
+  final bool inclusive;
+
+  const RangeBound({
+    this.textValue,
+    this.i64Value,
+    this.u64Value,
+    this.f64Value,
+    this.dateValue,
+    this.inclusive = true,
+  });
+
+  @override
+  int get hashCode =>
+      textValue.hashCode ^
+      i64Value.hashCode ^
+      u64Value.hashCode ^
+      f64Value.hashCode ^
+      dateValue.hashCode ^
+      inclusive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RangeBound &&
+          runtimeType == other.runtimeType &&
+          textValue == other.textValue &&
+          i64Value == other.i64Value &&
+          u64Value == other.u64Value &&
+          f64Value == other.f64Value &&
+          dateValue == other.dateValue &&
+          inclusive == other.inclusive;
+}
+
+enum ReaderReloadPolicy { onSearch, onCommitWithDelay, manual, nearRealTime }
+
+enum RecordOption { basic, withFreqs, withFreqsAndPositions }
+
+class RepairReport {
+  final BigInt recoveredDocs;
+  final BigInt lostDocs;
+  final BigInt lostSegments;
+
+  const RepairReport({
+    required this.recoveredDocs,
+    required this.lostDocs,
+    required this.lostSegments,
+  });
+
+  @override
+  int get hashCode =>
+      recoveredDocs.hashCode ^ lostDocs.hashCode ^ lostSegments.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RepairReport &&
+          runtimeType == other.runtimeType &&
+          recoveredDocs == other.recoveredDocs &&
+          lostDocs == other.lostDocs &&
+          lostSegments == other.lostSegments;
+}
+
+class SearchGroup {
+  final String? key;
+  final BigInt totalHits;
+  final List<SearchResult> hits;
+
+  const SearchGroup({this.key, required this.totalHits, required this.hits});
+
+  @override
+  int get hashCode => key.hashCode ^ totalHits.hashCode ^ hits.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchGroup &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          totalHits == other.totalHits &&
+          hits == other.hits;
+}
+
+class SearchQuery {
+  final QueryKind kind;
+  final String? field;
+  final String? text;
+  final int? slop;
+  final int? maxDistance;
+  final RangeBound? lower;
+  final RangeBound? upper;
+  final double? boost;
+  final double? constScore;
+  final List<SearchQuery> must;
+  final List<SearchQuery> should;
+  final List<SearchQuery> mustNot;
+
+  const SearchQuery({
+    required this.kind,
+    this.field,
+    this.text,
+    this.slop,
+    this.maxDistance,
+    this.lower,
+    this.upper,
+    this.boost,
+    this.constScore,
+    this.must = const [],
+    this.should = const [],
+    this.mustNot = const [],
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      field.hashCode ^
+      text.hashCode ^
+      slop.hashCode ^
+      maxDistance.hashCode ^
+      lower.hashCode ^
+      upper.hashCode ^
+      boost.hashCode ^
+      constScore.hashCode ^
+      must.hashCode ^
+      should.hashCode ^
+      mustNot.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchQuery &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          field == other.field &&
+          text == other.text &&
+          slop == other.slop &&
+          maxDistance == other.maxDistance &&
+          lower == other.lower &&
+          upper == other.upper &&
+          boost == other.boost &&
+          constScore == other.constScore &&
+          must == other.must &&
+          should == other.should &&
+          mustNot == other.mustNot;
+}
+
+class SearchResponse {
+  final BigInt totalHits;
+  final List<SearchResult> results;
+
+  const SearchResponse({required this.totalHits, required this.results});
+
+  @override
+  int get hashCode => totalHits.hashCode ^ results.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchResponse &&
+          runtimeType == other.runtimeType &&
+          totalHits == other.totalHits &&
+          results == other.results;
+}
+
 class SearchResult {
   final double score;
   final Document doc;
+  final String? snippet;
+  final List<HighlightRange> highlights;
 
-  const SearchResult({required this.score, required this.doc});
+  const SearchResult({
+    required this.score,
+    required this.doc,
+    this.snippet,
+    required this.highlights,
+  });
 
   @override
-  int get hashCode => score.hashCode ^ doc.hashCode;
+  int get hashCode =>
+      score.hashCode ^ doc.hashCode ^ snippet.hashCode ^ highlights.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -79,5 +1970,176 @@ class SearchResult {
       other is SearchResult &&
           runtimeType == other.runtimeType &&
           score == other.score &&
-          doc == other.doc;
+          doc == other.doc &&
+          snippet == other.snippet &&
+          highlights == other.highlights;
+}
+
+enum StorageBackend { mmap, fileIo, ram }
+
+enum TextLanguage {
+  arabic,
+  danish,
+  dutch,
+  english,
+  finnish,
+  french,
+  german,
+  greek,
+  hungarian,
+  italian,
+  norwegian,
+  portuguese,
+  romanian,
+  russian,
+  spanish,
+  swedish,
+  tamil,
+  turkish,
+}
+
+class TokenizerConfig {
+  final BaseTokenizer base;
+  final bool lowercase;
+  final BigInt? maxTokenLength;
+  final TextLanguage? stopWords;
+  final List<String> customStopWords;
+  final List<List<String>> synonyms;
+  final TextLanguage? stemmer;
+  final bool asciiFolding;
+  final bool hangulJamo;
+  final BigInt? minGram;
+  final BigInt? maxGram;
+  final bool stripHtml;
+  final bool stripMarkdown;
+
+  const TokenizerConfig({
+    required this.base,
+    this.lowercase = true,
+    this.maxTokenLength,
+    this.stopWords,
+    this.customStopWords = const [],
+    this.synonyms = const [],
+    this.stemmer,
+    this.asciiFolding = false,
+    this.hangulJamo = false,
+    this.minGram,
+    this.maxGram,
+    this.stripHtml = false,
+    this.stripMarkdown = false,
+  });
+
+  @override
+  int get hashCode =>
+      base.hashCode ^
+      lowercase.hashCode ^
+      maxTokenLength.hashCode ^
+      stopWords.hashCode ^
+      customStopWords.hashCode ^
+      synonyms.hashCode ^
+      stemmer.hashCode ^
+      asciiFolding.hashCode ^
+      hangulJamo.hashCode ^
+      minGram.hashCode ^
+      maxGram.hashCode ^
+      stripHtml.hashCode ^
+      stripMarkdown.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TokenizerConfig &&
+          runtimeType == other.runtimeType &&
+          base == other.base &&
+          lowercase == other.lowercase &&
+          maxTokenLength == other.maxTokenLength &&
+          stopWords == other.stopWords &&
+          customStopWords == other.customStopWords &&
+          synonyms == other.synonyms &&
+          stemmer == other.stemmer &&
+          asciiFolding == other.asciiFolding &&
+          hangulJamo == other.hangulJamo &&
+          minGram == other.minGram &&
+          maxGram == other.maxGram &&
+          stripHtml == other.stripHtml &&
+          stripMarkdown == other.stripMarkdown;
+}
+
+enum UpsertOutcome { inserted, replaced, failed }
+
+class UpsertResult {
+  final String id;
+  final UpsertOutcome outcome;
+  final String? error;
+
+  const UpsertResult({required this.id, required this.outcome, this.error});
+
+  @override
+  int get hashCode => id.hashCode ^ outcome.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UpsertResult &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          outcome == other.outcome &&
+          error == other.error;
+}
+
+enum VectorMetric { cosine, dotProduct, euclidean }
+
+class WriterOptions {
+  final BigInt? memoryBudgetBytes;
+  final BigInt? numThreads;
+  final ReaderReloadPolicy? reloadPolicy;
+  final DocstoreCompression? docstoreCompression;
+  final BigInt? docstoreBlockSize;
+  final StorageBackend? storageBackend;
+  final BigInt? protectedDataWaitMs;
+  final Uint8List? encryptionKey;
+  final BigInt? maxIndexSizeBytes;
+  final String? evictOldestBy;
+
+  const WriterOptions({
+    this.memoryBudgetBytes,
+    this.numThreads,
+    this.reloadPolicy,
+    this.docstoreCompression,
+    this.docstoreBlockSize,
+    this.storageBackend,
+    this.protectedDataWaitMs,
+    this.encryptionKey,
+    this.maxIndexSizeBytes,
+    this.evictOldestBy,
+  });
+
+  @override
+  int get hashCode =>
+      memoryBudgetBytes.hashCode ^
+      numThreads.hashCode ^
+      reloadPolicy.hashCode ^
+      docstoreCompression.hashCode ^
+      docstoreBlockSize.hashCode ^
+      storageBackend.hashCode ^
+      protectedDataWaitMs.hashCode ^
+      encryptionKey.hashCode ^
+      maxIndexSizeBytes.hashCode ^
+      evictOldestBy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WriterOptions &&
+          runtimeType == other.runtimeType &&
+          memoryBudgetBytes == other.memoryBudgetBytes &&
+          numThreads == other.numThreads &&
+          reloadPolicy == other.reloadPolicy &&
+          docstoreCompression == other.docstoreCompression &&
+          docstoreBlockSize == other.docstoreBlockSize &&
+          storageBackend == other.storageBackend &&
+          protectedDataWaitMs == other.protectedDataWaitMs &&
+          encryptionKey == other.encryptionKey &&
+          maxIndexSizeBytes == other.maxIndexSizeBytes &&
+          evictOldestBy == other.evictOldestBy;
 }
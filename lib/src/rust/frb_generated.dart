@@ -3,6 +3,7 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/error.dart';
 import 'api/simple.dart';
 import 'api/tantivy_api.dart';
 import 'dart:async';
@@ -67,7 +68,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1834736559;
+  int get rustContentHash => 1173725171;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -78,303 +79,1091 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiTantivyApiAddDocument({required Document doc});
+  Future<void> crateApiTantivyApiTantivyIndexAddDocument({
+    required TantivyIndex that,
+    required Document doc,
+  });
 
-  Future<void> crateApiTantivyApiAddDocumentNoCommit({required Document doc});
+  Future<BigInt> crateApiTantivyApiTantivyIndexAddDocumentChunked({
+    required TantivyIndex that,
+    required Document doc,
+    BigInt? maxChars,
+  });
 
-  Future<void> crateApiTantivyApiAddDocumentsBatch({
+  Future<void> crateApiTantivyApiTantivyIndexAddDocumentsBatch({
+    required TantivyIndex that,
     required List<Document> docs,
   });
 
-  void crateApiTantivyApiCommit();
+  Future<List<MarkdownHeading>> crateApiTantivyApiTantivyIndexAddMarkdownDocument({
+    required TantivyIndex that,
+    required Document doc,
+    String? headingsField,
+  });
 
-  Future<void> crateApiTantivyApiDeleteDocument({required String id});
+  Future<String> crateApiTantivyApiTantivyIndexAggregate({
+    required TantivyIndex that,
+    required String query,
+    required String aggregations,
+  });
 
-  Future<void> crateApiTantivyApiDeleteDocumentNoCommit({required String id});
+  Future<void> crateApiTantivyApiTantivyIndexClose({
+    required TantivyIndex that,
+  });
 
-  Future<void> crateApiTantivyApiDeleteDocumentsBatch({
+  void crateApiTantivyApiTantivyIndexCommit({required TantivyIndex that});
+
+  Future<BigInt> crateApiTantivyApiTantivyIndexCountDocuments({
+    required TantivyIndex that,
+    required String query,
+  });
+
+  Future<List<DateBucket>> crateApiTantivyApiTantivyIndexDateHistogram({
+    required TantivyIndex that,
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+  });
+
+  Future<BigInt> crateApiTantivyApiTantivyIndexDeleteByQuery({
+    required TantivyIndex that,
+    required String query,
+  });
+
+  Future<void> crateApiTantivyApiTantivyIndexDeleteChunkedDocument({
+    required TantivyIndex that,
+    required String parentId,
+  });
+
+  Future<void> crateApiTantivyApiTantivyIndexDeleteDocument({
+    required TantivyIndex that,
+    required String id,
+  });
+
+  Future<void> crateApiTantivyApiTantivyIndexDeleteDocumentsBatch({
+    required TantivyIndex that,
     required List<String> ids,
   });
 
-  Document? crateApiTantivyApiGetDocumentById({required String id});
+  Future<List<GeoSearchResult>> crateApiTantivyApiTantivyIndexGeoSearch({
+    required TantivyIndex that,
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+  });
 
-  String crateApiSimpleGreet({required String name});
+  Document? crateApiTantivyApiTantivyIndexGetDocumentById({
+    required TantivyIndex that,
+    required String id,
+  });
 
-  Future<void> crateApiSimpleInitApp();
+  BigInt crateApiTantivyApiTantivyIndexGetDocumentVersion({
+    required TantivyIndex that,
+    required String id,
+  });
 
-  void crateApiTantivyApiInitTantivy({required String dirPath});
+  Future<IndexStats> crateApiTantivyApiTantivyIndexGetIndexStats({
+    required TantivyIndex that,
+  });
 
-  Future<List<SearchResult>> crateApiTantivyApiSearchDocuments({
+  List<FieldDef> crateApiTantivyApiTantivyIndexGetSchema({
+    required TantivyIndex that,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexHybridSearch({
+    required TantivyIndex that,
     required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
     required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
   });
 
-  Future<void> crateApiTantivyApiUpdateDocument({required Document doc});
-}
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexKnnSearch({
+    required TantivyIndex that,
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+  });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
+  String crateApiTantivyApiTantivyIndexName({required TantivyIndex that});
+
+  TantivyIndex crateApiTantivyApiTantivyIndexOpen({
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
   });
 
-  @override
-  Future<void> crateApiTantivyApiAddDocument({required Document doc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_document(doc, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiAddDocumentConstMeta,
-        argValues: [doc],
-        apiImpl: this,
-      ),
-    );
-  }
+  TantivyIndex crateApiTantivyApiTantivyIndexOpenWithSchema({
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiAddDocumentConstMeta =>
-      const TaskConstMeta(debugName: "add_document", argNames: ["doc"]);
+  void crateApiTantivyApiTantivyIndexRefreshReader({
+    required TantivyIndex that,
+  });
 
-  @override
-  Future<void> crateApiTantivyApiAddDocumentNoCommit({required Document doc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_document(doc, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiAddDocumentNoCommitConstMeta,
-        argValues: [doc],
-        apiImpl: this,
-      ),
-    );
-  }
+  void crateApiTantivyApiTantivyIndexRollback({required TantivyIndex that});
 
-  TaskConstMeta get kCrateApiTantivyApiAddDocumentNoCommitConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_document_no_commit",
-        argNames: ["doc"],
-      );
+  Future<List<ChunkSearchResult>> crateApiTantivyApiTantivyIndexSearchChunked({
+    required TantivyIndex that,
+    required String query,
+    required BigInt topK,
+  });
 
-  @override
-  Future<void> crateApiTantivyApiAddDocumentsBatch({
-    required List<Document> docs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_document(docs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiAddDocumentsBatchConstMeta,
-        argValues: [docs],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexSearchDocuments({
+    required TantivyIndex that,
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiAddDocumentsBatchConstMeta =>
-      const TaskConstMeta(debugName: "add_documents_batch", argNames: ["docs"]);
+  Future<GroupedSearchResponse> crateApiTantivyApiTantivyIndexSearchGrouped({
+    required TantivyIndex that,
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+  });
 
-  @override
-  void crateApiTantivyApiCommit() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiCommitConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<void> crateApiTantivyApiTantivyIndexUpdateDocument({
+    required TantivyIndex that,
+    required Document doc,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiCommitConstMeta =>
-      const TaskConstMeta(debugName: "commit", argNames: []);
+  Future<BigInt> crateApiTantivyApiTantivyIndexUpdateDocumentIfVersion({
+    required TantivyIndex that,
+    required Document doc,
+    required BigInt expectedVersion,
+  });
 
-  @override
-  Future<void> crateApiTantivyApiDeleteDocument({required String id}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiDeleteDocumentConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<List<UpsertResult>> crateApiTantivyApiTantivyIndexUpsertDocumentsBatch({
+    required TantivyIndex that,
+    required List<Document> docs,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentConstMeta =>
-      const TaskConstMeta(debugName: "delete_document", argNames: ["id"]);
+  void crateApiTantivyApiAbortBatch({
+    required BigInt batchId,
+    String? indexName,
+  });
 
-  @override
-  Future<void> crateApiTantivyApiDeleteDocumentNoCommit({required String id}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiDeleteDocumentNoCommitConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<void> crateApiTantivyApiAddDocument({
+    required Document doc,
+    String? indexName,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentNoCommitConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_document_no_commit",
-        argNames: ["id"],
-      );
+  Future<BigInt> crateApiTantivyApiAddDocumentChunked({
+    required Document doc,
+    BigInt? maxChars,
+    String? indexName,
+  });
 
-  @override
-  Future<void> crateApiTantivyApiDeleteDocumentsBatch({
-    required List<String> ids,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(ids, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiDeleteDocumentsBatchConstMeta,
-        argValues: [ids],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<void> crateApiTantivyApiAddDocumentJson({
+    required String json,
+    String? indexName,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentsBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_documents_batch",
-        argNames: ["ids"],
-      );
+  Future<void> crateApiTantivyApiAddDocumentNoCommit({
+    required Document doc,
+    String? indexName,
+  });
 
-  @override
-  Document? crateApiTantivyApiGetDocumentById({required String id}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_document,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiGetDocumentByIdConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<void> crateApiTantivyApiAddDocumentsBatch({
+    required List<Document> docs,
+    String? indexName,
+  });
 
-  TaskConstMeta get kCrateApiTantivyApiGetDocumentByIdConstMeta =>
-      const TaskConstMeta(debugName: "get_document_by_id", argNames: ["id"]);
+  Stream<BatchProgress> crateApiTantivyApiAddDocumentsBatchStreamed({
+    required List<Document> docs,
+    BigInt? operationId,
+    String? indexName,
+  });
 
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<void> crateApiTantivyApiAddDocumentsJsonBatch({
+    required List<String> jsons,
+    String? indexName,
+  });
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  Future<List<MarkdownHeading>> crateApiTantivyApiAddMarkdownDocument({
+    required Document doc,
+    String? headingsField,
+    String? indexName,
+  });
+
+  Stream<BatchProgress> crateApiTantivyApiAddSchemaFields({
+    required List<FieldDef> fields,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<String> crateApiTantivyApiAggregate({
+    required String query,
+    required String aggregations,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiBackupIndex({
+    required String destPath,
+    String? indexName,
+  });
+
+  BigInt crateApiTantivyApiBeginBatch({String? indexName});
+
+  void crateApiTantivyApiCancelOperation({required BigInt operationId});
+
+  Future<void> crateApiTantivyApiClearIndex({String? indexName});
+
+  Future<void> crateApiTantivyApiCloseIndex({String? indexName});
+
+  void crateApiTantivyApiCommit({String? indexName});
+
+  void crateApiTantivyApiCommitBatch({
+    required BigInt batchId,
+    String? indexName,
+  });
+
+  void crateApiTantivyApiConfigureQueryParser({
+    required QueryParserConfig config,
+    String? indexName,
+  });
+
+  Future<BigInt> crateApiTantivyApiCountDocuments({
+    required String query,
+    String? indexName,
+  });
+
+  Future<List<DateBucket>> crateApiTantivyApiDateHistogram({
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+    String? indexName,
+  });
+
+  Future<BigInt> crateApiTantivyApiDeleteByQuery({
+    required String query,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiDeleteChunkedDocument({
+    required String parentId,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiDeleteDocument({
+    required String id,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiDeleteDocumentNoCommit({
+    required String id,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiDeleteDocumentsBatch({
+    required List<String> ids,
+    String? indexName,
+  });
+
+  String crateApiTantivyApiEscapeQuery({required String text});
+
+  Future<String> crateApiTantivyApiExplainScore({
+    required String query,
+    required String docId,
+    String? indexName,
+  });
+
+  Future<BigInt> crateApiTantivyApiExportDocuments({
+    required String path,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<List<Document>> crateApiTantivyApiFetchDocuments({
+    required List<HitHandle> handles,
+    List<String>? returnFields,
+    String? indexName,
+  });
+
+  FileDocument crateApiTantivyApiFileDocumentFromDocument({
+    required Document doc,
+  });
+
+  List<FieldDef> crateApiTantivyApiFileSchemaFields();
+
+  Future<List<SearchResult>> crateApiTantivyApiFindSimilar({
+    required String docId,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiForceUnlock({required String path});
+
+  Future<List<SearchResult>> crateApiTantivyApiFuzzySearch({
+    required String field,
+    required String term,
+    required int maxDistance,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<GeoSearchResult>> crateApiTantivyApiGeoSearch({
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<Document>> crateApiTantivyApiGetAllDocuments({
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  });
+
+  Document? crateApiTantivyApiGetDocumentById({
+    required String id,
+    String? indexName,
+  });
+
+  BigInt crateApiTantivyApiGetDocumentVersion({
+    required String id,
+    String? indexName,
+  });
+
+  Future<IndexStats> crateApiTantivyApiGetIndexStats({String? indexName});
+
+  List<FieldDef> crateApiTantivyApiGetSchema({String? indexName});
+
+  String crateApiSimpleGreet({required String name});
+
+  Future<List<SearchResult>> crateApiTantivyApiHybridSearch({
+    required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
+    required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
+    String? indexName,
+  });
+
+  Stream<ImportProgress> crateApiTantivyApiImportDocuments({
+    required String path,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Stream<DirectoryIndexProgress> crateApiTantivyApiIndexDirectory({
+    required String path,
+    required List<String> globs,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  bool crateApiTantivyApiIndexExists({required String path});
+
+  Future<void> crateApiTantivyApiIndexFile({
+    required String path,
+    required String docId,
+    String? mimeType,
+    String? indexName,
+  });
+
+  Future<void> crateApiSimpleInitApp();
+
+  void crateApiTantivyApiInitTantivy({
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiInitTantivyInMemory({
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiInitTantivyInMemoryWithSchema({
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiInitTantivyWithSchema({
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiKnnSearch({
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+    String? indexName,
+  });
+
+  Future<List<String>> crateApiTantivyApiListDocumentIds({
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  });
+
+  List<String> crateApiTantivyApiListIndexes();
+
+  Future<SearchResponse> crateApiTantivyApiMatchAll({
+    required BigInt topK,
+    BigInt? offset,
+    String? sortField,
+    bool? ascending,
+    String? indexName,
+  });
+
+  BigInt crateApiTantivyApiNewOperationId();
+
+  void crateApiTantivyApiOpenIndex({
+    required String name,
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiOpenIndexInMemory({
+    required String name,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiOpenIndexInMemoryWithSchema({
+    required String name,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  });
+
+  void crateApiTantivyApiOpenIndexWithSchema({
+    required String name,
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  });
+
+  Future<void> crateApiTantivyApiOptimizeIndex({
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiPhraseSearch({
+    required String field,
+    required String phrase,
+    required int slop,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiPrefixSearch({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  void crateApiTantivyApiRefreshReader({String? indexName});
+
+  Future<List<SearchResult>> crateApiTantivyApiRegexSearch({
+    required String field,
+    required String pattern,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  void crateApiTantivyApiRegisterTokenizer({
+    required String name,
+    required TokenizerConfig config,
+  });
+
+  Stream<BatchProgress> crateApiTantivyApiReindexWithSchema({
+    required List<FieldDef> fields,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiReopenIndex({
+    required String dirPath,
+    String? indexName,
+  });
+
+  Future<RepairReport> crateApiTantivyApiRepairIndex({
+    required String path,
+    BigInt? operationId,
+    Uint8List? encryptionKey,
+  });
+
+  Future<void> crateApiTantivyApiRestoreIndex({
+    required String srcPath,
+    String? indexName,
+  });
+
+  void crateApiTantivyApiRollback({String? indexName});
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchByDateRange({
+    required String field,
+    PlatformInt64? from,
+    PlatformInt64? to,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<ChunkSearchResult>> crateApiTantivyApiSearchChunked({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocuments({
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+    String? indexName,
+  });
+
+  Future<LenientSearchResponse> crateApiTantivyApiSearchDocumentsLenient({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<SearchResponse> crateApiTantivyApiSearchDocumentsPaged({
+    required String query,
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsSorted({
+    required String query,
+    required String sortField,
+    required bool ascending,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Stream<List<SearchResult>> crateApiTantivyApiSearchDocumentsStream({
+    required String query,
+    required BigInt maxResults,
+    BigInt? chunkSize,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithBm25({
+    required String query,
+    required BigInt topK,
+    required Bm25Params params,
+    String? indexName,
+  });
+
+  Future<SearchResponse> crateApiTantivyApiSearchDocumentsWithCount({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithParser({
+    required String query,
+    required BigInt topK,
+    required QueryParserConfig config,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithPopularity({
+    required String query,
+    required String popularityField,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithRecency({
+    required String query,
+    required String dateField,
+    required PlatformInt64 halfLifeMs,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithReturnFields({
+    required String query,
+    required BigInt topK,
+    required List<String> returnFields,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithSnippets({
+    required String query,
+    required BigInt topK,
+    String? snippetField,
+    BigInt? maxChars,
+    String? indexName,
+  });
+
+  Future<GroupedSearchResponse> crateApiTantivyApiSearchGrouped({
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+    String? indexName,
+  });
+
+  Future<List<HitHandle>> crateApiTantivyApiSearchHitHandles({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<FacetSearchResponse> crateApiTantivyApiSearchWithFacets({
+    required String query,
+    required String facetField,
+    String? facetRoot,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchWithQuery({
+    required SearchQuery query,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  Future<List<SearchResult>> crateApiTantivyApiSearchWithTags({
+    required String query,
+    required String tagField,
+    required List<String> requiredTags,
+    required List<String> excludedTags,
+    required BigInt topK,
+    String? indexName,
+  });
+
+  void crateApiTantivyApiSetAutoCommit({
+    AutoCommitPolicy? policy,
+    String? indexName,
+  });
+
+  Future<List<String>> crateApiTantivyApiSuggestTerms({
+    required String prefix,
+    required String field,
+    required BigInt limit,
+    String? indexName,
+  });
+
+  Stream<DirectorySyncProgress> crateApiTantivyApiSyncDirectory({
+    required String path,
+    required List<String> globs,
+    BigInt? operationId,
+    String? indexName,
+  });
+
+  Future<void> crateApiTantivyApiUpdateDocument({
+    required Document doc,
+    String? indexName,
+  });
+
+  Future<BigInt> crateApiTantivyApiUpdateDocumentIfVersion({
+    required Document doc,
+    required BigInt expectedVersion,
+    String? indexName,
+  });
+
+  Future<List<UpsertResult>> crateApiTantivyApiUpsertDocumentsBatch({
+    required List<Document> docs,
+    String? indexName,
+  });
+
+  Future<IndexHealth> crateApiTantivyApiValidateIndex({
+    required String path,
+    Uint8List? encryptionKey,
+  });
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TantivyIndex;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TantivyIndex;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TantivyIndexPtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiTantivyApiTantivyIndexAddDocument({
+    required TantivyIndex that,
+    required Document doc,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_document(doc, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexAddDocumentConstMeta,
+        argValues: [that, doc],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexAddDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_add_document",
+        argNames: ["that", "doc"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiTantivyIndexAddDocumentChunked({
+    required TantivyIndex that,
+    required Document doc,
+    BigInt? maxChars,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_box_autoadd_usize(maxChars, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexAddDocumentChunkedConstMeta,
+        argValues: [that, doc, maxChars],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexAddDocumentChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_add_document_chunked",
+        argNames: ["that", "doc", "maxChars"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexAddDocumentsBatch({
+    required TantivyIndex that,
+    required List<Document> docs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_list_document(docs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexAddDocumentsBatchConstMeta,
+        argValues: [that, docs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexAddDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_add_documents_batch",
+        argNames: ["that", "docs"],
+      );
+
+  @override
+  Future<List<MarkdownHeading>> crateApiTantivyApiTantivyIndexAddMarkdownDocument({
+    required TantivyIndex that,
+    required Document doc,
+    String? headingsField,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_String(headingsField, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_markdown_heading,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexAddMarkdownDocumentConstMeta,
+        argValues: [that, doc, headingsField],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexAddMarkdownDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_add_markdown_document",
+        argNames: ["that", "doc", "headingsField"],
+      );
+
+  @override
+  Future<String> crateApiTantivyApiTantivyIndexAggregate({
+    required TantivyIndex that,
+    required String query,
+    required String aggregations,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(aggregations, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexAggregateConstMeta,
+        argValues: [that, query, aggregations],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexAggregateConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_aggregate",
+        argNames: ["that", "query", "aggregations"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexClose({
+    required TantivyIndex that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexCloseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexCloseConstMeta =>
+      const TaskConstMeta(debugName: "TantivyIndex_close", argNames: ["that"]);
+
+  @override
+  void crateApiTantivyApiTantivyIndexCommit({required TantivyIndex that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexCommitConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexCommitConstMeta =>
+      const TaskConstMeta(debugName: "TantivyIndex_commit", argNames: ["that"]);
+
+  @override
+  Future<BigInt> crateApiTantivyApiTantivyIndexCountDocuments({
+    required TantivyIndex that,
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexCountDocumentsConstMeta,
+        argValues: [that, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexCountDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_count_documents",
+        argNames: ["that", "query"],
+      );
+
+  @override
+  Future<List<DateBucket>> crateApiTantivyApiTantivyIndexDateHistogram({
+    required TantivyIndex that,
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(dateField, serializer);
+          sse_encode_date_interval(interval, serializer);
+          sse_encode_opt_box_autoadd_i_32(utcOffsetMinutes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_date_bucket,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexDateHistogramConstMeta,
+        argValues: [that, query, dateField, interval, utcOffsetMinutes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexDateHistogramConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_date_histogram",
+        argNames: [
+          "that",
+          "query",
+          "dateField",
+          "interval",
+          "utcOffsetMinutes",
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiTantivyIndexDeleteByQuery({
+    required TantivyIndex that,
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -382,424 +1171,8563 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexDeleteByQueryConstMeta,
+        argValues: [that, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexDeleteByQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_delete_by_query",
+        argNames: ["that", "query"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexDeleteChunkedDocument({
+    required TantivyIndex that,
+    required String parentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(parentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_tantivy_error,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTantivyApiTantivyIndexDeleteChunkedDocumentConstMeta,
+        argValues: [that, parentId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexDeleteChunkedDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_delete_chunked_document",
+        argNames: ["that", "parentId"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexDeleteDocument({
+    required TantivyIndex that,
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexDeleteDocumentConstMeta,
+        argValues: [that, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexDeleteDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_delete_document",
+        argNames: ["that", "id"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexDeleteDocumentsBatch({
+    required TantivyIndex that,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_list_String(ids, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexDeleteDocumentsBatchConstMeta,
+        argValues: [that, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexDeleteDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_delete_documents_batch",
+        argNames: ["that", "ids"],
+      );
+
+  @override
+  Future<List<GeoSearchResult>> crateApiTantivyApiTantivyIndexGeoSearch({
+    required TantivyIndex that,
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(field, serializer);
+          sse_encode_box_autoadd_geo_filter(filter, serializer);
+          sse_encode_bool(sortByDistance, serializer);
+          sse_encode_usize(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_geo_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexGeoSearchConstMeta,
+        argValues: [that, query, field, filter, sortByDistance, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexGeoSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_geo_search",
+        argNames: [
+          "that",
+          "query",
+          "field",
+          "filter",
+          "sortByDistance",
+          "topK",
+        ],
+      );
+
+  @override
+  Document? crateApiTantivyApiTantivyIndexGetDocumentById({
+    required TantivyIndex that,
+    required String id,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_document,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexGetDocumentByIdConstMeta,
+        argValues: [that, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexGetDocumentByIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_get_document_by_id",
+        argNames: ["that", "id"],
+      );
+
+  @override
+  BigInt crateApiTantivyApiTantivyIndexGetDocumentVersion({
+    required TantivyIndex that,
+    required String id,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexGetDocumentVersionConstMeta,
+        argValues: [that, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexGetDocumentVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_get_document_version",
+        argNames: ["that", "id"],
+      );
+
+  @override
+  Future<IndexStats> crateApiTantivyApiTantivyIndexGetIndexStats({
+    required TantivyIndex that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_index_stats,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexGetIndexStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexGetIndexStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_get_index_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  List<FieldDef> crateApiTantivyApiTantivyIndexGetSchema({
+    required TantivyIndex that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_field_def,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexGetSchemaConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexGetSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_get_schema",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexHybridSearch({
+    required TantivyIndex that,
+    required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
+    required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(vectorField, serializer);
+          sse_encode_list_prim_f_32_loose(vector, serializer);
+          sse_encode_f_32(alpha, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_box_autoadd_fusion_method(fusion, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexHybridSearchConstMeta,
+        argValues: [
+          that,
+          query,
+          vectorField,
+          vector,
+          alpha,
+          topK,
+          fusion,
+          filter,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexHybridSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_hybrid_search",
+        argNames: [
+          "that",
+          "query",
+          "vectorField",
+          "vector",
+          "alpha",
+          "topK",
+          "fusion",
+          "filter",
+        ],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexKnnSearch({
+    required TantivyIndex that,
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(field, serializer);
+          sse_encode_list_prim_f_32_loose(vector, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_box_autoadd_vector_metric(metric, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexKnnSearchConstMeta,
+        argValues: [that, field, vector, topK, metric, filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexKnnSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_knn_search",
+        argNames: ["that", "field", "vector", "topK", "metric", "filter"],
+      );
+
+  @override
+  String crateApiTantivyApiTantivyIndexName({required TantivyIndex that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexNameConstMeta =>
+      const TaskConstMeta(debugName: "TantivyIndex_name", argNames: ["that"]);
+
+  @override
+  TantivyIndex crateApiTantivyApiTantivyIndexOpen({
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_opt_box_autoadd_text_language(language, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexOpenConstMeta,
+        argValues: [dirPath, language, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_open",
+        argNames: ["dirPath", "language", "writerOptions"],
+      );
+
+  @override
+  TantivyIndex crateApiTantivyApiTantivyIndexOpenWithSchema({
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_list_field_def(fields, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexOpenWithSchemaConstMeta,
+        argValues: [dirPath, fields, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexOpenWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_open_with_schema",
+        argNames: ["dirPath", "fields", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiTantivyIndexRefreshReader({
+    required TantivyIndex that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexRefreshReaderConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexRefreshReaderConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_refresh_reader",
+        argNames: ["that"],
+      );
+
+  @override
+  void crateApiTantivyApiTantivyIndexRollback({required TantivyIndex that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexRollbackConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexRollbackConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_rollback",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<ChunkSearchResult>> crateApiTantivyApiTantivyIndexSearchChunked({
+    required TantivyIndex that,
+    required String query,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexSearchChunkedConstMeta,
+        argValues: [that, query, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexSearchChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_search_chunked",
+        argNames: ["that", "query", "topK"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiTantivyIndexSearchDocuments({
+    required TantivyIndex that,
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_list_field_boost(fields, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexSearchDocumentsConstMeta,
+        argValues: [that, query, topK, fields, filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexSearchDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_search_documents",
+        argNames: ["that", "query", "topK", "fields", "filter"],
+      );
+
+  @override
+  Future<GroupedSearchResponse> crateApiTantivyApiTantivyIndexSearchGrouped({
+    required TantivyIndex that,
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(groupField, serializer);
+          sse_encode_usize(topGroups, serializer);
+          sse_encode_usize(hitsPerGroup, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_grouped_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexSearchGroupedConstMeta,
+        argValues: [that, query, groupField, topGroups, hitsPerGroup],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexSearchGroupedConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_search_grouped",
+        argNames: ["that", "query", "groupField", "topGroups", "hitsPerGroup"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiTantivyIndexUpdateDocument({
+    required TantivyIndex that,
+    required Document doc,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_document(doc, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexUpdateDocumentConstMeta,
+        argValues: [that, doc],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexUpdateDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_update_document",
+        argNames: ["that", "doc"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiTantivyIndexUpdateDocumentIfVersion({
+    required TantivyIndex that,
+    required Document doc,
+    required BigInt expectedVersion,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_u_64(expectedVersion, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexUpdateDocumentIfVersionConstMeta,
+        argValues: [that, doc, expectedVersion],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexUpdateDocumentIfVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_update_document_if_version",
+        argNames: ["that", "doc", "expectedVersion"],
+      );
+
+  @override
+  Future<List<UpsertResult>> crateApiTantivyApiTantivyIndexUpsertDocumentsBatch({
+    required TantivyIndex that,
+    required List<Document> docs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+            that,
+            serializer,
+          );
+          sse_encode_list_document(docs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_upsert_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiTantivyIndexUpsertDocumentsBatchConstMeta,
+        argValues: [that, docs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiTantivyIndexUpsertDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "TantivyIndex_upsert_documents_batch",
+        argNames: ["that", "docs"],
+      );
+
+  @override
+  void crateApiTantivyApiAbortBatch({
+    required BigInt batchId,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(batchId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAbortBatchConstMeta,
+        argValues: [batchId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAbortBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "abort_batch",
+        argNames: ["batchId", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiAddDocument({
+    required Document doc,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentConstMeta,
+        argValues: [doc, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_document",
+        argNames: ["doc", "indexName"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiAddDocumentChunked({
+    required Document doc,
+    BigInt? maxChars,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_box_autoadd_usize(maxChars, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentChunkedConstMeta,
+        argValues: [doc, maxChars, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_document_chunked",
+        argNames: ["doc", "maxChars", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiAddDocumentJson({
+    required String json,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentJsonConstMeta,
+        argValues: [json, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_document_json",
+        argNames: ["json", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiAddDocumentNoCommit({
+    required Document doc,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentNoCommitConstMeta,
+        argValues: [doc, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentNoCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_document_no_commit",
+        argNames: ["doc", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiAddDocumentsBatch({
+    required List<Document> docs,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_document(docs, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentsBatchConstMeta,
+        argValues: [docs, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_documents_batch",
+        argNames: ["docs", "indexName"],
+      );
+
+  @override
+  Stream<BatchProgress> crateApiTantivyApiAddDocumentsBatchStreamed({
+    required List<Document> docs,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<BatchProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_document(docs, serializer);
+            sse_encode_StreamSink_batch_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 38,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiAddDocumentsBatchStreamedConstMeta,
+          argValues: [docs, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentsBatchStreamedConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_documents_batch_streamed",
+        argNames: ["docs", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiAddDocumentsJsonBatch({
+    required List<String> jsons,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(jsons, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddDocumentsJsonBatchConstMeta,
+        argValues: [jsons, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddDocumentsJsonBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_documents_json_batch",
+        argNames: ["jsons", "indexName"],
+      );
+
+  @override
+  Future<List<MarkdownHeading>> crateApiTantivyApiAddMarkdownDocument({
+    required Document doc,
+    String? headingsField,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_String(headingsField, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_markdown_heading,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAddMarkdownDocumentConstMeta,
+        argValues: [doc, headingsField, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddMarkdownDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_markdown_document",
+        argNames: ["doc", "headingsField", "indexName"],
+      );
+
+  @override
+  Stream<BatchProgress> crateApiTantivyApiAddSchemaFields({
+    required List<FieldDef> fields,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<BatchProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_field_def(fields, serializer);
+            sse_encode_StreamSink_batch_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 41,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiAddSchemaFieldsConstMeta,
+          argValues: [fields, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAddSchemaFieldsConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_schema_fields",
+        argNames: ["fields", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  Future<String> crateApiTantivyApiAggregate({
+    required String query,
+    required String aggregations,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(aggregations, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiAggregateConstMeta,
+        argValues: [query, aggregations, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiAggregateConstMeta =>
+      const TaskConstMeta(
+        debugName: "aggregate",
+        argNames: ["query", "aggregations", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiBackupIndex({
+    required String destPath,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(destPath, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiBackupIndexConstMeta,
+        argValues: [destPath, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiBackupIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "backup_index",
+        argNames: ["destPath", "indexName"],
+      );
+
+  @override
+  BigInt crateApiTantivyApiBeginBatch({String? indexName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiBeginBatchConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiBeginBatchConstMeta =>
+      const TaskConstMeta(debugName: "begin_batch", argNames: ["indexName"]);
+
+  @override
+  void crateApiTantivyApiCancelOperation({required BigInt operationId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(operationId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiCancelOperationConstMeta,
+        argValues: [operationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiCancelOperationConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_operation",
+        argNames: ["operationId"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiClearIndex({String? indexName}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiClearIndexConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiClearIndexConstMeta =>
+      const TaskConstMeta(debugName: "clear_index", argNames: ["indexName"]);
+
+  @override
+  Future<void> crateApiTantivyApiCloseIndex({String? indexName}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiCloseIndexConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiCloseIndexConstMeta =>
+      const TaskConstMeta(debugName: "close_index", argNames: ["indexName"]);
+
+  @override
+  void crateApiTantivyApiCommit({String? indexName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiCommitConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiCommitConstMeta =>
+      const TaskConstMeta(debugName: "commit", argNames: ["indexName"]);
+
+  @override
+  void crateApiTantivyApiCommitBatch({
+    required BigInt batchId,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(batchId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiCommitBatchConstMeta,
+        argValues: [batchId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiCommitBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "commit_batch",
+        argNames: ["batchId", "indexName"],
+      );
+
+  @override
+  void crateApiTantivyApiConfigureQueryParser({
+    required QueryParserConfig config,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_query_parser_config(config, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiConfigureQueryParserConstMeta,
+        argValues: [config, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiConfigureQueryParserConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_query_parser",
+        argNames: ["config", "indexName"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiCountDocuments({
+    required String query,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiCountDocumentsConstMeta,
+        argValues: [query, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiCountDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "count_documents",
+        argNames: ["query", "indexName"],
+      );
+
+  @override
+  Future<List<DateBucket>> crateApiTantivyApiDateHistogram({
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(dateField, serializer);
+          sse_encode_date_interval(interval, serializer);
+          sse_encode_opt_box_autoadd_i_32(utcOffsetMinutes, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_date_bucket,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDateHistogramConstMeta,
+        argValues: [query, dateField, interval, utcOffsetMinutes, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDateHistogramConstMeta =>
+      const TaskConstMeta(
+        debugName: "date_histogram",
+        argNames: [
+          "query",
+          "dateField",
+          "interval",
+          "utcOffsetMinutes",
+          "indexName",
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiDeleteByQuery({
+    required String query,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDeleteByQueryConstMeta,
+        argValues: [query, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDeleteByQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_by_query",
+        argNames: ["query", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiDeleteChunkedDocument({
+    required String parentId,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(parentId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDeleteChunkedDocumentConstMeta,
+        argValues: [parentId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDeleteChunkedDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_chunked_document",
+        argNames: ["parentId", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiDeleteDocument({
+    required String id,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDeleteDocumentConstMeta,
+        argValues: [id, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_document",
+        argNames: ["id", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiDeleteDocumentNoCommit({
+    required String id,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDeleteDocumentNoCommitConstMeta,
+        argValues: [id, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentNoCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_document_no_commit",
+        argNames: ["id", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiDeleteDocumentsBatch({
+    required List<String> ids,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(ids, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiDeleteDocumentsBatchConstMeta,
+        argValues: [ids, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiDeleteDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_documents_batch",
+        argNames: ["ids", "indexName"],
+      );
+
+  @override
+  String crateApiTantivyApiEscapeQuery({required String text}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiEscapeQueryConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiEscapeQueryConstMeta =>
+      const TaskConstMeta(debugName: "escape_query", argNames: ["text"]);
+
+  @override
+  Future<String> crateApiTantivyApiExplainScore({
+    required String query,
+    required String docId,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(docId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiExplainScoreConstMeta,
+        argValues: [query, docId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiExplainScoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "explain_score",
+        argNames: ["query", "docId", "indexName"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiExportDocuments({
+    required String path,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiExportDocumentsConstMeta,
+        argValues: [path, operationId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiExportDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_documents",
+        argNames: ["path", "operationId", "indexName"],
+      );
+
+  @override
+  Future<List<Document>> crateApiTantivyApiFetchDocuments({
+    required List<HitHandle> handles,
+    List<String>? returnFields,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_hit_handle(handles, serializer);
+          sse_encode_opt_list_String(returnFields, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_document,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiFetchDocumentsConstMeta,
+        argValues: [handles, returnFields, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiFetchDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_documents",
+        argNames: ["handles", "returnFields", "indexName"],
+      );
+
+  @override
+  FileDocument crateApiTantivyApiFileDocumentFromDocument({
+    required Document doc,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_document,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiFileDocumentFromDocumentConstMeta,
+        argValues: [doc],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiFileDocumentFromDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_document_from_document",
+        argNames: ["doc"],
+      );
+
+  @override
+  List<FieldDef> crateApiTantivyApiFileSchemaFields() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_field_def,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiFileSchemaFieldsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiFileSchemaFieldsConstMeta =>
+      const TaskConstMeta(debugName: "file_schema_fields", argNames: []);
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiFindSimilar({
+    required String docId,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(docId, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiFindSimilarConstMeta,
+        argValues: [docId, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiFindSimilarConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_similar",
+        argNames: ["docId", "topK", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiForceUnlock({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiForceUnlockConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiForceUnlockConstMeta =>
+      const TaskConstMeta(debugName: "force_unlock", argNames: ["path"]);
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiFuzzySearch({
+    required String field,
+    required String term,
+    required int maxDistance,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_String(term, serializer);
+          sse_encode_u_8(maxDistance, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiFuzzySearchConstMeta,
+        argValues: [field, term, maxDistance, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiFuzzySearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "fuzzy_search",
+        argNames: ["field", "term", "maxDistance", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<GeoSearchResult>> crateApiTantivyApiGeoSearch({
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(field, serializer);
+          sse_encode_box_autoadd_geo_filter(filter, serializer);
+          sse_encode_bool(sortByDistance, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_geo_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGeoSearchConstMeta,
+        argValues: [query, field, filter, sortByDistance, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGeoSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "geo_search",
+        argNames: [
+          "query",
+          "field",
+          "filter",
+          "sortByDistance",
+          "topK",
+          "indexName",
+        ],
+      );
+
+  @override
+  Future<List<Document>> crateApiTantivyApiGetAllDocuments({
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(offset, serializer);
+          sse_encode_usize(limit, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_document,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGetAllDocumentsConstMeta,
+        argValues: [offset, limit, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGetAllDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_documents",
+        argNames: ["offset", "limit", "indexName"],
+      );
+
+  @override
+  Document? crateApiTantivyApiGetDocumentById({
+    required String id,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_document,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGetDocumentByIdConstMeta,
+        argValues: [id, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGetDocumentByIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_document_by_id",
+        argNames: ["id", "indexName"],
+      );
+
+  @override
+  BigInt crateApiTantivyApiGetDocumentVersion({
+    required String id,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGetDocumentVersionConstMeta,
+        argValues: [id, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGetDocumentVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_document_version",
+        argNames: ["id", "indexName"],
+      );
+
+  @override
+  Future<IndexStats> crateApiTantivyApiGetIndexStats({String? indexName}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_index_stats,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGetIndexStatsConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGetIndexStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_index_stats",
+        argNames: ["indexName"],
+      );
+
+  @override
+  List<FieldDef> crateApiTantivyApiGetSchema({String? indexName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_field_def,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiGetSchemaConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiGetSchemaConstMeta =>
+      const TaskConstMeta(debugName: "get_schema", argNames: ["indexName"]);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiHybridSearch({
+    required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
+    required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(vectorField, serializer);
+          sse_encode_list_prim_f_32_loose(vector, serializer);
+          sse_encode_f_32(alpha, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_box_autoadd_fusion_method(fusion, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiHybridSearchConstMeta,
+        argValues: [
+          query,
+          vectorField,
+          vector,
+          alpha,
+          topK,
+          fusion,
+          filter,
+          indexName,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiHybridSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "hybrid_search",
+        argNames: [
+          "query",
+          "vectorField",
+          "vector",
+          "alpha",
+          "topK",
+          "fusion",
+          "filter",
+          "indexName",
+        ],
+      );
+
+  @override
+  Stream<ImportProgress> crateApiTantivyApiImportDocuments({
+    required String path,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<ImportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_StreamSink_import_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 75,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiImportDocumentsConstMeta,
+          argValues: [path, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiImportDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_documents",
+        argNames: ["path", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  Stream<DirectoryIndexProgress> crateApiTantivyApiIndexDirectory({
+    required String path,
+    required List<String> globs,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<DirectoryIndexProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_list_String(globs, serializer);
+            sse_encode_StreamSink_directory_index_progress_Sse(
+              sink,
+              serializer,
+            );
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 76,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiIndexDirectoryConstMeta,
+          argValues: [path, globs, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiIndexDirectoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_directory",
+        argNames: ["path", "globs", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  bool crateApiTantivyApiIndexExists({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiIndexExistsConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiIndexExistsConstMeta =>
+      const TaskConstMeta(debugName: "index_exists", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiTantivyApiIndexFile({
+    required String path,
+    required String docId,
+    String? mimeType,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(docId, serializer);
+          sse_encode_opt_String(mimeType, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiIndexFileConstMeta,
+        argValues: [path, docId, mimeType, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiIndexFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_file",
+        argNames: ["path", "docId", "mimeType", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  void crateApiTantivyApiInitTantivy({
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_opt_box_autoadd_text_language(language, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiInitTantivyConstMeta,
+        argValues: [dirPath, language, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiInitTantivyConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_tantivy",
+        argNames: ["dirPath", "language", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiInitTantivyInMemory({
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_text_language(language, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiInitTantivyInMemoryConstMeta,
+        argValues: [language, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiInitTantivyInMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_tantivy_in_memory",
+        argNames: ["language", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiInitTantivyInMemoryWithSchema({
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_field_def(fields, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiInitTantivyInMemoryWithSchemaConstMeta,
+        argValues: [fields, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiInitTantivyInMemoryWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_tantivy_in_memory_with_schema",
+        argNames: ["fields", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiInitTantivyWithSchema({
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_list_field_def(fields, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiInitTantivyWithSchemaConstMeta,
+        argValues: [dirPath, fields, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiInitTantivyWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_tantivy_with_schema",
+        argNames: ["dirPath", "fields", "writerOptions"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiKnnSearch({
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_list_prim_f_32_loose(vector, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_box_autoadd_vector_metric(metric, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiKnnSearchConstMeta,
+        argValues: [field, vector, topK, metric, filter, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiKnnSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "knn_search",
+        argNames: ["field", "vector", "topK", "metric", "filter", "indexName"],
+      );
+
+  @override
+  Future<List<String>> crateApiTantivyApiListDocumentIds({
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(offset, serializer);
+          sse_encode_usize(limit, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiListDocumentIdsConstMeta,
+        argValues: [offset, limit, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiListDocumentIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_document_ids",
+        argNames: ["offset", "limit", "indexName"],
+      );
+
+  @override
+  List<String> crateApiTantivyApiListIndexes() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiListIndexesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiListIndexesConstMeta =>
+      const TaskConstMeta(debugName: "list_indexes", argNames: []);
+
+  @override
+  Future<SearchResponse> crateApiTantivyApiMatchAll({
+    required BigInt topK,
+    BigInt? offset,
+    String? sortField,
+    bool? ascending,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_box_autoadd_usize(offset, serializer);
+          sse_encode_opt_String(sortField, serializer);
+          sse_encode_opt_box_autoadd_bool(ascending, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiMatchAllConstMeta,
+        argValues: [topK, offset, sortField, ascending, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiMatchAllConstMeta => const TaskConstMeta(
+    debugName: "match_all",
+    argNames: ["topK", "offset", "sortField", "ascending", "indexName"],
+  );
+
+  @override
+  BigInt crateApiTantivyApiNewOperationId() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTantivyApiNewOperationIdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiNewOperationIdConstMeta =>
+      const TaskConstMeta(debugName: "new_operation_id", argNames: []);
+
+  @override
+  void crateApiTantivyApiOpenIndex({
+    required String name,
+    required String dirPath,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_opt_box_autoadd_text_language(language, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiOpenIndexConstMeta,
+        argValues: [name, dirPath, language, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiOpenIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_index",
+        argNames: ["name", "dirPath", "language", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiOpenIndexInMemory({
+    required String name,
+    TextLanguage? language,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_box_autoadd_text_language(language, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiOpenIndexInMemoryConstMeta,
+        argValues: [name, language, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiOpenIndexInMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_index_in_memory",
+        argNames: ["name", "language", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiOpenIndexInMemoryWithSchema({
+    required String name,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_list_field_def(fields, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiOpenIndexInMemoryWithSchemaConstMeta,
+        argValues: [name, fields, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiOpenIndexInMemoryWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_index_in_memory_with_schema",
+        argNames: ["name", "fields", "writerOptions"],
+      );
+
+  @override
+  void crateApiTantivyApiOpenIndexWithSchema({
+    required String name,
+    required String dirPath,
+    required List<FieldDef> fields,
+    WriterOptions? writerOptions,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_list_field_def(fields, serializer);
+          sse_encode_opt_box_autoadd_writer_options(writerOptions, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiOpenIndexWithSchemaConstMeta,
+        argValues: [name, dirPath, fields, writerOptions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiOpenIndexWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_index_with_schema",
+        argNames: ["name", "dirPath", "fields", "writerOptions"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiOptimizeIndex({
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiOptimizeIndexConstMeta,
+        argValues: [operationId, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiOptimizeIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "optimize_index",
+        argNames: ["operationId", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiPhraseSearch({
+    required String field,
+    required String phrase,
+    required int slop,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_String(phrase, serializer);
+          sse_encode_u_32(slop, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiPhraseSearchConstMeta,
+        argValues: [field, phrase, slop, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiPhraseSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "phrase_search",
+        argNames: ["field", "phrase", "slop", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiPrefixSearch({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiPrefixSearchConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiPrefixSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "prefix_search",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  void crateApiTantivyApiRefreshReader({String? indexName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRefreshReaderConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRefreshReaderConstMeta =>
+      const TaskConstMeta(debugName: "refresh_reader", argNames: ["indexName"]);
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiRegexSearch({
+    required String field,
+    required String pattern,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_String(pattern, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRegexSearchConstMeta,
+        argValues: [field, pattern, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRegexSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "regex_search",
+        argNames: ["field", "pattern", "topK", "indexName"],
+      );
+
+  @override
+  void crateApiTantivyApiRegisterTokenizer({
+    required String name,
+    required TokenizerConfig config,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_box_autoadd_tokenizer_config(config, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRegisterTokenizerConstMeta,
+        argValues: [name, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRegisterTokenizerConstMeta =>
+      const TaskConstMeta(
+        debugName: "register_tokenizer",
+        argNames: ["name", "config"],
+      );
+
+  @override
+  Stream<BatchProgress> crateApiTantivyApiReindexWithSchema({
+    required List<FieldDef> fields,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<BatchProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_field_def(fields, serializer);
+            sse_encode_StreamSink_batch_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 99,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiReindexWithSchemaConstMeta,
+          argValues: [fields, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiReindexWithSchemaConstMeta =>
+      const TaskConstMeta(
+        debugName: "reindex_with_schema",
+        argNames: ["fields", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiReopenIndex({
+    required String dirPath,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dirPath, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiReopenIndexConstMeta,
+        argValues: [dirPath, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiReopenIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "reopen_index",
+        argNames: ["dirPath", "indexName"],
+      );
+
+  @override
+  Future<RepairReport> crateApiTantivyApiRepairIndex({
+    required String path,
+    BigInt? operationId,
+    Uint8List? encryptionKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+          sse_encode_opt_list_prim_u_8_strict(encryptionKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_repair_report,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRepairIndexConstMeta,
+        argValues: [path, operationId, encryptionKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRepairIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "repair_index",
+        argNames: ["path", "operationId", "encryptionKey"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiRestoreIndex({
+    required String srcPath,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(srcPath, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRestoreIndexConstMeta,
+        argValues: [srcPath, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRestoreIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_index",
+        argNames: ["srcPath", "indexName"],
+      );
+
+  @override
+  void crateApiTantivyApiRollback({String? indexName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiRollbackConstMeta,
+        argValues: [indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiRollbackConstMeta =>
+      const TaskConstMeta(debugName: "rollback", argNames: ["indexName"]);
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchByDateRange({
+    required String field,
+    PlatformInt64? from,
+    PlatformInt64? to,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_opt_box_autoadd_i_64(from, serializer);
+          sse_encode_opt_box_autoadd_i_64(to, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchByDateRangeConstMeta,
+        argValues: [field, from, to, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchByDateRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_by_date_range",
+        argNames: ["field", "from", "to", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<ChunkSearchResult>> crateApiTantivyApiSearchChunked({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchChunkedConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_chunked",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocuments({
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_list_field_boost(fields, serializer);
+          sse_encode_opt_box_autoadd_search_query(filter, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsConstMeta,
+        argValues: [query, topK, fields, filter, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents",
+        argNames: ["query", "topK", "fields", "filter", "indexName"],
+      );
+
+  @override
+  Future<LenientSearchResponse> crateApiTantivyApiSearchDocumentsLenient({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_lenient_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsLenientConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsLenientConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_lenient",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  Future<SearchResponse> crateApiTantivyApiSearchDocumentsPaged({
+    required String query,
+    required BigInt offset,
+    required BigInt limit,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(offset, serializer);
+          sse_encode_usize(limit, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsPagedConstMeta,
+        argValues: [query, offset, limit, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsPagedConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_paged",
+        argNames: ["query", "offset", "limit", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsSorted({
+    required String query,
+    required String sortField,
+    required bool ascending,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(sortField, serializer);
+          sse_encode_bool(ascending, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsSortedConstMeta,
+        argValues: [query, sortField, ascending, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsSortedConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_sorted",
+        argNames: ["query", "sortField", "ascending", "topK", "indexName"],
+      );
+
+  @override
+  Stream<List<SearchResult>> crateApiTantivyApiSearchDocumentsStream({
+    required String query,
+    required BigInt maxResults,
+    BigInt? chunkSize,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<List<SearchResult>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(query, serializer);
+            sse_encode_usize(maxResults, serializer);
+            sse_encode_opt_box_autoadd_usize(chunkSize, serializer);
+            sse_encode_StreamSink_list_search_result_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 110,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiSearchDocumentsStreamConstMeta,
+          argValues: [
+            query,
+            maxResults,
+            chunkSize,
+            sink,
+            operationId,
+            indexName,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_stream",
+        argNames: [
+          "query",
+          "maxResults",
+          "chunkSize",
+          "sink",
+          "operationId",
+          "indexName",
+        ],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithBm25({
+    required String query,
+    required BigInt topK,
+    required Bm25Params params,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_box_autoadd_bm_25_params(params, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithBm25ConstMeta,
+        argValues: [query, topK, params, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithBm25ConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_bm25",
+        argNames: ["query", "topK", "params", "indexName"],
+      );
+
+  @override
+  Future<SearchResponse> crateApiTantivyApiSearchDocumentsWithCount({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithCountConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_count",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithParser({
+    required String query,
+    required BigInt topK,
+    required QueryParserConfig config,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_box_autoadd_query_parser_config(config, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithParserConstMeta,
+        argValues: [query, topK, config, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithParserConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_parser",
+        argNames: ["query", "topK", "config", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithPopularity({
+    required String query,
+    required String popularityField,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(popularityField, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithPopularityConstMeta,
+        argValues: [query, popularityField, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithPopularityConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_popularity",
+        argNames: ["query", "popularityField", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithRecency({
+    required String query,
+    required String dateField,
+    required PlatformInt64 halfLifeMs,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(dateField, serializer);
+          sse_encode_i_64(halfLifeMs, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithRecencyConstMeta,
+        argValues: [query, dateField, halfLifeMs, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithRecencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_recency",
+        argNames: ["query", "dateField", "halfLifeMs", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithReturnFields({
+    required String query,
+    required BigInt topK,
+    required List<String> returnFields,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_list_String(returnFields, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithReturnFieldsConstMeta,
+        argValues: [query, topK, returnFields, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithReturnFieldsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_return_fields",
+        argNames: ["query", "topK", "returnFields", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchDocumentsWithSnippets({
+    required String query,
+    required BigInt topK,
+    String? snippetField,
+    BigInt? maxChars,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(snippetField, serializer);
+          sse_encode_opt_box_autoadd_usize(maxChars, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchDocumentsWithSnippetsConstMeta,
+        argValues: [query, topK, snippetField, maxChars, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsWithSnippetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_documents_with_snippets",
+        argNames: ["query", "topK", "snippetField", "maxChars", "indexName"],
+      );
+
+  @override
+  Future<GroupedSearchResponse> crateApiTantivyApiSearchGrouped({
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(groupField, serializer);
+          sse_encode_usize(topGroups, serializer);
+          sse_encode_usize(hitsPerGroup, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_grouped_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchGroupedConstMeta,
+        argValues: [query, groupField, topGroups, hitsPerGroup, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchGroupedConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_grouped",
+        argNames: [
+          "query",
+          "groupField",
+          "topGroups",
+          "hitsPerGroup",
+          "indexName",
+        ],
+      );
+
+  @override
+  Future<List<HitHandle>> crateApiTantivyApiSearchHitHandles({
+    required String query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_hit_handle,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchHitHandlesConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchHitHandlesConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_hit_handles",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  Future<FacetSearchResponse> crateApiTantivyApiSearchWithFacets({
+    required String query,
+    required String facetField,
+    String? facetRoot,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(facetField, serializer);
+          sse_encode_opt_String(facetRoot, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_facet_search_response,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchWithFacetsConstMeta,
+        argValues: [query, facetField, facetRoot, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchWithFacetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_with_facets",
+        argNames: ["query", "facetField", "facetRoot", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchWithQuery({
+    required SearchQuery query,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_search_query(query, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchWithQueryConstMeta,
+        argValues: [query, topK, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchWithQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_with_query",
+        argNames: ["query", "topK", "indexName"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiTantivyApiSearchWithTags({
+    required String query,
+    required String tagField,
+    required List<String> requiredTags,
+    required List<String> excludedTags,
+    required BigInt topK,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_String(tagField, serializer);
+          sse_encode_list_String(requiredTags, serializer);
+          sse_encode_list_String(excludedTags, serializer);
+          sse_encode_usize(topK, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSearchWithTagsConstMeta,
+        argValues: [
+          query,
+          tagField,
+          requiredTags,
+          excludedTags,
+          topK,
+          indexName,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSearchWithTagsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_with_tags",
+        argNames: [
+          "query",
+          "tagField",
+          "requiredTags",
+          "excludedTags",
+          "topK",
+          "indexName",
+        ],
+      );
+
+  @override
+  void crateApiTantivyApiSetAutoCommit({
+    AutoCommitPolicy? policy,
+    String? indexName,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_auto_commit_policy(policy, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSetAutoCommitConstMeta,
+        argValues: [policy, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSetAutoCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_auto_commit",
+        argNames: ["policy", "indexName"],
+      );
+
+  @override
+  Future<List<String>> crateApiTantivyApiSuggestTerms({
+    required String prefix,
+    required String field,
+    required BigInt limit,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(prefix, serializer);
+          sse_encode_String(field, serializer);
+          sse_encode_usize(limit, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiSuggestTermsConstMeta,
+        argValues: [prefix, field, limit, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSuggestTermsConstMeta =>
+      const TaskConstMeta(
+        debugName: "suggest_terms",
+        argNames: ["prefix", "field", "limit", "indexName"],
+      );
+
+  @override
+  Stream<DirectorySyncProgress> crateApiTantivyApiSyncDirectory({
+    required String path,
+    required List<String> globs,
+    BigInt? operationId,
+    String? indexName,
+  }) {
+    final sink = RustStreamSink<DirectorySyncProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_list_String(globs, serializer);
+            sse_encode_StreamSink_directory_sync_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_64(operationId, serializer);
+            sse_encode_opt_String(indexName, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 125,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_tantivy_error,
+          ),
+          constMeta: kCrateApiTantivyApiSyncDirectoryConstMeta,
+          argValues: [path, globs, sink, operationId, indexName],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiSyncDirectoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_directory",
+        argNames: ["path", "globs", "sink", "operationId", "indexName"],
+      );
+
+  @override
+  Future<void> crateApiTantivyApiUpdateDocument({
+    required Document doc,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiUpdateDocumentConstMeta,
+        argValues: [doc, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiUpdateDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_document",
+        argNames: ["doc", "indexName"],
+      );
+
+  @override
+  Future<BigInt> crateApiTantivyApiUpdateDocumentIfVersion({
+    required Document doc,
+    required BigInt expectedVersion,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_document(doc, serializer);
+          sse_encode_u_64(expectedVersion, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiUpdateDocumentIfVersionConstMeta,
+        argValues: [doc, expectedVersion, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiUpdateDocumentIfVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_document_if_version",
+        argNames: ["doc", "expectedVersion", "indexName"],
+      );
+
+  @override
+  Future<List<UpsertResult>> crateApiTantivyApiUpsertDocumentsBatch({
+    required List<Document> docs,
+    String? indexName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_document(docs, serializer);
+          sse_encode_opt_String(indexName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_upsert_result,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiUpsertDocumentsBatchConstMeta,
+        argValues: [docs, indexName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiUpsertDocumentsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "upsert_documents_batch",
+        argNames: ["docs", "indexName"],
+      );
+
+  @override
+  Future<IndexHealth> crateApiTantivyApiValidateIndex({
+    required String path,
+    Uint8List? encryptionKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_list_prim_u_8_strict(encryptionKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_index_health,
+          decodeErrorData: sse_decode_tantivy_error,
+        ),
+        constMeta: kCrateApiTantivyApiValidateIndexConstMeta,
+        argValues: [path, encryptionKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTantivyApiValidateIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_index",
+        argNames: ["path", "encryptionKey"],
+      );
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TantivyIndex =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TantivyIndex =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  TantivyIndex dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  TantivyIndex dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(raw).map(
+        (e) => MapEntry(e.$1, e.$2),
+      ),
+    );
+  }
+
+  @protected
+  TantivyIndex dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<BatchProgress> dco_decode_StreamSink_batch_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DirectoryIndexProgress> dco_decode_StreamSink_directory_index_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DirectorySyncProgress> dco_decode_StreamSink_directory_sync_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<SearchResult>> dco_decode_StreamSink_list_search_result_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AutoCommitPolicy dco_decode_auto_commit_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AutoCommitPolicy(
+      maxPendingDocs: dco_decode_opt_box_autoadd_usize(arr[0]),
+      maxDelayMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  BaseTokenizer dco_decode_base_tokenizer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BaseTokenizer.values[raw as int];
+  }
+
+  @protected
+  BatchProgress dco_decode_batch_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BatchProgress(
+      processed: dco_decode_usize(arr[0]),
+      total: dco_decode_usize(arr[1]),
+      done: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  Bm25Params dco_decode_bm_25_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Bm25Params(
+      k1: dco_decode_opt_box_autoadd_f_32(arr[0]),
+      b: dco_decode_opt_box_autoadd_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AutoCommitPolicy dco_decode_box_autoadd_auto_commit_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_auto_commit_policy(raw);
+  }
+
+  @protected
+  Bm25Params dco_decode_box_autoadd_bm_25_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bm_25_params(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  DocstoreCompression dco_decode_box_autoadd_docstore_compression(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_docstore_compression(raw);
+  }
+
+  @protected
+  Document dco_decode_box_autoadd_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_document(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FusionMethod dco_decode_box_autoadd_fusion_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fusion_method(raw);
+  }
+
+  @protected
+  GeoFilter dco_decode_box_autoadd_geo_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_geo_filter(raw);
+  }
+
+  @protected
+  GeoPoint dco_decode_box_autoadd_geo_point(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_geo_point(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  QueryParserConfig dco_decode_box_autoadd_query_parser_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_query_parser_config(raw);
+  }
+
+  @protected
+  RangeBound dco_decode_box_autoadd_range_bound(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_range_bound(raw);
+  }
+
+  @protected
+  ReaderReloadPolicy dco_decode_box_autoadd_reader_reload_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reader_reload_policy(raw);
+  }
+
+  @protected
+  RecordOption dco_decode_box_autoadd_record_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_record_option(raw);
+  }
+
+  @protected
+  SearchQuery dco_decode_box_autoadd_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_query(raw);
+  }
+
+  @protected
+  StorageBackend dco_decode_box_autoadd_storage_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_storage_backend(raw);
+  }
+
+  @protected
+  TextLanguage dco_decode_box_autoadd_text_language(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_language(raw);
+  }
+
+  @protected
+  TokenizerConfig dco_decode_box_autoadd_tokenizer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tokenizer_config(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  VectorMetric dco_decode_box_autoadd_vector_metric(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_vector_metric(raw);
+  }
+
+  @protected
+  WriterOptions dco_decode_box_autoadd_writer_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_writer_options(raw);
+  }
+
+  @protected
+  ChunkSearchResult dco_decode_chunk_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ChunkSearchResult(
+      parentId: dco_decode_String(arr[0]),
+      chunkIndex: dco_decode_usize(arr[1]),
+      chunk: dco_decode_search_result(arr[2]),
+      matchingChunks: dco_decode_usize(arr[3]),
+    );
+  }
+
+  @protected
+  DateBucket dco_decode_date_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DateBucket(
+      startMillis: dco_decode_i_64(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  DateInterval dco_decode_date_interval(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DateInterval.values[raw as int];
+  }
+
+  @protected
+  DirectoryIndexProgress dco_decode_directory_index_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DirectoryIndexProgress(
+      indexed: dco_decode_usize(arr[0]),
+      skipped: dco_decode_usize(arr[1]),
+      total: dco_decode_usize(arr[2]),
+      path: dco_decode_opt_String(arr[3]),
+      done: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  DirectorySyncProgress dco_decode_directory_sync_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DirectorySyncProgress(
+      added: dco_decode_usize(arr[0]),
+      updated: dco_decode_usize(arr[1]),
+      removed: dco_decode_usize(arr[2]),
+      unchanged: dco_decode_usize(arr[3]),
+      skipped: dco_decode_usize(arr[4]),
+      total: dco_decode_usize(arr[5]),
+      path: dco_decode_opt_String(arr[6]),
+      done: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  DocstoreCompression dco_decode_docstore_compression(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocstoreCompression.values[raw as int];
+  }
+
+  @protected
+  Document dco_decode_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Document(
+      id: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+      fields: dco_decode_list_field_value(arr[2]),
+      metadata: dco_decode_Map_String_String_None(arr[3]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FacetCount dco_decode_facet_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FacetCount(
+      path: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  FacetSearchResponse dco_decode_facet_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FacetSearchResponse(
+      results: dco_decode_list_search_result(arr[0]),
+      facetCounts: dco_decode_list_facet_count(arr[1]),
+    );
+  }
+
+  @protected
+  FieldBoost dco_decode_field_boost(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FieldBoost(
+      field: dco_decode_String(arr[0]),
+      boost: dco_decode_opt_box_autoadd_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  FieldDef dco_decode_field_def(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return FieldDef(
+      name: dco_decode_String(arr[0]),
+      fieldType: dco_decode_field_type(arr[1]),
+      stored: dco_decode_bool(arr[2]),
+      indexed: dco_decode_bool(arr[3]),
+      fast: dco_decode_bool(arr[4]),
+      tokenizer: dco_decode_opt_String(arr[5]),
+      recordOption: dco_decode_opt_box_autoadd_record_option(arr[6]),
+      shadowTokenizers: dco_decode_list_String(arr[7]),
+      detectLanguages: dco_decode_list_text_language(arr[8]),
+    );
+  }
+
+  @protected
+  FieldType dco_decode_field_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FieldType.values[raw as int];
+  }
+
+  @protected
+  FieldValue dco_decode_field_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return FieldValue(
+      name: dco_decode_String(arr[0]),
+      textValue: dco_decode_opt_String(arr[1]),
+      i64Value: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      u64Value: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      f64Value: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      dateValue: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      boolValue: dco_decode_opt_box_autoadd_bool(arr[6]),
+      vectorValue: dco_decode_opt_list_prim_f_32_strict(arr[7]),
+      geoValue: dco_decode_opt_box_autoadd_geo_point(arr[8]),
+      bytesValue: dco_decode_opt_list_prim_u_8_strict(arr[9]),
+    );
+  }
+
+  @protected
+  FileDocument dco_decode_file_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FileDocument(
+      path: dco_decode_String(arr[0]),
+      size: dco_decode_u_64(arr[1]),
+      modified: dco_decode_i_64(arr[2]),
+      mimeType: dco_decode_String(arr[3]),
+      contentHash: dco_decode_String(arr[4]),
+      text: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  FusionMethod dco_decode_fusion_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FusionMethod.values[raw as int];
+  }
+
+  @protected
+  GeoFilter dco_decode_geo_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GeoFilter(
+      center: dco_decode_opt_box_autoadd_geo_point(arr[0]),
+      radiusMeters: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      southWest: dco_decode_opt_box_autoadd_geo_point(arr[2]),
+      northEast: dco_decode_opt_box_autoadd_geo_point(arr[3]),
+    );
+  }
+
+  @protected
+  GeoPoint dco_decode_geo_point(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GeoPoint(lat: dco_decode_f_64(arr[0]), lon: dco_decode_f_64(arr[1]));
+  }
+
+  @protected
+  GeoSearchResult dco_decode_geo_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GeoSearchResult(
+      result: dco_decode_search_result(arr[0]),
+      distanceMeters: dco_decode_opt_box_autoadd_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  GroupedSearchResponse dco_decode_grouped_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupedSearchResponse(
+      totalHits: dco_decode_usize(arr[0]),
+      totalGroups: dco_decode_usize(arr[1]),
+      groups: dco_decode_list_search_group(arr[2]),
+    );
+  }
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HighlightRange(
+      start: dco_decode_usize(arr[0]),
+      end: dco_decode_usize(arr[1]),
+      byteStart: dco_decode_usize(arr[2]),
+      byteEnd: dco_decode_usize(arr[3]),
+    );
+  }
+
+  @protected
+  HitHandle dco_decode_hit_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HitHandle(
+      score: dco_decode_f_32(arr[0]),
+      segmentOrd: dco_decode_u_32(arr[1]),
+      docId: dco_decode_u_32(arr[2]),
+      generation: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImportProgress(
+      imported: dco_decode_usize(arr[0]),
+      bytesRead: dco_decode_u_64(arr[1]),
+      totalBytes: dco_decode_u_64(arr[2]),
+      done: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  IndexHealth dco_decode_index_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return IndexHealth(
+      exists: dco_decode_bool(arr[0]),
+      healthy: dco_decode_bool(arr[1]),
+      locked: dco_decode_bool(arr[2]),
+      open: dco_decode_bool(arr[3]),
+      numSegments: dco_decode_u_64(arr[4]),
+      numDocs: dco_decode_u_64(arr[5]),
+      missingFiles: dco_decode_list_String(arr[6]),
+      corruptedFiles: dco_decode_list_String(arr[7]),
+      error: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  IndexStats dco_decode_index_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IndexStats(
+      numDocs: dco_decode_u_64(arr[0]),
+      numDeletedDocs: dco_decode_u_64(arr[1]),
+      numSegments: dco_decode_u_64(arr[2]),
+      sizeBytes: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  LenientSearchResponse dco_decode_lenient_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LenientSearchResponse(
+      results: dco_decode_list_search_result(arr[0]),
+      warnings: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chunk_search_result).toList();
+  }
+
+  @protected
+  List<DateBucket> dco_decode_list_date_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_date_bucket).toList();
+  }
+
+  @protected
+  List<Document> dco_decode_list_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_document).toList();
+  }
+
+  @protected
+  List<FacetCount> dco_decode_list_facet_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_facet_count).toList();
+  }
+
+  @protected
+  List<FieldBoost> dco_decode_list_field_boost(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_boost).toList();
+  }
+
+  @protected
+  List<FieldDef> dco_decode_list_field_def(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_def).toList();
+  }
+
+  @protected
+  List<FieldValue> dco_decode_list_field_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_value).toList();
+  }
+
+  @protected
+  List<GeoSearchResult> dco_decode_list_geo_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_geo_search_result).toList();
+  }
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_highlight_range).toList();
+  }
+
+  @protected
+  List<HitHandle> dco_decode_list_hit_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_hit_handle).toList();
+  }
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  }
+
+  @protected
+  List<MarkdownHeading> dco_decode_list_markdown_heading(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_markdown_heading).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<SearchGroup> dco_decode_list_search_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_group).toList();
+  }
+
+  @protected
+  List<SearchQuery> dco_decode_list_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_query).toList();
+  }
+
+  @protected
+  List<SearchResult> dco_decode_list_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_result).toList();
+  }
+
+  @protected
+  List<TextLanguage> dco_decode_list_text_language(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_language).toList();
+  }
+
+  @protected
+  List<UpsertResult> dco_decode_list_upsert_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_upsert_result).toList();
+  }
+
+  @protected
+  MarkdownHeading dco_decode_markdown_heading(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MarkdownHeading(
+      level: dco_decode_u_32(arr[0]),
+      title: dco_decode_String(arr[1]),
+      anchor: dco_decode_String(arr[2]),
+      line: dco_decode_usize(arr[3]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AutoCommitPolicy? dco_decode_opt_box_autoadd_auto_commit_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_auto_commit_policy(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  DocstoreCompression? dco_decode_opt_box_autoadd_docstore_compression(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw ==
+        null ? null : dco_decode_box_autoadd_docstore_compression(raw);
+  }
+
+  @protected
+  Document? dco_decode_opt_box_autoadd_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_document(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FusionMethod? dco_decode_opt_box_autoadd_fusion_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fusion_method(raw);
+  }
+
+  @protected
+  GeoPoint? dco_decode_opt_box_autoadd_geo_point(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_geo_point(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  RangeBound? dco_decode_opt_box_autoadd_range_bound(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_range_bound(raw);
+  }
+
+  @protected
+  ReaderReloadPolicy? dco_decode_opt_box_autoadd_reader_reload_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw ==
+        null ? null : dco_decode_box_autoadd_reader_reload_policy(raw);
+  }
+
+  @protected
+  RecordOption? dco_decode_opt_box_autoadd_record_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_record_option(raw);
+  }
+
+  @protected
+  SearchQuery? dco_decode_opt_box_autoadd_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_search_query(raw);
+  }
+
+  @protected
+  StorageBackend? dco_decode_opt_box_autoadd_storage_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_storage_backend(raw);
+  }
+
+  @protected
+  TextLanguage? dco_decode_opt_box_autoadd_text_language(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_text_language(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  VectorMetric? dco_decode_opt_box_autoadd_vector_metric(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_vector_metric(raw);
+  }
+
+  @protected
+  WriterOptions? dco_decode_opt_box_autoadd_writer_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_writer_options(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<FieldBoost>? dco_decode_opt_list_field_boost(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_field_boost(raw);
+  }
+
+  @protected
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  QueryKind dco_decode_query_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QueryKind.values[raw as int];
+  }
+
+  @protected
+  QueryParserConfig dco_decode_query_parser_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QueryParserConfig(
+      defaultFields: dco_decode_opt_list_field_boost(arr[0]),
+      conjunctionByDefault: dco_decode_opt_box_autoadd_bool(arr[1]),
+    );
+  }
+
+  @protected
+  RangeBound dco_decode_range_bound(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RangeBound(
+      textValue: dco_decode_opt_String(arr[0]),
+      i64Value: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      u64Value: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      f64Value: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      dateValue: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      inclusive: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  ReaderReloadPolicy dco_decode_reader_reload_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReaderReloadPolicy.values[raw as int];
+  }
+
+  @protected
+  RecordOption dco_decode_record_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecordOption.values[raw as int];
+  }
+
+  @protected (String, String)
+  dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  RepairReport dco_decode_repair_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RepairReport(
+      recoveredDocs: dco_decode_u_64(arr[0]),
+      lostDocs: dco_decode_u_64(arr[1]),
+      lostSegments: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  SearchGroup dco_decode_search_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SearchGroup(
+      key: dco_decode_opt_String(arr[0]),
+      totalHits: dco_decode_usize(arr[1]),
+      hits: dco_decode_list_search_result(arr[2]),
+    );
+  }
+
+  @protected
+  SearchQuery dco_decode_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return SearchQuery(
+      kind: dco_decode_query_kind(arr[0]),
+      field: dco_decode_opt_String(arr[1]),
+      text: dco_decode_opt_String(arr[2]),
+      slop: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      maxDistance: dco_decode_opt_box_autoadd_u_8(arr[4]),
+      lower: dco_decode_opt_box_autoadd_range_bound(arr[5]),
+      upper: dco_decode_opt_box_autoadd_range_bound(arr[6]),
+      boost: dco_decode_opt_box_autoadd_f_32(arr[7]),
+      constScore: dco_decode_opt_box_autoadd_f_32(arr[8]),
+      must: dco_decode_list_search_query(arr[9]),
+      should: dco_decode_list_search_query(arr[10]),
+      mustNot: dco_decode_list_search_query(arr[11]),
+    );
+  }
+
+  @protected
+  SearchResponse dco_decode_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SearchResponse(
+      totalHits: dco_decode_usize(arr[0]),
+      results: dco_decode_list_search_result(arr[1]),
+    );
+  }
+
+  @protected
+  SearchResult dco_decode_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SearchResult(
+      score: dco_decode_f_32(arr[0]),
+      doc: dco_decode_document(arr[1]),
+      snippet: dco_decode_opt_String(arr[2]),
+      highlights: dco_decode_list_highlight_range(arr[3]),
+    );
+  }
+
+  @protected
+  StorageBackend dco_decode_storage_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StorageBackend.values[raw as int];
+  }
+
+  @protected
+  TantivyError dco_decode_tantivy_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TantivyError(
+      kind: dco_decode_tantivy_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+      position: dco_decode_opt_box_autoadd_usize(arr[2]),
+    );
+  }
+
+  @protected
+  TantivyErrorKind dco_decode_tantivy_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TantivyErrorKind.values[raw as int];
+  }
+
+  @protected
+  TextLanguage dco_decode_text_language(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TextLanguage.values[raw as int];
+  }
+
+  @protected
+  TokenizerConfig dco_decode_tokenizer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return TokenizerConfig(
+      base: dco_decode_base_tokenizer(arr[0]),
+      lowercase: dco_decode_bool(arr[1]),
+      maxTokenLength: dco_decode_opt_box_autoadd_usize(arr[2]),
+      stopWords: dco_decode_opt_box_autoadd_text_language(arr[3]),
+      customStopWords: dco_decode_list_String(arr[4]),
+      synonyms: dco_decode_list_list_String(arr[5]),
+      stemmer: dco_decode_opt_box_autoadd_text_language(arr[6]),
+      asciiFolding: dco_decode_bool(arr[7]),
+      hangulJamo: dco_decode_bool(arr[8]),
+      minGram: dco_decode_opt_box_autoadd_usize(arr[9]),
+      maxGram: dco_decode_opt_box_autoadd_usize(arr[10]),
+      stripHtml: dco_decode_bool(arr[11]),
+      stripMarkdown: dco_decode_bool(arr[12]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpsertOutcome dco_decode_upsert_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UpsertOutcome.values[raw as int];
+  }
+
+  @protected
+  UpsertResult dco_decode_upsert_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UpsertResult(
+      id: dco_decode_String(arr[0]),
+      outcome: dco_decode_upsert_outcome(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VectorMetric dco_decode_vector_metric(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VectorMetric.values[raw as int];
+  }
+
+  @protected
+  WriterOptions dco_decode_writer_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return WriterOptions(
+      memoryBudgetBytes: dco_decode_opt_box_autoadd_usize(arr[0]),
+      numThreads: dco_decode_opt_box_autoadd_usize(arr[1]),
+      reloadPolicy: dco_decode_opt_box_autoadd_reader_reload_policy(arr[2]),
+      docstoreCompression: dco_decode_opt_box_autoadd_docstore_compression(
+        arr[3],
+      ),
+      docstoreBlockSize: dco_decode_opt_box_autoadd_usize(arr[4]),
+      storageBackend: dco_decode_opt_box_autoadd_storage_backend(arr[5]),
+      protectedDataWaitMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      encryptionKey: dco_decode_opt_list_prim_u_8_strict(arr[7]),
+      maxIndexSizeBytes: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      evictOldestBy: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  TantivyIndex sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  TantivyIndex sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  TantivyIndex sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TantivyIndexImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<BatchProgress> sse_decode_StreamSink_batch_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DirectoryIndexProgress> sse_decode_StreamSink_directory_index_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DirectorySyncProgress> sse_decode_StreamSink_directory_sync_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<SearchResult>> sse_decode_StreamSink_list_search_result_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AutoCommitPolicy sse_decode_auto_commit_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxPendingDocs = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_maxDelayMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return AutoCommitPolicy(
+      maxPendingDocs: var_maxPendingDocs,
+      maxDelayMs: var_maxDelayMs,
+    );
+  }
+
+  @protected
+  BaseTokenizer sse_decode_base_tokenizer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BaseTokenizer.values[inner];
+  }
+
+  @protected
+  BatchProgress sse_decode_batch_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_processed = sse_decode_usize(deserializer);
+    var var_total = sse_decode_usize(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    return BatchProgress(
+      processed: var_processed,
+      total: var_total,
+      done: var_done,
+    );
+  }
+
+  @protected
+  Bm25Params sse_decode_bm_25_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_k1 = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_b = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return Bm25Params(k1: var_k1, b: var_b);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AutoCommitPolicy sse_decode_box_autoadd_auto_commit_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_auto_commit_policy(deserializer));
+  }
+
+  @protected
+  Bm25Params sse_decode_box_autoadd_bm_25_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bm_25_params(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  DocstoreCompression sse_decode_box_autoadd_docstore_compression(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_docstore_compression(deserializer));
+  }
+
+  @protected
+  Document sse_decode_box_autoadd_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_document(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FusionMethod sse_decode_box_autoadd_fusion_method(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fusion_method(deserializer));
+  }
+
+  @protected
+  GeoFilter sse_decode_box_autoadd_geo_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_geo_filter(deserializer));
+  }
+
+  @protected
+  GeoPoint sse_decode_box_autoadd_geo_point(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_geo_point(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  QueryParserConfig sse_decode_box_autoadd_query_parser_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_query_parser_config(deserializer));
+  }
+
+  @protected
+  RangeBound sse_decode_box_autoadd_range_bound(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_range_bound(deserializer));
+  }
+
+  @protected
+  ReaderReloadPolicy sse_decode_box_autoadd_reader_reload_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reader_reload_policy(deserializer));
+  }
+
+  @protected
+  RecordOption sse_decode_box_autoadd_record_option(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_record_option(deserializer));
+  }
+
+  @protected
+  SearchQuery sse_decode_box_autoadd_search_query(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_query(deserializer));
+  }
+
+  @protected
+  StorageBackend sse_decode_box_autoadd_storage_backend(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_storage_backend(deserializer));
+  }
+
+  @protected
+  TextLanguage sse_decode_box_autoadd_text_language(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_text_language(deserializer));
+  }
+
+  @protected
+  TokenizerConfig sse_decode_box_autoadd_tokenizer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tokenizer_config(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  VectorMetric sse_decode_box_autoadd_vector_metric(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_vector_metric(deserializer));
+  }
+
+  @protected
+  WriterOptions sse_decode_box_autoadd_writer_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_writer_options(deserializer));
+  }
+
+  @protected
+  ChunkSearchResult sse_decode_chunk_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_parentId = sse_decode_String(deserializer);
+    var var_chunkIndex = sse_decode_usize(deserializer);
+    var var_chunk = sse_decode_search_result(deserializer);
+    var var_matchingChunks = sse_decode_usize(deserializer);
+    return ChunkSearchResult(
+      parentId: var_parentId,
+      chunkIndex: var_chunkIndex,
+      chunk: var_chunk,
+      matchingChunks: var_matchingChunks,
+    );
+  }
+
+  @protected
+  DateBucket sse_decode_date_bucket(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMillis = sse_decode_i_64(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return DateBucket(startMillis: var_startMillis, count: var_count);
+  }
+
+  @protected
+  DateInterval sse_decode_date_interval(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DateInterval.values[inner];
+  }
+
+  @protected
+  DirectoryIndexProgress sse_decode_directory_index_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_indexed = sse_decode_usize(deserializer);
+    var var_skipped = sse_decode_usize(deserializer);
+    var var_total = sse_decode_usize(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    return DirectoryIndexProgress(
+      indexed: var_indexed,
+      skipped: var_skipped,
+      total: var_total,
+      path: var_path,
+      done: var_done,
+    );
+  }
+
+  @protected
+  DirectorySyncProgress sse_decode_directory_sync_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_usize(deserializer);
+    var var_updated = sse_decode_usize(deserializer);
+    var var_removed = sse_decode_usize(deserializer);
+    var var_unchanged = sse_decode_usize(deserializer);
+    var var_skipped = sse_decode_usize(deserializer);
+    var var_total = sse_decode_usize(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    return DirectorySyncProgress(
+      added: var_added,
+      updated: var_updated,
+      removed: var_removed,
+      unchanged: var_unchanged,
+      skipped: var_skipped,
+      total: var_total,
+      path: var_path,
+      done: var_done,
+    );
+  }
+
+  @protected
+  DocstoreCompression sse_decode_docstore_compression(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DocstoreCompression.values[inner];
+  }
+
+  @protected
+  Document sse_decode_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_fields = sse_decode_list_field_value(deserializer);
+    var var_metadata = sse_decode_Map_String_String_None(deserializer);
+    return Document(
+      id: var_id,
+      text: var_text,
+      fields: var_fields,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FacetCount sse_decode_facet_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return FacetCount(path: var_path, count: var_count);
+  }
+
+  @protected
+  FacetSearchResponse sse_decode_facet_search_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_results = sse_decode_list_search_result(deserializer);
+    var var_facetCounts = sse_decode_list_facet_count(deserializer);
+    return FacetSearchResponse(
+      results: var_results,
+      facetCounts: var_facetCounts,
+    );
+  }
+
+  @protected
+  FieldBoost sse_decode_field_boost(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_boost = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return FieldBoost(field: var_field, boost: var_boost);
+  }
+
+  @protected
+  FieldDef sse_decode_field_def(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_fieldType = sse_decode_field_type(deserializer);
+    var var_stored = sse_decode_bool(deserializer);
+    var var_indexed = sse_decode_bool(deserializer);
+    var var_fast = sse_decode_bool(deserializer);
+    var var_tokenizer = sse_decode_opt_String(deserializer);
+    var var_recordOption =
+        sse_decode_opt_box_autoadd_record_option(deserializer);
+    var var_shadowTokenizers = sse_decode_list_String(deserializer);
+    var var_detectLanguages = sse_decode_list_text_language(deserializer);
+    return FieldDef(
+      name: var_name,
+      fieldType: var_fieldType,
+      stored: var_stored,
+      indexed: var_indexed,
+      fast: var_fast,
+      tokenizer: var_tokenizer,
+      recordOption: var_recordOption,
+      shadowTokenizers: var_shadowTokenizers,
+      detectLanguages: var_detectLanguages,
+    );
+  }
+
+  @protected
+  FieldType sse_decode_field_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FieldType.values[inner];
+  }
+
+  @protected
+  FieldValue sse_decode_field_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_textValue = sse_decode_opt_String(deserializer);
+    var var_i64Value = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_u64Value = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_f64Value = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_dateValue = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_boolValue = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_vectorValue = sse_decode_opt_list_prim_f_32_strict(deserializer);
+    var var_geoValue = sse_decode_opt_box_autoadd_geo_point(deserializer);
+    var var_bytesValue = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return FieldValue(
+      name: var_name,
+      textValue: var_textValue,
+      i64Value: var_i64Value,
+      u64Value: var_u64Value,
+      f64Value: var_f64Value,
+      dateValue: var_dateValue,
+      boolValue: var_boolValue,
+      vectorValue: var_vectorValue,
+      geoValue: var_geoValue,
+      bytesValue: var_bytesValue,
+    );
+  }
+
+  @protected
+  FileDocument sse_decode_file_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_modified = sse_decode_i_64(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_contentHash = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return FileDocument(
+      path: var_path,
+      size: var_size,
+      modified: var_modified,
+      mimeType: var_mimeType,
+      contentHash: var_contentHash,
+      text: var_text,
+    );
+  }
+
+  @protected
+  FusionMethod sse_decode_fusion_method(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FusionMethod.values[inner];
+  }
+
+  @protected
+  GeoFilter sse_decode_geo_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_center = sse_decode_opt_box_autoadd_geo_point(deserializer);
+    var var_radiusMeters = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_southWest = sse_decode_opt_box_autoadd_geo_point(deserializer);
+    var var_northEast = sse_decode_opt_box_autoadd_geo_point(deserializer);
+    return GeoFilter(
+      center: var_center,
+      radiusMeters: var_radiusMeters,
+      southWest: var_southWest,
+      northEast: var_northEast,
+    );
+  }
+
+  @protected
+  GeoPoint sse_decode_geo_point(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lat = sse_decode_f_64(deserializer);
+    var var_lon = sse_decode_f_64(deserializer);
+    return GeoPoint(lat: var_lat, lon: var_lon);
+  }
+
+  @protected
+  GeoSearchResult sse_decode_geo_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_result = sse_decode_search_result(deserializer);
+    var var_distanceMeters = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return GeoSearchResult(
+      result: var_result,
+      distanceMeters: var_distanceMeters,
+    );
+  }
+
+  @protected
+  GroupedSearchResponse sse_decode_grouped_search_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalHits = sse_decode_usize(deserializer);
+    var var_totalGroups = sse_decode_usize(deserializer);
+    var var_groups = sse_decode_list_search_group(deserializer);
+    return GroupedSearchResponse(
+      totalHits: var_totalHits,
+      totalGroups: var_totalGroups,
+      groups: var_groups,
+    );
+  }
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_usize(deserializer);
+    var var_end = sse_decode_usize(deserializer);
+    var var_byteStart = sse_decode_usize(deserializer);
+    var var_byteEnd = sse_decode_usize(deserializer);
+    return HighlightRange(
+      start: var_start,
+      end: var_end,
+      byteStart: var_byteStart,
+      byteEnd: var_byteEnd,
+    );
+  }
+
+  @protected
+  HitHandle sse_decode_hit_handle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_f_32(deserializer);
+    var var_segmentOrd = sse_decode_u_32(deserializer);
+    var var_docId = sse_decode_u_32(deserializer);
+    var var_generation = sse_decode_u_64(deserializer);
+    return HitHandle(
+      score: var_score,
+      segmentOrd: var_segmentOrd,
+      docId: var_docId,
+      generation: var_generation,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imported = sse_decode_usize(deserializer);
+    var var_bytesRead = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    return ImportProgress(
+      imported: var_imported,
+      bytesRead: var_bytesRead,
+      totalBytes: var_totalBytes,
+      done: var_done,
+    );
+  }
+
+  @protected
+  IndexHealth sse_decode_index_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exists = sse_decode_bool(deserializer);
+    var var_healthy = sse_decode_bool(deserializer);
+    var var_locked = sse_decode_bool(deserializer);
+    var var_open = sse_decode_bool(deserializer);
+    var var_numSegments = sse_decode_u_64(deserializer);
+    var var_numDocs = sse_decode_u_64(deserializer);
+    var var_missingFiles = sse_decode_list_String(deserializer);
+    var var_corruptedFiles = sse_decode_list_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return IndexHealth(
+      exists: var_exists,
+      healthy: var_healthy,
+      locked: var_locked,
+      open: var_open,
+      numSegments: var_numSegments,
+      numDocs: var_numDocs,
+      missingFiles: var_missingFiles,
+      corruptedFiles: var_corruptedFiles,
+      error: var_error,
+    );
+  }
+
+  @protected
+  IndexStats sse_decode_index_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_numDocs = sse_decode_u_64(deserializer);
+    var var_numDeletedDocs = sse_decode_u_64(deserializer);
+    var var_numSegments = sse_decode_u_64(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    return IndexStats(
+      numDocs: var_numDocs,
+      numDeletedDocs: var_numDeletedDocs,
+      numSegments: var_numSegments,
+      sizeBytes: var_sizeBytes,
+    );
+  }
+
+  @protected
+  LenientSearchResponse sse_decode_lenient_search_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_results = sse_decode_list_search_result(deserializer);
+    var var_warnings = sse_decode_list_String(deserializer);
+    return LenientSearchResponse(results: var_results, warnings: var_warnings);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChunkSearchResult> sse_decode_list_chunk_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChunkSearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chunk_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DateBucket> sse_decode_list_date_bucket(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DateBucket>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_date_bucket(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Document> sse_decode_list_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Document>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_document(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FacetCount> sse_decode_list_facet_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FacetCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_facet_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldBoost> sse_decode_list_field_boost(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldBoost>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_boost(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldDef> sse_decode_list_field_def(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldDef>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_def(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldValue> sse_decode_list_field_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GeoSearchResult> sse_decode_list_geo_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GeoSearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_geo_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HighlightRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_highlight_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HitHandle> sse_decode_list_hit_handle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HitHandle>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hit_handle(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <List<String>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MarkdownHeading> sse_decode_list_markdown_heading(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MarkdownHeading>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_markdown_heading(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchGroup> sse_decode_list_search_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchQuery> sse_decode_list_search_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchQuery>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_query(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchResult> sse_decode_list_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextLanguage> sse_decode_list_text_language(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextLanguage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_language(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UpsertResult> sse_decode_list_upsert_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UpsertResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_upsert_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MarkdownHeading sse_decode_markdown_heading(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_anchor = sse_decode_String(deserializer);
+    var var_line = sse_decode_usize(deserializer);
+    return MarkdownHeading(
+      level: var_level,
+      title: var_title,
+      anchor: var_anchor,
+      line: var_line,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AutoCommitPolicy? sse_decode_opt_box_autoadd_auto_commit_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_auto_commit_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DocstoreCompression? sse_decode_opt_box_autoadd_docstore_compression(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_docstore_compression(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_document(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FusionMethod? sse_decode_opt_box_autoadd_fusion_method(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fusion_method(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GeoPoint? sse_decode_opt_box_autoadd_geo_point(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_geo_point(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RangeBound? sse_decode_opt_box_autoadd_range_bound(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_range_bound(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReaderReloadPolicy? sse_decode_opt_box_autoadd_reader_reload_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reader_reload_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RecordOption? sse_decode_opt_box_autoadd_record_option(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_record_option(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SearchQuery? sse_decode_opt_box_autoadd_search_query(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_search_query(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  StorageBackend? sse_decode_opt_box_autoadd_storage_backend(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_storage_backend(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TextLanguage? sse_decode_opt_box_autoadd_text_language(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_text_language(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  VectorMetric? sse_decode_opt_box_autoadd_vector_metric(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_vector_metric(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WriterOptions? sse_decode_opt_box_autoadd_writer_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_writer_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<FieldBoost>? sse_decode_opt_list_field_boost(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_field_boost(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_f_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QueryKind sse_decode_query_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QueryKind.values[inner];
+  }
+
+  @protected
+  QueryParserConfig sse_decode_query_parser_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_defaultFields = sse_decode_opt_list_field_boost(deserializer);
+    var var_conjunctionByDefault =
+        sse_decode_opt_box_autoadd_bool(deserializer);
+    return QueryParserConfig(
+      defaultFields: var_defaultFields,
+      conjunctionByDefault: var_conjunctionByDefault,
+    );
+  }
+
+  @protected
+  RangeBound sse_decode_range_bound(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_textValue = sse_decode_opt_String(deserializer);
+    var var_i64Value = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_u64Value = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_f64Value = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_dateValue = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_inclusive = sse_decode_bool(deserializer);
+    return RangeBound(
+      textValue: var_textValue,
+      i64Value: var_i64Value,
+      u64Value: var_u64Value,
+      f64Value: var_f64Value,
+      dateValue: var_dateValue,
+      inclusive: var_inclusive,
+    );
+  }
+
+  @protected
+  ReaderReloadPolicy sse_decode_reader_reload_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReaderReloadPolicy.values[inner];
+  }
+
+  @protected
+  RecordOption sse_decode_record_option(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RecordOption.values[inner];
+  }
+
+  @protected (String, String)
+  sse_decode_record_string_string(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RepairReport sse_decode_repair_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_recoveredDocs = sse_decode_u_64(deserializer);
+    var var_lostDocs = sse_decode_u_64(deserializer);
+    var var_lostSegments = sse_decode_u_64(deserializer);
+    return RepairReport(
+      recoveredDocs: var_recoveredDocs,
+      lostDocs: var_lostDocs,
+      lostSegments: var_lostSegments,
+    );
+  }
+
+  @protected
+  SearchGroup sse_decode_search_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_opt_String(deserializer);
+    var var_totalHits = sse_decode_usize(deserializer);
+    var var_hits = sse_decode_list_search_result(deserializer);
+    return SearchGroup(key: var_key, totalHits: var_totalHits, hits: var_hits);
+  }
+
+  @protected
+  SearchQuery sse_decode_search_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_query_kind(deserializer);
+    var var_field = sse_decode_opt_String(deserializer);
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_slop = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_maxDistance = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_lower = sse_decode_opt_box_autoadd_range_bound(deserializer);
+    var var_upper = sse_decode_opt_box_autoadd_range_bound(deserializer);
+    var var_boost = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_constScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_must = sse_decode_list_search_query(deserializer);
+    var var_should = sse_decode_list_search_query(deserializer);
+    var var_mustNot = sse_decode_list_search_query(deserializer);
+    return SearchQuery(
+      kind: var_kind,
+      field: var_field,
+      text: var_text,
+      slop: var_slop,
+      maxDistance: var_maxDistance,
+      lower: var_lower,
+      upper: var_upper,
+      boost: var_boost,
+      constScore: var_constScore,
+      must: var_must,
+      should: var_should,
+      mustNot: var_mustNot,
+    );
+  }
+
+  @protected
+  SearchResponse sse_decode_search_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalHits = sse_decode_usize(deserializer);
+    var var_results = sse_decode_list_search_result(deserializer);
+    return SearchResponse(totalHits: var_totalHits, results: var_results);
+  }
+
+  @protected
+  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_f_32(deserializer);
+    var var_doc = sse_decode_document(deserializer);
+    var var_snippet = sse_decode_opt_String(deserializer);
+    var var_highlights = sse_decode_list_highlight_range(deserializer);
+    return SearchResult(
+      score: var_score,
+      doc: var_doc,
+      snippet: var_snippet,
+      highlights: var_highlights,
+    );
+  }
+
+  @protected
+  StorageBackend sse_decode_storage_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return StorageBackend.values[inner];
+  }
+
+  @protected
+  TantivyError sse_decode_tantivy_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_tantivy_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_position = sse_decode_opt_box_autoadd_usize(deserializer);
+    return TantivyError(
+      kind: var_kind,
+      message: var_message,
+      position: var_position,
+    );
+  }
+
+  @protected
+  TantivyErrorKind sse_decode_tantivy_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TantivyErrorKind.values[inner];
+  }
+
+  @protected
+  TextLanguage sse_decode_text_language(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TextLanguage.values[inner];
+  }
+
+  @protected
+  TokenizerConfig sse_decode_tokenizer_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_base = sse_decode_base_tokenizer(deserializer);
+    var var_lowercase = sse_decode_bool(deserializer);
+    var var_maxTokenLength = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_stopWords = sse_decode_opt_box_autoadd_text_language(deserializer);
+    var var_customStopWords = sse_decode_list_String(deserializer);
+    var var_synonyms = sse_decode_list_list_String(deserializer);
+    var var_stemmer = sse_decode_opt_box_autoadd_text_language(deserializer);
+    var var_asciiFolding = sse_decode_bool(deserializer);
+    var var_hangulJamo = sse_decode_bool(deserializer);
+    var var_minGram = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_maxGram = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_stripHtml = sse_decode_bool(deserializer);
+    var var_stripMarkdown = sse_decode_bool(deserializer);
+    return TokenizerConfig(
+      base: var_base,
+      lowercase: var_lowercase,
+      maxTokenLength: var_maxTokenLength,
+      stopWords: var_stopWords,
+      customStopWords: var_customStopWords,
+      synonyms: var_synonyms,
+      stemmer: var_stemmer,
+      asciiFolding: var_asciiFolding,
+      hangulJamo: var_hangulJamo,
+      minGram: var_minGram,
+      maxGram: var_maxGram,
+      stripHtml: var_stripHtml,
+      stripMarkdown: var_stripMarkdown,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UpsertOutcome sse_decode_upsert_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UpsertOutcome.values[inner];
+  }
+
+  @protected
+  UpsertResult sse_decode_upsert_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_outcome = sse_decode_upsert_outcome(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return UpsertResult(id: var_id, outcome: var_outcome, error: var_error);
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  VectorMetric sse_decode_vector_metric(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return VectorMetric.values[inner];
+  }
+
+  @protected
+  WriterOptions sse_decode_writer_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_memoryBudgetBytes = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_numThreads = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_reloadPolicy =
+        sse_decode_opt_box_autoadd_reader_reload_policy(deserializer);
+    var var_docstoreCompression =
+        sse_decode_opt_box_autoadd_docstore_compression(deserializer);
+    var var_docstoreBlockSize = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_storageBackend =
+        sse_decode_opt_box_autoadd_storage_backend(deserializer);
+    var var_protectedDataWaitMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_encryptionKey = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_maxIndexSizeBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_evictOldestBy = sse_decode_opt_String(deserializer);
+    return WriterOptions(
+      memoryBudgetBytes: var_memoryBudgetBytes,
+      numThreads: var_numThreads,
+      reloadPolicy: var_reloadPolicy,
+      docstoreCompression: var_docstoreCompression,
+      docstoreBlockSize: var_docstoreBlockSize,
+      storageBackend: var_storageBackend,
+      protectedDataWaitMs: var_protectedDataWaitMs,
+      encryptionKey: var_encryptionKey,
+      maxIndexSizeBytes: var_maxIndexSizeBytes,
+      evictOldestBy: var_evictOldestBy,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TantivyIndexImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TantivyIndexImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TantivyIndexImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_batch_progress_Sse(
+    RustStreamSink<BatchProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_batch_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_directory_index_progress_Sse(
+    RustStreamSink<DirectoryIndexProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_directory_index_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_directory_sync_progress_Sse(
+    RustStreamSink<DirectorySyncProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_directory_sync_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_import_progress_Sse(
+    RustStreamSink<ImportProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_import_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_search_result_Sse(
+    RustStreamSink<List<SearchResult>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_auto_commit_policy(
+    AutoCommitPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_usize(self.maxPendingDocs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxDelayMs, serializer);
+  }
+
+  @protected
+  void sse_encode_base_tokenizer(BaseTokenizer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_batch_progress(BatchProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.processed, serializer);
+    sse_encode_usize(self.total, serializer);
+    sse_encode_bool(self.done, serializer);
+  }
+
+  @protected
+  void sse_encode_bm_25_params(Bm25Params self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_f_32(self.k1, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.b, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_auto_commit_policy(
+    AutoCommitPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auto_commit_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bm_25_params(
+    Bm25Params self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bm_25_params(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_docstore_compression(
+    DocstoreCompression self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_docstore_compression(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_document(
+    Document self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_document(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_fusion_method(
+    FusionMethod self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fusion_method(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_geo_filter(
+    GeoFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_geo_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_geo_point(
+    GeoPoint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_geo_point(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_query_parser_config(
+    QueryParserConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_query_parser_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_range_bound(
+    RangeBound self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_range_bound(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_reader_reload_policy(
+    ReaderReloadPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reader_reload_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_record_option(
+    RecordOption self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_record_option(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_search_query(
+    SearchQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_storage_backend(
+    StorageBackend self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_storage_backend(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_language(
+    TextLanguage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_language(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tokenizer_config(
+    TokenizerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tokenizer_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_vector_metric(
+    VectorMetric self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_vector_metric(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_writer_options(
+    WriterOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_writer_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_chunk_search_result(
+    ChunkSearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.parentId, serializer);
+    sse_encode_usize(self.chunkIndex, serializer);
+    sse_encode_search_result(self.chunk, serializer);
+    sse_encode_usize(self.matchingChunks, serializer);
+  }
+
+  @protected
+  void sse_encode_date_bucket(DateBucket self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.startMillis, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_date_interval(DateInterval self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_directory_index_progress(
+    DirectoryIndexProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.indexed, serializer);
+    sse_encode_usize(self.skipped, serializer);
+    sse_encode_usize(self.total, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_bool(self.done, serializer);
+  }
+
+  @protected
+  void sse_encode_directory_sync_progress(
+    DirectorySyncProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.added, serializer);
+    sse_encode_usize(self.updated, serializer);
+    sse_encode_usize(self.removed, serializer);
+    sse_encode_usize(self.unchanged, serializer);
+    sse_encode_usize(self.skipped, serializer);
+    sse_encode_usize(self.total, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_bool(self.done, serializer);
+  }
+
+  @protected
+  void sse_encode_docstore_compression(
+    DocstoreCompression self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_document(Document self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_field_value(self.fields, serializer);
+    sse_encode_Map_String_String_None(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_facet_count(FacetCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_facet_search_response(
+    FacetSearchResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_search_result(self.results, serializer);
+    sse_encode_list_facet_count(self.facetCounts, serializer);
+  }
+
+  @protected
+  void sse_encode_field_boost(FieldBoost self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.field, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.boost, serializer);
+  }
+
+  @protected
+  void sse_encode_field_def(FieldDef self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_field_type(self.fieldType, serializer);
+    sse_encode_bool(self.stored, serializer);
+    sse_encode_bool(self.indexed, serializer);
+    sse_encode_bool(self.fast, serializer);
+    sse_encode_opt_String(self.tokenizer, serializer);
+    sse_encode_opt_box_autoadd_record_option(self.recordOption, serializer);
+    sse_encode_list_String(self.shadowTokenizers, serializer);
+    sse_encode_list_text_language(self.detectLanguages, serializer);
+  }
+
+  @protected
+  void sse_encode_field_type(FieldType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_field_value(FieldValue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.textValue, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.i64Value, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.u64Value, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.f64Value, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.dateValue, serializer);
+    sse_encode_opt_box_autoadd_bool(self.boolValue, serializer);
+    sse_encode_opt_list_prim_f_32_strict(self.vectorValue, serializer);
+    sse_encode_opt_box_autoadd_geo_point(self.geoValue, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.bytesValue, serializer);
+  }
+
+  @protected
+  void sse_encode_file_document(FileDocument self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_i_64(self.modified, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.contentHash, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_fusion_method(FusionMethod self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_geo_filter(GeoFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_geo_point(self.center, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.radiusMeters, serializer);
+    sse_encode_opt_box_autoadd_geo_point(self.southWest, serializer);
+    sse_encode_opt_box_autoadd_geo_point(self.northEast, serializer);
+  }
+
+  @protected
+  void sse_encode_geo_point(GeoPoint self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.lat, serializer);
+    sse_encode_f_64(self.lon, serializer);
+  }
+
+  @protected
+  void sse_encode_geo_search_result(
+    GeoSearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_result(self.result, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.distanceMeters, serializer);
+  }
+
+  @protected
+  void sse_encode_grouped_search_response(
+    GroupedSearchResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.totalHits, serializer);
+    sse_encode_usize(self.totalGroups, serializer);
+    sse_encode_list_search_group(self.groups, serializer);
+  }
+
+  @protected
+  void sse_encode_highlight_range(
+    HighlightRange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.start, serializer);
+    sse_encode_usize(self.end, serializer);
+    sse_encode_usize(self.byteStart, serializer);
+    sse_encode_usize(self.byteEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_hit_handle(HitHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_u_32(self.segmentOrd, serializer);
+    sse_encode_u_32(self.docId, serializer);
+    sse_encode_u_64(self.generation, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_import_progress(
+    ImportProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.imported, serializer);
+    sse_encode_u_64(self.bytesRead, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_bool(self.done, serializer);
+  }
+
+  @protected
+  void sse_encode_index_health(IndexHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.exists, serializer);
+    sse_encode_bool(self.healthy, serializer);
+    sse_encode_bool(self.locked, serializer);
+    sse_encode_bool(self.open, serializer);
+    sse_encode_u_64(self.numSegments, serializer);
+    sse_encode_u_64(self.numDocs, serializer);
+    sse_encode_list_String(self.missingFiles, serializer);
+    sse_encode_list_String(self.corruptedFiles, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_index_stats(IndexStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.numDocs, serializer);
+    sse_encode_u_64(self.numDeletedDocs, serializer);
+    sse_encode_u_64(self.numSegments, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_lenient_search_response(
+    LenientSearchResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_search_result(self.results, serializer);
+    sse_encode_list_String(self.warnings, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chunk_search_result(
+    List<ChunkSearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chunk_search_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_date_bucket(
+    List<DateBucket> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_date_bucket(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_document(List<Document> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_document(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_facet_count(
+    List<FacetCount> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_facet_count(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_field_boost(
+    List<FieldBoost> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_boost(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_field_def(
+    List<FieldDef> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_def(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_field_value(
+    List<FieldValue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_geo_search_result(
+    List<GeoSearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_geo_search_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_highlight_range(
+    List<HighlightRange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_highlight_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_hit_handle(
+    List<HitHandle> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hit_handle(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_list_String(
+    List<List<String>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_markdown_heading(
+    List<MarkdownHeading> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_markdown_heading(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(
+      self is Float32List ? self : Float32List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_group(
+    List<SearchGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_group(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_query(
+    List<SearchQuery> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_query(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_result(
+    List<SearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_result(item, serializer);
+    }
+  }
 
-  @override
-  void crateApiTantivyApiInitTantivy({required String dirPath}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dirPath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiInitTantivyConstMeta,
-        argValues: [dirPath],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_list_text_language(
+    List<TextLanguage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_language(item, serializer);
+    }
   }
 
-  TaskConstMeta get kCrateApiTantivyApiInitTantivyConstMeta =>
-      const TaskConstMeta(debugName: "init_tantivy", argNames: ["dirPath"]);
+  @protected
+  void sse_encode_list_upsert_result(
+    List<UpsertResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_upsert_result(item, serializer);
+    }
+  }
 
-  @override
-  Future<List<SearchResult>> crateApiTantivyApiSearchDocuments({
-    required String query,
-    required BigInt topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
-          sse_encode_usize(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_search_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiSearchDocumentsConstMeta,
-        argValues: [query, topK],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_markdown_heading(
+    MarkdownHeading self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.level, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.anchor, serializer);
+    sse_encode_usize(self.line, serializer);
   }
 
-  TaskConstMeta get kCrateApiTantivyApiSearchDocumentsConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_documents",
-        argNames: ["query", "topK"],
-      );
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @override
-  Future<void> crateApiTantivyApiUpdateDocument({required Document doc}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_document(doc, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTantivyApiUpdateDocumentConstMeta,
-        argValues: [doc],
-        apiImpl: this,
-      ),
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
-  TaskConstMeta get kCrateApiTantivyApiUpdateDocumentConstMeta =>
-      const TaskConstMeta(debugName: "update_document", argNames: ["doc"]);
+  @protected
+  void sse_encode_opt_box_autoadd_auto_commit_policy(
+    AutoCommitPolicy? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_auto_commit_policy(self, serializer);
+    }
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_opt_box_autoadd_docstore_compression(
+    DocstoreCompression? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_docstore_compression(self, serializer);
+    }
   }
 
   @protected
-  Document dco_decode_box_autoadd_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_document(raw);
+  void sse_encode_opt_box_autoadd_document(
+    Document? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_document(self, serializer);
+    }
   }
 
   @protected
-  Document dco_decode_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return Document(
-      id: dco_decode_String(arr[0]),
-      text: dco_decode_String(arr[1]),
-    );
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_opt_box_autoadd_fusion_method(
+    FusionMethod? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fusion_method(self, serializer);
+    }
   }
 
   @protected
-  List<Document> dco_decode_list_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_document).toList();
+  void sse_encode_opt_box_autoadd_geo_point(
+    GeoPoint? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_geo_point(self, serializer);
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_search_result).toList();
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  Document? dco_decode_opt_box_autoadd_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_document(raw);
+  void sse_encode_opt_box_autoadd_range_bound(
+    RangeBound? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_range_bound(self, serializer);
+    }
   }
 
   @protected
-  SearchResult dco_decode_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SearchResult(
-      score: dco_decode_f_32(arr[0]),
-      doc: dco_decode_document(arr[1]),
-    );
+  void sse_encode_opt_box_autoadd_reader_reload_policy(
+    ReaderReloadPolicy? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_reader_reload_policy(self, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_opt_box_autoadd_record_option(
+    RecordOption? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_record_option(self, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_opt_box_autoadd_search_query(
+    SearchQuery? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_search_query(self, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_opt_box_autoadd_storage_backend(
+    StorageBackend? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_storage_backend(self, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_text_language(
+    TextLanguage? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_text_language(self, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  Document sse_decode_box_autoadd_document(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_document(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  Document sse_decode_document(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    return Document(id: var_id, text: var_text);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_8(self, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_usize(
+    BigInt? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_vector_metric(
+    VectorMetric? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_vector_metric(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<Document> sse_decode_list_document(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_writer_options(
+    WriterOptions? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Document>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_document(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_writer_options(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_list_String(
+    List<String>? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
   }
 
   @protected
-  List<SearchResult> sse_decode_list_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_list_field_boost(
+    List<FieldBoost>? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_search_result(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_field_boost(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer) {
+  void sse_encode_opt_list_prim_f_32_strict(
+    Float32List? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_document(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_f_32_strict(self, serializer);
     }
   }
 
   @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_score = sse_decode_f_32(deserializer);
-    var var_doc = sse_decode_document(deserializer);
-    return SearchResult(score: var_score, doc: var_doc);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_query_kind(QueryKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_query_parser_config(
+    QueryParserConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_list_field_boost(self.defaultFields, serializer);
+    sse_encode_opt_box_autoadd_bool(self.conjunctionByDefault, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_range_bound(RangeBound self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_opt_String(self.textValue, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.i64Value, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.u64Value, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.f64Value, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.dateValue, serializer);
+    sse_encode_bool(self.inclusive, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_reader_reload_policy(
+    ReaderReloadPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_record_option(RecordOption self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_record_string_string(
+    (String, String) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_repair_report(RepairReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_64(self.recoveredDocs, serializer);
+    sse_encode_u_64(self.lostDocs, serializer);
+    sse_encode_u_64(self.lostSegments, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_document(
-    Document self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_search_group(SearchGroup self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_document(self, serializer);
+    sse_encode_opt_String(self.key, serializer);
+    sse_encode_usize(self.totalHits, serializer);
+    sse_encode_list_search_result(self.hits, serializer);
   }
 
   @protected
-  void sse_encode_document(Document self, SseSerializer serializer) {
+  void sse_encode_search_query(SearchQuery self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.text, serializer);
+    sse_encode_query_kind(self.kind, serializer);
+    sse_encode_opt_String(self.field, serializer);
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.slop, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.maxDistance, serializer);
+    sse_encode_opt_box_autoadd_range_bound(self.lower, serializer);
+    sse_encode_opt_box_autoadd_range_bound(self.upper, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.boost, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.constScore, serializer);
+    sse_encode_list_search_query(self.must, serializer);
+    sse_encode_list_search_query(self.should, serializer);
+    sse_encode_list_search_query(self.mustNot, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_search_response(
+    SearchResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_usize(self.totalHits, serializer);
+    sse_encode_list_search_result(self.results, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_document(self.doc, serializer);
+    sse_encode_opt_String(self.snippet, serializer);
+    sse_encode_list_highlight_range(self.highlights, serializer);
   }
 
   @protected
-  void sse_encode_list_document(List<Document> self, SseSerializer serializer) {
+  void sse_encode_storage_backend(
+    StorageBackend self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_document(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_tantivy_error(TantivyError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_tantivy_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_usize(self.position, serializer);
   }
 
   @protected
-  void sse_encode_list_search_result(
-    List<SearchResult> self,
+  void sse_encode_tantivy_error_kind(
+    TantivyErrorKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_search_result(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_document(
-    Document? self,
+  void sse_encode_text_language(TextLanguage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_tokenizer_config(
+    TokenizerConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_base_tokenizer(self.base, serializer);
+    sse_encode_bool(self.lowercase, serializer);
+    sse_encode_opt_box_autoadd_usize(self.maxTokenLength, serializer);
+    sse_encode_opt_box_autoadd_text_language(self.stopWords, serializer);
+    sse_encode_list_String(self.customStopWords, serializer);
+    sse_encode_list_list_String(self.synonyms, serializer);
+    sse_encode_opt_box_autoadd_text_language(self.stemmer, serializer);
+    sse_encode_bool(self.asciiFolding, serializer);
+    sse_encode_bool(self.hangulJamo, serializer);
+    sse_encode_opt_box_autoadd_usize(self.minGram, serializer);
+    sse_encode_opt_box_autoadd_usize(self.maxGram, serializer);
+    sse_encode_bool(self.stripHtml, serializer);
+    sse_encode_bool(self.stripMarkdown, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_document(self, serializer);
-    }
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.score, serializer);
-    sse_encode_document(self.doc, serializer);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
@@ -813,6 +9741,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_upsert_outcome(UpsertOutcome self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_upsert_result(UpsertResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_upsert_outcome(self.outcome, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -820,14 +9762,271 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_vector_metric(VectorMetric self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_writer_options(WriterOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_opt_box_autoadd_usize(self.memoryBudgetBytes, serializer);
+    sse_encode_opt_box_autoadd_usize(self.numThreads, serializer);
+    sse_encode_opt_box_autoadd_reader_reload_policy(
+      self.reloadPolicy,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_docstore_compression(
+      self.docstoreCompression,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_usize(self.docstoreBlockSize, serializer);
+    sse_encode_opt_box_autoadd_storage_backend(self.storageBackend, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.protectedDataWaitMs, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.encryptionKey, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxIndexSizeBytes, serializer);
+    sse_encode_opt_String(self.evictOldestBy, serializer);
   }
 }
+
+@sealed
+class TantivyIndexImpl extends RustOpaque implements TantivyIndex {
+  // Not to be used by end users
+  TantivyIndexImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  TantivyIndexImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData =
+      RustArcStaticData(
+        rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TantivyIndex,
+        rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TantivyIndex,
+        rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TantivyIndexPtr,
+      );
+
+  Future<void> addDocument({required Document doc}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexAddDocument(
+        that: this,
+        doc: doc,
+      );
+
+  Future<BigInt> addDocumentChunked({required Document doc, BigInt? maxChars}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexAddDocumentChunked(
+        that: this,
+        doc: doc,
+        maxChars: maxChars,
+      );
+
+  Future<void> addDocumentsBatch({required List<Document> docs}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexAddDocumentsBatch(
+        that: this,
+        docs: docs,
+      );
+
+  Future<List<MarkdownHeading>> addMarkdownDocument({
+    required Document doc,
+    String? headingsField,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexAddMarkdownDocument(
+    that: this,
+    doc: doc,
+    headingsField: headingsField,
+  );
+
+  Future<String> aggregate({
+    required String query,
+    required String aggregations,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexAggregate(
+    that: this,
+    query: query,
+    aggregations: aggregations,
+  );
+
+  Future<void> close() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexClose(that: this);
+
+  void commit() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexCommit(that: this);
+
+  Future<BigInt> countDocuments({required String query}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexCountDocuments(
+        that: this,
+        query: query,
+      );
+
+  Future<List<DateBucket>> dateHistogram({
+    required String query,
+    required String dateField,
+    required DateInterval interval,
+    int? utcOffsetMinutes,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexDateHistogram(
+    that: this,
+    query: query,
+    dateField: dateField,
+    interval: interval,
+    utcOffsetMinutes: utcOffsetMinutes,
+  );
+
+  Future<BigInt> deleteByQuery({required String query}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexDeleteByQuery(
+        that: this,
+        query: query,
+      );
+
+  Future<void> deleteChunkedDocument({required String parentId}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexDeleteChunkedDocument(
+        that: this,
+        parentId: parentId,
+      );
+
+  Future<void> deleteDocument({required String id}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexDeleteDocument(
+        that: this,
+        id: id,
+      );
+
+  Future<void> deleteDocumentsBatch({required List<String> ids}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexDeleteDocumentsBatch(
+        that: this,
+        ids: ids,
+      );
+
+  Future<List<GeoSearchResult>> geoSearch({
+    required String query,
+    required String field,
+    required GeoFilter filter,
+    required bool sortByDistance,
+    required BigInt topK,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexGeoSearch(
+    that: this,
+    query: query,
+    field: field,
+    filter: filter,
+    sortByDistance: sortByDistance,
+    topK: topK,
+  );
+
+  Document? getDocumentById({required String id}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexGetDocumentById(
+        that: this,
+        id: id,
+      );
+
+  BigInt getDocumentVersion({required String id}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexGetDocumentVersion(
+        that: this,
+        id: id,
+      );
+
+  Future<IndexStats> getIndexStats() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexGetIndexStats(
+        that: this,
+      );
+
+  List<FieldDef> getSchema() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexGetSchema(that: this);
+
+  Future<List<SearchResult>> hybridSearch({
+    required String query,
+    required String vectorField,
+    required List<double> vector,
+    required double alpha,
+    required BigInt topK,
+    FusionMethod? fusion,
+    SearchQuery? filter,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexHybridSearch(
+    that: this,
+    query: query,
+    vectorField: vectorField,
+    vector: vector,
+    alpha: alpha,
+    topK: topK,
+    fusion: fusion,
+    filter: filter,
+  );
+
+  Future<List<SearchResult>> knnSearch({
+    required String field,
+    required List<double> vector,
+    required BigInt topK,
+    VectorMetric? metric,
+    SearchQuery? filter,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexKnnSearch(
+    that: this,
+    field: field,
+    vector: vector,
+    topK: topK,
+    metric: metric,
+    filter: filter,
+  );
+
+  String get name =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexName(that: this);
+
+  void refreshReader() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexRefreshReader(
+        that: this,
+      );
+
+  void rollback() =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexRollback(that: this);
+
+  Future<List<ChunkSearchResult>> searchChunked({
+    required String query,
+    required BigInt topK,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexSearchChunked(
+    that: this,
+    query: query,
+    topK: topK,
+  );
+
+  Future<List<SearchResult>> searchDocuments({
+    required String query,
+    required BigInt topK,
+    List<FieldBoost>? fields,
+    SearchQuery? filter,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexSearchDocuments(
+    that: this,
+    query: query,
+    topK: topK,
+    fields: fields,
+    filter: filter,
+  );
+
+  Future<GroupedSearchResponse> searchGrouped({
+    required String query,
+    required String groupField,
+    required BigInt topGroups,
+    required BigInt hitsPerGroup,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexSearchGrouped(
+    that: this,
+    query: query,
+    groupField: groupField,
+    topGroups: topGroups,
+    hitsPerGroup: hitsPerGroup,
+  );
+
+  Future<void> updateDocument({required Document doc}) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexUpdateDocument(
+        that: this,
+        doc: doc,
+      );
+
+  Future<BigInt> updateDocumentIfVersion({
+    required Document doc,
+    required BigInt expectedVersion,
+  }) =>
+      RustLib.instance.api.crateApiTantivyApiTantivyIndexUpdateDocumentIfVersion(
+        that: this,
+        doc: doc,
+        expectedVersion: expectedVersion,
+      );
+
+  Future<List<UpsertResult>> upsertDocumentsBatch({
+    required List<Document> docs,
+  }) => RustLib.instance.api.crateApiTantivyApiTantivyIndexUpsertDocumentsBatch(
+    that: this,
+    docs: docs,
+  );
+}
@@ -6,6 +6,7 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/error.dart';
 import 'api/simple.dart';
 import 'api/tantivy_api.dart';
 import 'dart:async';
@@ -21,121 +22,1412 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TantivyIndexPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  TantivyIndex dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  );
+
+  @protected
+  TantivyIndex dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  );
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+  @protected
+  TantivyIndex dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<BatchProgress> dco_decode_StreamSink_batch_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<DirectoryIndexProgress> dco_decode_StreamSink_directory_index_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<DirectorySyncProgress> dco_decode_StreamSink_directory_sync_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<List<SearchResult>> dco_decode_StreamSink_list_search_result_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AutoCommitPolicy dco_decode_auto_commit_policy(dynamic raw);
+
+  @protected
+  BaseTokenizer dco_decode_base_tokenizer(dynamic raw);
+
+  @protected
+  BatchProgress dco_decode_batch_progress(dynamic raw);
+
+  @protected
+  Bm25Params dco_decode_bm_25_params(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AutoCommitPolicy dco_decode_box_autoadd_auto_commit_policy(dynamic raw);
+
+  @protected
+  Bm25Params dco_decode_box_autoadd_bm_25_params(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  DocstoreCompression dco_decode_box_autoadd_docstore_compression(dynamic raw);
+
+  @protected
+  Document dco_decode_box_autoadd_document(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FusionMethod dco_decode_box_autoadd_fusion_method(dynamic raw);
+
+  @protected
+  GeoFilter dco_decode_box_autoadd_geo_filter(dynamic raw);
+
+  @protected
+  GeoPoint dco_decode_box_autoadd_geo_point(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  QueryParserConfig dco_decode_box_autoadd_query_parser_config(dynamic raw);
+
+  @protected
+  RangeBound dco_decode_box_autoadd_range_bound(dynamic raw);
+
+  @protected
+  ReaderReloadPolicy dco_decode_box_autoadd_reader_reload_policy(dynamic raw);
+
+  @protected
+  RecordOption dco_decode_box_autoadd_record_option(dynamic raw);
+
+  @protected
+  SearchQuery dco_decode_box_autoadd_search_query(dynamic raw);
+
+  @protected
+  StorageBackend dco_decode_box_autoadd_storage_backend(dynamic raw);
+
+  @protected
+  TextLanguage dco_decode_box_autoadd_text_language(dynamic raw);
+
+  @protected
+  TokenizerConfig dco_decode_box_autoadd_tokenizer_config(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+  @protected
+  VectorMetric dco_decode_box_autoadd_vector_metric(dynamic raw);
+
+  @protected
+  WriterOptions dco_decode_box_autoadd_writer_options(dynamic raw);
+
+  @protected
+  ChunkSearchResult dco_decode_chunk_search_result(dynamic raw);
+
+  @protected
+  DateBucket dco_decode_date_bucket(dynamic raw);
+
+  @protected
+  DateInterval dco_decode_date_interval(dynamic raw);
+
+  @protected
+  DirectoryIndexProgress dco_decode_directory_index_progress(dynamic raw);
+
+  @protected
+  DirectorySyncProgress dco_decode_directory_sync_progress(dynamic raw);
+
+  @protected
+  DocstoreCompression dco_decode_docstore_compression(dynamic raw);
+
+  @protected
+  Document dco_decode_document(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FacetCount dco_decode_facet_count(dynamic raw);
+
+  @protected
+  FacetSearchResponse dco_decode_facet_search_response(dynamic raw);
+
+  @protected
+  FieldBoost dco_decode_field_boost(dynamic raw);
+
+  @protected
+  FieldDef dco_decode_field_def(dynamic raw);
+
+  @protected
+  FieldType dco_decode_field_type(dynamic raw);
+
+  @protected
+  FieldValue dco_decode_field_value(dynamic raw);
+
+  @protected
+  FileDocument dco_decode_file_document(dynamic raw);
+
+  @protected
+  FusionMethod dco_decode_fusion_method(dynamic raw);
+
+  @protected
+  GeoFilter dco_decode_geo_filter(dynamic raw);
+
+  @protected
+  GeoPoint dco_decode_geo_point(dynamic raw);
+
+  @protected
+  GeoSearchResult dco_decode_geo_search_result(dynamic raw);
+
+  @protected
+  GroupedSearchResponse dco_decode_grouped_search_response(dynamic raw);
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw);
+
+  @protected
+  HitHandle dco_decode_hit_handle(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw);
+
+  @protected
+  IndexHealth dco_decode_index_health(dynamic raw);
+
+  @protected
+  IndexStats dco_decode_index_stats(dynamic raw);
+
+  @protected
+  LenientSearchResponse dco_decode_lenient_search_response(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw);
+
+  @protected
+  List<DateBucket> dco_decode_list_date_bucket(dynamic raw);
+
+  @protected
+  List<Document> dco_decode_list_document(dynamic raw);
+
+  @protected
+  List<FacetCount> dco_decode_list_facet_count(dynamic raw);
+
+  @protected
+  List<FieldBoost> dco_decode_list_field_boost(dynamic raw);
+
+  @protected
+  List<FieldDef> dco_decode_list_field_def(dynamic raw);
+
+  @protected
+  List<FieldValue> dco_decode_list_field_value(dynamic raw);
+
+  @protected
+  List<GeoSearchResult> dco_decode_list_geo_search_result(dynamic raw);
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw);
+
+  @protected
+  List<HitHandle> dco_decode_list_hit_handle(dynamic raw);
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+  @protected
+  List<MarkdownHeading> dco_decode_list_markdown_heading(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<SearchGroup> dco_decode_list_search_group(dynamic raw);
+
+  @protected
+  List<SearchQuery> dco_decode_list_search_query(dynamic raw);
+
+  @protected
+  List<SearchResult> dco_decode_list_search_result(dynamic raw);
+
+  @protected
+  List<TextLanguage> dco_decode_list_text_language(dynamic raw);
+
+  @protected
+  List<UpsertResult> dco_decode_list_upsert_result(dynamic raw);
+
+  @protected
+  MarkdownHeading dco_decode_markdown_heading(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AutoCommitPolicy? dco_decode_opt_box_autoadd_auto_commit_policy(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  DocstoreCompression? dco_decode_opt_box_autoadd_docstore_compression(
+    dynamic raw,
+  );
+
+  @protected
+  Document? dco_decode_opt_box_autoadd_document(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FusionMethod? dco_decode_opt_box_autoadd_fusion_method(dynamic raw);
+
+  @protected
+  GeoPoint? dco_decode_opt_box_autoadd_geo_point(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  RangeBound? dco_decode_opt_box_autoadd_range_bound(dynamic raw);
+
+  @protected
+  ReaderReloadPolicy? dco_decode_opt_box_autoadd_reader_reload_policy(
+    dynamic raw,
+  );
+
+  @protected
+  RecordOption? dco_decode_opt_box_autoadd_record_option(dynamic raw);
+
+  @protected
+  SearchQuery? dco_decode_opt_box_autoadd_search_query(dynamic raw);
+
+  @protected
+  StorageBackend? dco_decode_opt_box_autoadd_storage_backend(dynamic raw);
+
+  @protected
+  TextLanguage? dco_decode_opt_box_autoadd_text_language(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+  @protected
+  VectorMetric? dco_decode_opt_box_autoadd_vector_metric(dynamic raw);
+
+  @protected
+  WriterOptions? dco_decode_opt_box_autoadd_writer_options(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<FieldBoost>? dco_decode_opt_list_field_boost(dynamic raw);
+
+  @protected
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  QueryKind dco_decode_query_kind(dynamic raw);
+
+  @protected
+  QueryParserConfig dco_decode_query_parser_config(dynamic raw);
+
+  @protected
+  RangeBound dco_decode_range_bound(dynamic raw);
+
+  @protected
+  ReaderReloadPolicy dco_decode_reader_reload_policy(dynamic raw);
+
+  @protected
+  RecordOption dco_decode_record_option(dynamic raw);
+
+  @protected (String, String)
+  dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  RepairReport dco_decode_repair_report(dynamic raw);
+
+  @protected
+  SearchGroup dco_decode_search_group(dynamic raw);
+
+  @protected
+  SearchQuery dco_decode_search_query(dynamic raw);
+
+  @protected
+  SearchResponse dco_decode_search_response(dynamic raw);
+
+  @protected
+  SearchResult dco_decode_search_result(dynamic raw);
+
+  @protected
+  StorageBackend dco_decode_storage_backend(dynamic raw);
+
+  @protected
+  TantivyError dco_decode_tantivy_error(dynamic raw);
+
+  @protected
+  TantivyErrorKind dco_decode_tantivy_error_kind(dynamic raw);
+
+  @protected
+  TextLanguage dco_decode_text_language(dynamic raw);
+
+  @protected
+  TokenizerConfig dco_decode_tokenizer_config(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UpsertOutcome dco_decode_upsert_outcome(dynamic raw);
+
+  @protected
+  UpsertResult dco_decode_upsert_result(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  VectorMetric dco_decode_vector_metric(dynamic raw);
+
+  @protected
+  WriterOptions dco_decode_writer_options(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  TantivyIndex sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TantivyIndex sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TantivyIndex sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<BatchProgress> sse_decode_StreamSink_batch_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<DirectoryIndexProgress> sse_decode_StreamSink_directory_index_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<DirectorySyncProgress> sse_decode_StreamSink_directory_sync_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<List<SearchResult>> sse_decode_StreamSink_list_search_result_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AutoCommitPolicy sse_decode_auto_commit_policy(SseDeserializer deserializer);
+
+  @protected
+  BaseTokenizer sse_decode_base_tokenizer(SseDeserializer deserializer);
+
+  @protected
+  BatchProgress sse_decode_batch_progress(SseDeserializer deserializer);
+
+  @protected
+  Bm25Params sse_decode_bm_25_params(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AutoCommitPolicy sse_decode_box_autoadd_auto_commit_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Bm25Params sse_decode_box_autoadd_bm_25_params(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  DocstoreCompression sse_decode_box_autoadd_docstore_compression(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Document sse_decode_box_autoadd_document(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FusionMethod sse_decode_box_autoadd_fusion_method(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GeoFilter sse_decode_box_autoadd_geo_filter(SseDeserializer deserializer);
+
+  @protected
+  GeoPoint sse_decode_box_autoadd_geo_point(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  QueryParserConfig sse_decode_box_autoadd_query_parser_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RangeBound sse_decode_box_autoadd_range_bound(SseDeserializer deserializer);
+
+  @protected
+  ReaderReloadPolicy sse_decode_box_autoadd_reader_reload_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordOption sse_decode_box_autoadd_record_option(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchQuery sse_decode_box_autoadd_search_query(SseDeserializer deserializer);
+
+  @protected
+  StorageBackend sse_decode_box_autoadd_storage_backend(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextLanguage sse_decode_box_autoadd_text_language(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TokenizerConfig sse_decode_box_autoadd_tokenizer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  VectorMetric sse_decode_box_autoadd_vector_metric(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriterOptions sse_decode_box_autoadd_writer_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChunkSearchResult sse_decode_chunk_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DateBucket sse_decode_date_bucket(SseDeserializer deserializer);
+
+  @protected
+  DateInterval sse_decode_date_interval(SseDeserializer deserializer);
+
+  @protected
+  DirectoryIndexProgress sse_decode_directory_index_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DirectorySyncProgress sse_decode_directory_sync_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DocstoreCompression sse_decode_docstore_compression(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Document sse_decode_document(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FacetCount sse_decode_facet_count(SseDeserializer deserializer);
+
+  @protected
+  FacetSearchResponse sse_decode_facet_search_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FieldBoost sse_decode_field_boost(SseDeserializer deserializer);
+
+  @protected
+  FieldDef sse_decode_field_def(SseDeserializer deserializer);
+
+  @protected
+  FieldType sse_decode_field_type(SseDeserializer deserializer);
+
+  @protected
+  FieldValue sse_decode_field_value(SseDeserializer deserializer);
+
+  @protected
+  FileDocument sse_decode_file_document(SseDeserializer deserializer);
+
+  @protected
+  FusionMethod sse_decode_fusion_method(SseDeserializer deserializer);
+
+  @protected
+  GeoFilter sse_decode_geo_filter(SseDeserializer deserializer);
+
+  @protected
+  GeoPoint sse_decode_geo_point(SseDeserializer deserializer);
+
+  @protected
+  GeoSearchResult sse_decode_geo_search_result(SseDeserializer deserializer);
+
+  @protected
+  GroupedSearchResponse sse_decode_grouped_search_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer);
+
+  @protected
+  HitHandle sse_decode_hit_handle(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer);
+
+  @protected
+  IndexHealth sse_decode_index_health(SseDeserializer deserializer);
+
+  @protected
+  IndexStats sse_decode_index_stats(SseDeserializer deserializer);
+
+  @protected
+  LenientSearchResponse sse_decode_lenient_search_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ChunkSearchResult> sse_decode_list_chunk_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DateBucket> sse_decode_list_date_bucket(SseDeserializer deserializer);
+
+  @protected
+  List<Document> sse_decode_list_document(SseDeserializer deserializer);
+
+  @protected
+  List<FacetCount> sse_decode_list_facet_count(SseDeserializer deserializer);
+
+  @protected
+  List<FieldBoost> sse_decode_list_field_boost(SseDeserializer deserializer);
+
+  @protected
+  List<FieldDef> sse_decode_list_field_def(SseDeserializer deserializer);
+
+  @protected
+  List<FieldValue> sse_decode_list_field_value(SseDeserializer deserializer);
+
+  @protected
+  List<GeoSearchResult> sse_decode_list_geo_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HitHandle> sse_decode_list_hit_handle(SseDeserializer deserializer);
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<MarkdownHeading> sse_decode_list_markdown_heading(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SearchGroup> sse_decode_list_search_group(SseDeserializer deserializer);
+
+  @protected
+  List<SearchQuery> sse_decode_list_search_query(SseDeserializer deserializer);
+
+  @protected
+  List<SearchResult> sse_decode_list_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TextLanguage> sse_decode_list_text_language(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UpsertResult> sse_decode_list_upsert_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MarkdownHeading sse_decode_markdown_heading(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AutoCommitPolicy? sse_decode_opt_box_autoadd_auto_commit_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  DocstoreCompression? sse_decode_opt_box_autoadd_docstore_compression(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FusionMethod? sse_decode_opt_box_autoadd_fusion_method(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GeoPoint? sse_decode_opt_box_autoadd_geo_point(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  RangeBound? sse_decode_opt_box_autoadd_range_bound(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReaderReloadPolicy? sse_decode_opt_box_autoadd_reader_reload_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordOption? sse_decode_opt_box_autoadd_record_option(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchQuery? sse_decode_opt_box_autoadd_search_query(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StorageBackend? sse_decode_opt_box_autoadd_storage_backend(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextLanguage? sse_decode_opt_box_autoadd_text_language(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  VectorMetric? sse_decode_opt_box_autoadd_vector_metric(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriterOptions? sse_decode_opt_box_autoadd_writer_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FieldBoost>? sse_decode_opt_list_field_boost(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  QueryKind sse_decode_query_kind(SseDeserializer deserializer);
+
+  @protected
+  QueryParserConfig sse_decode_query_parser_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RangeBound sse_decode_range_bound(SseDeserializer deserializer);
+
+  @protected
+  ReaderReloadPolicy sse_decode_reader_reload_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordOption sse_decode_record_option(SseDeserializer deserializer);
+
+  @protected (String, String)
+  sse_decode_record_string_string(SseDeserializer deserializer);
+
+  @protected
+  RepairReport sse_decode_repair_report(SseDeserializer deserializer);
+
+  @protected
+  SearchGroup sse_decode_search_group(SseDeserializer deserializer);
+
+  @protected
+  SearchQuery sse_decode_search_query(SseDeserializer deserializer);
+
+  @protected
+  SearchResponse sse_decode_search_response(SseDeserializer deserializer);
+
+  @protected
+  SearchResult sse_decode_search_result(SseDeserializer deserializer);
+
+  @protected
+  StorageBackend sse_decode_storage_backend(SseDeserializer deserializer);
+
+  @protected
+  TantivyError sse_decode_tantivy_error(SseDeserializer deserializer);
+
+  @protected
+  TantivyErrorKind sse_decode_tantivy_error_kind(SseDeserializer deserializer);
+
+  @protected
+  TextLanguage sse_decode_text_language(SseDeserializer deserializer);
+
+  @protected
+  TokenizerConfig sse_decode_tokenizer_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UpsertOutcome sse_decode_upsert_outcome(SseDeserializer deserializer);
+
+  @protected
+  UpsertResult sse_decode_upsert_result(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  VectorMetric sse_decode_vector_metric(SseDeserializer deserializer);
+
+  @protected
+  WriterOptions sse_decode_writer_options(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    TantivyIndex self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_batch_progress_Sse(
+    RustStreamSink<BatchProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_directory_index_progress_Sse(
+    RustStreamSink<DirectoryIndexProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_directory_sync_progress_Sse(
+    RustStreamSink<DirectorySyncProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_import_progress_Sse(
+    RustStreamSink<ImportProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_search_result_Sse(
+    RustStreamSink<List<SearchResult>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auto_commit_policy(
+    AutoCommitPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_base_tokenizer(BaseTokenizer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_progress(BatchProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bm_25_params(Bm25Params self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_auto_commit_policy(
+    AutoCommitPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bm_25_params(
+    Bm25Params self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_docstore_compression(
+    DocstoreCompression self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_document(Document self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fusion_method(
+    FusionMethod self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_geo_filter(
+    GeoFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_geo_point(
+    GeoPoint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_query_parser_config(
+    QueryParserConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_range_bound(
+    RangeBound self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reader_reload_policy(
+    ReaderReloadPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_record_option(
+    RecordOption self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_search_query(
+    SearchQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_storage_backend(
+    StorageBackend self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_text_language(
+    TextLanguage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_tokenizer_config(
+    TokenizerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_vector_metric(
+    VectorMetric self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_writer_options(
+    WriterOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chunk_search_result(
+    ChunkSearchResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_date_bucket(DateBucket self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_date_interval(DateInterval self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_directory_index_progress(
+    DirectoryIndexProgress self,
+    SseSerializer serializer,
+  );
+
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+  void sse_encode_directory_sync_progress(
+    DirectorySyncProgress self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String dco_decode_String(dynamic raw);
+  void sse_encode_docstore_compression(
+    DocstoreCompression self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Document dco_decode_box_autoadd_document(dynamic raw);
+  void sse_encode_document(Document self, SseSerializer serializer);
 
   @protected
-  Document dco_decode_document(dynamic raw);
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  void sse_encode_facet_count(FacetCount self, SseSerializer serializer);
 
   @protected
-  List<Document> dco_decode_list_document(dynamic raw);
+  void sse_encode_facet_search_response(
+    FacetSearchResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_field_boost(FieldBoost self, SseSerializer serializer);
 
   @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw);
+  void sse_encode_field_def(FieldDef self, SseSerializer serializer);
 
   @protected
-  Document? dco_decode_opt_box_autoadd_document(dynamic raw);
+  void sse_encode_field_type(FieldType self, SseSerializer serializer);
 
   @protected
-  SearchResult dco_decode_search_result(dynamic raw);
+  void sse_encode_field_value(FieldValue self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_file_document(FileDocument self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_fusion_method(FusionMethod self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_geo_filter(GeoFilter self, SseSerializer serializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_geo_point(GeoPoint self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_geo_search_result(
+    GeoSearchResult self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Document sse_decode_box_autoadd_document(SseDeserializer deserializer);
+  void sse_encode_grouped_search_response(
+    GroupedSearchResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Document sse_decode_document(SseDeserializer deserializer);
+  void sse_encode_highlight_range(
+    HighlightRange self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_hit_handle(HitHandle self, SseSerializer serializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  List<Document> sse_decode_list_document(SseDeserializer deserializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_import_progress(
+    ImportProgress self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<SearchResult> sse_decode_list_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_index_health(IndexHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_index_stats(IndexStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lenient_search_response(
+    LenientSearchResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer);
+  void sse_encode_list_chunk_search_result(
+    List<ChunkSearchResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_date_bucket(
+    List<DateBucket> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_document(List<Document> self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_facet_count(
+    List<FacetCount> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_field_boost(
+    List<FieldBoost> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_field_def(List<FieldDef> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_field_value(
+    List<FieldValue> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_list_geo_search_result(
+    List<GeoSearchResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_document(Document self, SseSerializer serializer);
+  void sse_encode_list_highlight_range(
+    List<HighlightRange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_document(Document self, SseSerializer serializer);
+  void sse_encode_list_hit_handle(
+    List<HitHandle> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_list_list_String(
+    List<List<String>> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_list_markdown_heading(
+    List<MarkdownHeading> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_document(List<Document> self, SseSerializer serializer);
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_list_prim_u_8_strict(
@@ -143,41 +1435,295 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_search_group(
+    List<SearchGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_search_query(
+    List<SearchQuery> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_search_result(
     List<SearchResult> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_text_language(
+    List<TextLanguage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_upsert_result(
+    List<UpsertResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_markdown_heading(
+    MarkdownHeading self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_auto_commit_policy(
+    AutoCommitPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_docstore_compression(
+    DocstoreCompression? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_document(
     Document? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_fusion_method(
+    FusionMethod? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_geo_point(
+    GeoPoint? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_range_bound(
+    RangeBound? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_reader_reload_policy(
+    ReaderReloadPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_record_option(
+    RecordOption? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_search_query(
+    SearchQuery? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_storage_backend(
+    StorageBackend? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_language(
+    TextLanguage? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_vector_metric(
+    VectorMetric? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_writer_options(
+    WriterOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_field_boost(
+    List<FieldBoost>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_f_32_strict(
+    Float32List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_query_kind(QueryKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_parser_config(
+    QueryParserConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_range_bound(RangeBound self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reader_reload_policy(
+    ReaderReloadPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_option(RecordOption self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_repair_report(RepairReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_group(SearchGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_query(SearchQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_response(
+    SearchResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_search_result(SearchResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_storage_backend(
+    StorageBackend self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tantivy_error(TantivyError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tantivy_error_kind(
+    TantivyErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_text_language(TextLanguage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tokenizer_config(
+    TokenizerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_upsert_outcome(UpsertOutcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_upsert_result(UpsertResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_vector_metric(VectorMetric self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_writer_options(WriterOptions self, SseSerializer serializer);
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    int ptr,
+  ) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+        ptr,
+      );
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    int ptr,
+  ) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+        ptr,
+      );
 }
 
 @JS('wasm_bindgen')
@@ -185,4 +1731,12 @@ external RustLibWasmModule get wasmModule;
 
 @JS()
 @anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    int ptr,
+  );
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTantivyIndex(
+    int ptr,
+  );
+}
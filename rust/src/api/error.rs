@@ -0,0 +1,167 @@
+use std::fmt;
+use tantivy::query::QueryParserError;
+
+// Dart에서 오류 종류별로 처리할 수 있도록 구분한 오류 코드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TantivyErrorKind {
+    // 인덱스를 초기화하지 않았거나 이미 닫은 경우
+    NotInitialized,
+    // 같은 이름의 인덱스가 다른 경로로 열려 있거나, 같은 디렉토리가 다른 writer 설정으로 열려 있는 경우
+    AlreadyOpen,
+    // 다른 프로세스나 writer가 인덱스 잠금을 가지고 있는 경우
+    LockBusy,
+    // 쿼리 문법 오류
+    QueryParse,
+    // 다른 호출자의 batch가 진행 중이라 commit할 수 없는 경우
+    BatchInProgress,
+    // 기존 인덱스의 스키마가 요청한 스키마와 다른 경우
+    SchemaMismatch,
+    // 스키마에 없는 필드를 지정한 경우
+    FieldNotFound,
+    // 잘못된 인자 (빈 값, 범위를 벗어난 값, 필드 타입 불일치 등)
+    InvalidArgument,
+    // 인덱스 파일이 손상되었거나 호환되지 않는 경우
+    Corrupted,
+    // 검색 결과 핸들의 검색 시점이 오래되어 문서를 가져올 수 없는 경우 (다시 검색해야 함)
+    Expired,
+    // cancel_operation으로 작업이 취소된 경우
+    Cancelled,
+    // update_document_if_version에서 저장된 문서의 버전이 기대한 버전과 다른 경우
+    VersionConflict,
+    // 암호화한 인덱스를 키 없이 열거나, 키가 다르거나, 암호화하지 않은 인덱스에 키를 지정한 경우
+    EncryptionKey,
+    // 인덱스가 WriterOptions.max_index_size_bytes로 지정한 최대 크기를 넘은 경우
+    QuotaExceeded,
+    // 파일 접근이 거부된 경우 (iOS 데이터 보호로 기기가 잠긴 동안 등, 잠금이 풀린 뒤 다시 시도)
+    PermissionDenied,
+    // 파일 입출력 오류
+    Io,
+    // 그 밖의 내부 오류
+    Internal,
+}
+
+// Flutter로 전달되는 오류 (Dart에서는 예외로 throw됨)
+// kind로 오류 종류를 구분하며, message는 사람이 읽을 수 있는 설명입니다.
+#[derive(Debug, Clone)]
+pub struct TantivyError {
+    pub kind: TantivyErrorKind,
+    pub message: String,
+    // QueryParse 오류가 난 쿼리 문자열 안의 위치 (Dart String의 UTF-16 인덱스, 알 수 없으면 None)
+    pub position: Option<usize>,
+}
+
+impl TantivyError {
+    pub(crate) fn new(kind: TantivyErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            position: None,
+        }
+    }
+}
+
+impl fmt::Display for TantivyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TantivyError {}
+
+// 내부 오류를 종류별로 분류
+// anyhow!로 직접 만든 오류는 입력 검증 오류이므로 InvalidArgument로 취급합니다.
+impl From<anyhow::Error> for TantivyError {
+    fn from(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<TantivyError>() {
+            Ok(error) => return error,
+            Err(error) => error,
+        };
+
+        if let Some(query_error) = error.downcast_ref::<QueryParserError>() {
+            let position = syntax_error_position(query_error);
+            return TantivyError {
+                position,
+                ..TantivyError::new(TantivyErrorKind::QueryParse, error.to_string())
+            };
+        }
+
+        let kind = if let Some(error) = error.downcast_ref::<tantivy::TantivyError>() {
+            tantivy_error_kind(error)
+        } else if let Some(error) = error.downcast_ref::<std::io::Error>() {
+            io_error_kind(error)
+        } else {
+            TantivyErrorKind::InvalidArgument
+        };
+        TantivyError::new(kind, error.to_string())
+    }
+}
+
+impl From<tantivy::TantivyError> for TantivyError {
+    fn from(error: tantivy::TantivyError) -> Self {
+        anyhow::Error::from(error).into()
+    }
+}
+
+impl From<std::io::Error> for TantivyError {
+    fn from(error: std::io::Error) -> Self {
+        anyhow::Error::from(error).into()
+    }
+}
+
+// 문법 오류가 난 위치
+// tantivy의 SyntaxError는 쿼리 문자열만 담고 있으므로 lenient 파서로 다시 파싱해 첫 오류 위치를 찾습니다.
+fn syntax_error_position(error: &QueryParserError) -> Option<usize> {
+    let QueryParserError::SyntaxError(query) = error else {
+        return None;
+    };
+    let (_, errors) = tantivy::query_grammar::parse_query_lenient(query);
+    let pos = errors.first()?.pos;
+    Some(query.get(..pos)?.encode_utf16().count())
+}
+
+fn io_error_kind(error: &std::io::Error) -> TantivyErrorKind {
+    if error.kind() == std::io::ErrorKind::PermissionDenied {
+        TantivyErrorKind::PermissionDenied
+    } else {
+        TantivyErrorKind::Io
+    }
+}
+
+// tantivy 오류에 담긴 파일 입출력 오류
+pub(crate) fn tantivy_io_error(error: &tantivy::TantivyError) -> Option<&std::io::Error> {
+    use tantivy::directory::error::{LockError, OpenDirectoryError, OpenReadError, OpenWriteError};
+    use tantivy::TantivyError;
+
+    match error {
+        TantivyError::IoError(io_error)
+        | TantivyError::OpenReadError(OpenReadError::IoError { io_error, .. })
+        | TantivyError::OpenWriteError(OpenWriteError::IoError { io_error, .. })
+        | TantivyError::OpenDirectoryError(OpenDirectoryError::IoError { io_error, .. })
+        | TantivyError::LockFailure(LockError::IoError(io_error), _) => Some(io_error),
+        _ => None,
+    }
+}
+
+fn tantivy_error_kind(error: &tantivy::TantivyError) -> TantivyErrorKind {
+    use tantivy::TantivyError::*;
+
+    if tantivy_io_error(error)
+        .is_some_and(|io_error| io_error_kind(io_error) == TantivyErrorKind::PermissionDenied)
+    {
+        return TantivyErrorKind::PermissionDenied;
+    }
+    match error {
+        LockFailure(..) => TantivyErrorKind::LockBusy,
+        OpenDirectoryError(_) | OpenReadError(_) | OpenWriteError(_) | IoError(_) => {
+            TantivyErrorKind::Io
+        }
+        DataCorruption(_) | IncompatibleIndex(_) | DeserializeError(_) => {
+            TantivyErrorKind::Corrupted
+        }
+        FieldNotFound(_) => TantivyErrorKind::FieldNotFound,
+        InvalidArgument(_) | SchemaError(_) | AggregationError(_) => {
+            TantivyErrorKind::InvalidArgument
+        }
+        _ => TantivyErrorKind::Internal,
+    }
+}
@@ -1,2 +1,3 @@
+pub mod error;
 pub mod simple;
 pub mod tantivy_api;
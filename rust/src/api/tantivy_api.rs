@@ -1,288 +1,8259 @@
+use crate::api::error::{tantivy_io_error, TantivyError, TantivyErrorKind};
+#[cfg(feature = "chinese")]
+use crate::chinese_tokenizer::ChineseTokenizer;
+use crate::cjk_tokenizer::CjkBigramTokenizer;
+use crate::encrypted_directory::{self, EncryptedDirectory};
+use crate::file_directory::FileDirectory;
+use crate::file_walk::{content_hash, walk_files};
+use crate::frb_generated::StreamSink;
+use crate::geo::{self, BoundingBox, GeoHashTokenizer};
+use crate::hangul_jamo::HangulJamoFilter;
+#[cfg(feature = "japanese")]
+use crate::japanese_tokenizer::JapaneseTokenizer;
+use crate::markup_tokenizer::{Markup, MarkupStripTokenizer};
+use crate::protected_directory::{self, ProtectedDirectory};
+use crate::synonym_filter::SynonymFilter;
+use crate::text_extract::{
+    extract_file_text, heading_anchors, markdown_headings, mime_type_from_extension,
+};
 use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
-use tantivy::collector::TopDocs;
-use tantivy::query::QueryParser;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::net::{IpAddr, Ipv6Addr};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::{Duration, Instant};
+use tantivy::aggregation::agg_req::Aggregations;
+use tantivy::aggregation::{AggregationCollector, AggregationLimitsGuard};
+use tantivy::collector::{
+    Collector, Count, DocSetCollector, FacetCollector, SegmentCollector, TopDocs,
+};
+use tantivy::columnar::{BytesColumn, StrColumn};
+use tantivy::directory::{Directory, MmapDirectory, RamDirectory, INDEX_WRITER_LOCK, META_LOCK};
+use tantivy::fastfield::{AliveBitSet, Column};
+use tantivy::index::SegmentComponent;
+use tantivy::postings::Postings;
+use tantivy::query::{
+    AllQuery, Bm25StatisticsProvider, BooleanQuery, BoostQuery, ConstScoreQuery, ExistsQuery,
+    FuzzyTermQuery, MoreLikeThisQuery, Occur, PhrasePrefixQuery, PhraseQuery, Query, QueryParser,
+    RangeQuery, RegexQuery, TermQuery,
+};
 use tantivy::schema::*;
-use tantivy::{doc, Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::store::{Compressor, StoreReader, ZstdCompressor};
+use tantivy::tokenizer::{
+    AsciiFoldingFilter, Language, LowerCaser, NgramTokenizer, RawTokenizer, RemoveLongFilter,
+    SimpleTokenizer, Stemmer, StopWordFilter, TextAnalyzer, TextAnalyzerBuilder, Tokenizer,
+    TokenizerManager, WhitespaceTokenizer,
+};
+use tantivy::{
+    DateTime, DocAddress, DocId, DocSet, Document as _, HasLen, Index, IndexReader, IndexSettings,
+    IndexWriter, Order, ReloadPolicy, Searcher, TantivyDocument, Term,
+};
+use whatlang::Lang;
 
 // Flutter에서 사용할 문서 구조체
+#[flutter_rust_bridge::frb]
 #[derive(Debug, Clone)]
 pub struct Document {
     pub id: String,
     pub text: String,
+    // 사용자 정의 스키마의 추가 필드 값 (id, text 외)
+    #[frb(default = "const []")]
+    pub fields: Vec<FieldValue>,
+    // 검색되지 않고 저장만 되는 부가 정보 (썸네일 경로, JSON 문자열 등)
+    #[frb(default = "const {}")]
+    pub metadata: HashMap<String, String>,
+}
+
+// 문서의 개별 필드 값
+// 필드 타입에 맞는 값 하나만 지정합니다 (Text/String/Facet/IpAddr은 text_value, 숫자는 해당 타입의 값).
+// 같은 이름의 FieldValue를 여러 개 지정하면 여러 값을 가진 필드가 됩니다 (예: 태그).
+#[derive(Debug, Clone)]
+pub struct FieldValue {
+    pub name: String,
+    pub text_value: Option<String>,
+    pub i64_value: Option<i64>,
+    pub u64_value: Option<u64>,
+    pub f64_value: Option<f64>,
+    // Date 필드 값 (Unix epoch 기준 밀리초)
+    pub date_value: Option<i64>,
+    pub bool_value: Option<bool>,
+    // Vector 필드 값 (임베딩)
+    pub vector_value: Option<Vec<f32>>,
+    // GeoPoint 필드 값 (위도/경도)
+    pub geo_value: Option<GeoPoint>,
+    // Bytes 필드 값
+    pub bytes_value: Option<Vec<u8>>,
+}
+
+// 위도(-90 ~ 90)와 경도(-180 ~ 180) 좌표
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl GeoPoint {
+    fn as_tuple(self) -> (f64, f64) {
+        (self.lat, self.lon)
+    }
+
+    fn validate(self) -> Result<(f64, f64)> {
+        let point = self.as_tuple();
+        if !geo::is_valid_point(point) {
+            return Err(anyhow!(
+                "Invalid coordinates ({}, {}): latitude must be between -90 and 90 and longitude between -180 and 180",
+                self.lat,
+                self.lon
+            ));
+        }
+        Ok(point)
+    }
+}
+
+impl FieldValue {
+    // Rust 내부에서 사용하는 값 생성 헬퍼
+    fn empty(name: String) -> Self {
+        FieldValue {
+            name,
+            text_value: None,
+            i64_value: None,
+            u64_value: None,
+            f64_value: None,
+            date_value: None,
+            bool_value: None,
+            vector_value: None,
+            geo_value: None,
+            bytes_value: None,
+        }
+    }
+}
+
+// 스키마 필드 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    // 토큰화되어 전문 검색에 사용되는 텍스트
+    Text,
+    // 토큰화 없이 그대로 색인되는 문자열 (ID, 태그 등)
+    String,
+    // 부호 있는 64비트 정수 (타임스탬프 등)
+    I64,
+    // 부호 없는 64비트 정수 (카운트 등)
+    U64,
+    // 64비트 부동소수점 (가격 등)
+    F64,
+    // 날짜/시간 (Unix epoch 기준 밀리초로 주고받음)
+    Date,
+    // 계층형 카테고리 경로 (예: /books/fiction), text_value로 주고받음
+    Facet,
+    // 임베딩 벡터 (f32 배열), knn_search로 가장 가까운 문서를 찾음
+    // 항상 fast field로 저장되며 indexed는 무시됩니다. 한 필드의 벡터는 모두 같은 차원이어야 합니다.
+    Vector,
+    // 위치 (위도/경도), geo_value로 주고받으며 geo_search로 반경/영역 안의 문서를 찾음
+    // 항상 색인되고 fast field로 저장되며 indexed, fast는 무시됩니다.
+    GeoPoint,
+    // IPv4/IPv6 주소 (예: 192.168.0.1, ::1), text_value로 주고받으며 Range 쿼리로 주소 범위를 검색
+    IpAddr,
+    // 참/거짓 (보관 여부 등), 쿼리 문법에서는 is_archived:false와 같이 검색
+    Bool,
+    // 검색되지 않고 저장만 되는 바이너리 값 (protobuf, 암호화된 데이터 등), bytes_value로 주고받음
+    // 항상 저장되며 stored, indexed, fast는 무시됩니다.
+    Bytes,
+}
+
+// 텍스트 분석 언어 (어간 추출 및 불용어 제거에 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextLanguage {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl TextLanguage {
+    const ALL: [TextLanguage; 18] = [
+        TextLanguage::Arabic,
+        TextLanguage::Danish,
+        TextLanguage::Dutch,
+        TextLanguage::English,
+        TextLanguage::Finnish,
+        TextLanguage::French,
+        TextLanguage::German,
+        TextLanguage::Greek,
+        TextLanguage::Hungarian,
+        TextLanguage::Italian,
+        TextLanguage::Norwegian,
+        TextLanguage::Portuguese,
+        TextLanguage::Romanian,
+        TextLanguage::Russian,
+        TextLanguage::Spanish,
+        TextLanguage::Swedish,
+        TextLanguage::Tamil,
+        TextLanguage::Turkish,
+    ];
+
+    fn tantivy_language(self) -> Language {
+        match self {
+            TextLanguage::Arabic => Language::Arabic,
+            TextLanguage::Danish => Language::Danish,
+            TextLanguage::Dutch => Language::Dutch,
+            TextLanguage::English => Language::English,
+            TextLanguage::Finnish => Language::Finnish,
+            TextLanguage::French => Language::French,
+            TextLanguage::German => Language::German,
+            TextLanguage::Greek => Language::Greek,
+            TextLanguage::Hungarian => Language::Hungarian,
+            TextLanguage::Italian => Language::Italian,
+            TextLanguage::Norwegian => Language::Norwegian,
+            TextLanguage::Portuguese => Language::Portuguese,
+            TextLanguage::Romanian => Language::Romanian,
+            TextLanguage::Russian => Language::Russian,
+            TextLanguage::Spanish => Language::Spanish,
+            TextLanguage::Swedish => Language::Swedish,
+            TextLanguage::Tamil => Language::Tamil,
+            TextLanguage::Turkish => Language::Turkish,
+        }
+    }
+
+    // whatlang 언어 감지 결과 중 이 언어에 해당하는 값
+    fn detected_lang(self) -> Lang {
+        match self {
+            TextLanguage::Arabic => Lang::Ara,
+            TextLanguage::Danish => Lang::Dan,
+            TextLanguage::Dutch => Lang::Nld,
+            TextLanguage::English => Lang::Eng,
+            TextLanguage::Finnish => Lang::Fin,
+            TextLanguage::French => Lang::Fra,
+            TextLanguage::German => Lang::Deu,
+            TextLanguage::Greek => Lang::Ell,
+            TextLanguage::Hungarian => Lang::Hun,
+            TextLanguage::Italian => Lang::Ita,
+            TextLanguage::Norwegian => Lang::Nob,
+            TextLanguage::Portuguese => Lang::Por,
+            TextLanguage::Romanian => Lang::Ron,
+            TextLanguage::Russian => Lang::Rus,
+            TextLanguage::Spanish => Lang::Spa,
+            TextLanguage::Swedish => Lang::Swe,
+            TextLanguage::Tamil => Lang::Tam,
+            TextLanguage::Turkish => Lang::Tur,
+        }
+    }
+
+    // 스키마에 저장되는 토크나이저 이름 (예: lang_english)
+    fn tokenizer_name(self) -> String {
+        format!("lang_{:?}", self).to_lowercase()
+    }
+
+    // 소문자 변환, 불용어 제거(지원되는 언어만), 어간 추출을 수행하는 분석기
+    fn analyzer(self) -> TextAnalyzer {
+        let language = self.tantivy_language();
+        let builder = TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .dynamic();
+        let builder = match StopWordFilter::new(language) {
+            Some(stop_words) => builder.filter_dynamic(stop_words),
+            None => builder,
+        };
+        builder.filter_dynamic(Stemmer::new(language)).build()
+    }
+}
+
+// 한중일 문자를 2-gram으로 분리하는 분석기 이름
+const CJK_BIGRAM_TOKENIZER: &str = "cjk_bigram";
+
+// 단어 단위로 분리한 뒤 한글을 자모로 분해하는 분석기 이름 (입력 중 검색용)
+const KOREAN_JAMO_TOKENIZER: &str = "korean_jamo";
+
+// HTML 태그를 제거한 본문을 기본 분석기와 같이 분리하는 분석기 이름 (리치 텍스트 편집기의 HTML 등)
+const HTML_TOKENIZER: &str = "html";
+
+// 마크다운 문법을 제거한 본문을 기본 분석기와 같이 분리하는 분석기 이름 (메모 앱의 마크다운 노트 등)
+const MARKDOWN_TOKENIZER: &str = "markdown";
+
+// GeoPoint 필드의 위치를 지오해시 접두어로 색인하는 분석기 이름 (GeoPoint 전용)
+const GEO_TOKENIZER: &str = "geo";
+
+// 형태소 분석으로 일본어를 단어 단위로 분리하는 분석기 이름 (japanese 기능 필요)
+#[cfg(feature = "japanese")]
+const JAPANESE_TOKENIZER: &str = "japanese";
+
+// jieba로 중국어를 단어 단위로 분리하는 분석기 이름 (chinese 기능 필요)
+#[cfg(feature = "chinese")]
+const CHINESE_TOKENIZER: &str = "chinese";
+
+// 사용자 정의 분석기의 기본 토크나이저
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseTokenizer {
+    // 공백과 구두점 기준으로 분리
+    Simple,
+    // 공백 기준으로만 분리
+    Whitespace,
+    // 분리하지 않고 전체를 하나의 토큰으로 사용
+    Raw,
+    // 한중일 문자는 2-gram, 그 외는 단어 단위로 분리
+    CjkBigram,
+    // min_gram~max_gram 길이의 모든 n-gram으로 분리
+    Ngram,
+    // 일본어 형태소 분석으로 단어 단위 분리 (japanese 기능을 켜고 빌드한 경우에만 사용 가능)
+    Japanese,
+    // jieba로 중국어 단어 단위 분리 (chinese 기능을 켜고 빌드한 경우에만 사용 가능)
+    Chinese,
+}
+
+// Flutter에서 이름을 붙여 등록하는 분석기 파이프라인
+// HTML 태그/마크다운 문법 제거 -> 기본 토크나이저 -> 길이 제한 -> 소문자 변환 -> 불용어 제거 -> 동의어 확장 -> 어간 추출 -> 악센트 제거 -> 한글 자모 분해 순서로 적용됩니다.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct TokenizerConfig {
+    pub base: BaseTokenizer,
+    #[frb(default = true)]
+    pub lowercase: bool,
+    // 이 길이(바이트)를 넘는 토큰은 제거
+    pub max_token_length: Option<usize>,
+    // 해당 언어의 불용어 목록으로 불용어 제거
+    pub stop_words: Option<TextLanguage>,
+    // 추가로 제거할 불용어 목록 (의료, 법률 등 분야별 목록), lowercase가 true면 대소문자 구분 없음
+    #[frb(default = "const []")]
+    pub custom_stop_words: Vec<String>,
+    // 동의어 그룹 목록 (예: [["couch", "sofa"], ["tv", "television"]]), 단어 하나 단위로만 적용
+    // 그룹의 단어가 나오면 같은 위치에 그룹의 다른 단어들도 색인되며, 검색어에도 똑같이 적용됩니다.
+    #[frb(default = "const []")]
+    pub synonyms: Vec<Vec<String>>,
+    // 해당 언어의 어간 추출기 적용
+    pub stemmer: Option<TextLanguage>,
+    // 악센트/발음 구별 기호를 제거하여 ASCII로 변환 (예: café -> cafe)
+    // 어간 추출 이후에 적용되므로 어간 추출기는 원래 철자를 그대로 사용합니다.
+    #[frb(default = false)]
+    pub ascii_folding: bool,
+    // 한글 음절을 자모로 분해 (예: 한글 -> ㅎㅏㄴㄱㅡㄹ), 조합 중인 입력도 접두어 검색으로 일치
+    #[frb(default = false)]
+    pub hangul_jamo: bool,
+    // Ngram 토크나이저의 최소/최대 길이 (기본값 2, 3)
+    pub min_gram: Option<usize>,
+    pub max_gram: Option<usize>,
+    // 분리하기 전에 HTML 태그를 제거하고 엔티티를 디코딩 (태그 이름과 속성 값이 색인되지 않음)
+    // 저장된 값은 원본 HTML 그대로이며, 강조 위치도 원본 HTML 기준입니다.
+    #[frb(default = false)]
+    pub strip_html: bool,
+    // 분리하기 전에 마크다운 문법을 제거 (링크/이미지 주소와 본문의 HTML 태그는 색인되지 않음)
+    // strip_html과 함께 지정하면 strip_markdown이 적용됩니다.
+    #[frb(default = false)]
+    pub strip_markdown: bool,
+}
+
+impl TokenizerConfig {
+    // strip_html, strip_markdown이면 기본 토크나이저가 마크업을 제거한 본문을 분리하도록 감쌈
+    fn base_builder<T: Tokenizer>(&self, tokenizer: T) -> TextAnalyzerBuilder {
+        if self.strip_markdown {
+            TextAnalyzer::builder(MarkupStripTokenizer::new(tokenizer, Markup::Markdown)).dynamic()
+        } else if self.strip_html {
+            TextAnalyzer::builder(MarkupStripTokenizer::new(tokenizer, Markup::Html)).dynamic()
+        } else {
+            TextAnalyzer::builder(tokenizer).dynamic()
+        }
+    }
+
+    fn analyzer(&self) -> Result<TextAnalyzer> {
+        let mut builder = match self.base {
+            BaseTokenizer::Simple => self.base_builder(SimpleTokenizer::default()),
+            BaseTokenizer::Whitespace => self.base_builder(WhitespaceTokenizer::default()),
+            BaseTokenizer::Raw => self.base_builder(RawTokenizer::default()),
+            BaseTokenizer::CjkBigram => self.base_builder(CjkBigramTokenizer::default()),
+            BaseTokenizer::Ngram => self.base_builder(NgramTokenizer::new(
+                self.min_gram.unwrap_or(2),
+                self.max_gram.unwrap_or(3),
+                false,
+            )?),
+            #[cfg(feature = "japanese")]
+            BaseTokenizer::Japanese => self.base_builder(JapaneseTokenizer::default()),
+            #[cfg(not(feature = "japanese"))]
+            BaseTokenizer::Japanese => {
+                return Err(anyhow!(
+                    "The Japanese tokenizer requires building with the 'japanese' cargo feature"
+                ))
+            }
+            #[cfg(feature = "chinese")]
+            BaseTokenizer::Chinese => self.base_builder(ChineseTokenizer::default()),
+            #[cfg(not(feature = "chinese"))]
+            BaseTokenizer::Chinese => {
+                return Err(anyhow!(
+                    "The Chinese tokenizer requires building with the 'chinese' cargo feature"
+                ))
+            }
+        };
+        if let Some(limit) = self.max_token_length {
+            builder = builder.filter_dynamic(RemoveLongFilter::limit(limit));
+        }
+        if self.lowercase {
+            builder = builder.filter_dynamic(LowerCaser);
+        }
+        if let Some(language) = self.stop_words {
+            let stop_words = StopWordFilter::new(language.tantivy_language())
+                .ok_or_else(|| anyhow!("No stop word list available for {:?}", language))?;
+            builder = builder.filter_dynamic(stop_words);
+        }
+        // 소문자 변환 이후에 적용되므로 단어 목록도 같은 형태로 맞춤
+        let normalize = |word: &String| {
+            if self.lowercase {
+                word.to_lowercase()
+            } else {
+                word.clone()
+            }
+        };
+        if !self.custom_stop_words.is_empty() {
+            let words = self.custom_stop_words.iter().map(normalize);
+            builder = builder.filter_dynamic(StopWordFilter::remove(words));
+        }
+        if !self.synonyms.is_empty() {
+            let groups: Vec<Vec<String>> = self
+                .synonyms
+                .iter()
+                .map(|group| group.iter().map(normalize).collect())
+                .collect();
+            builder = builder.filter_dynamic(SynonymFilter::new(&groups));
+        }
+        if let Some(language) = self.stemmer {
+            builder = builder.filter_dynamic(Stemmer::new(language.tantivy_language()));
+        }
+        if self.ascii_folding {
+            builder = builder.filter_dynamic(AsciiFoldingFilter);
+        }
+        if self.hangul_jamo {
+            builder = builder.filter_dynamic(HangulJamoFilter);
+        }
+        Ok(builder.build())
+    }
+}
+
+// Flutter에서 등록한 사용자 정의 분석기 (인덱스를 열 때마다 함께 등록됨)
+static CUSTOM_TOKENIZERS: Lazy<Mutex<HashMap<String, TextAnalyzer>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// 스키마가 참조하는 토크나이저는 인덱스에 저장되지 않으므로 열 때마다 등록
+fn tokenizer_manager() -> TokenizerManager {
+    let tokenizers = builtin_tokenizer_manager();
+    for (name, analyzer) in CUSTOM_TOKENIZERS.lock().unwrap().iter() {
+        tokenizers.register(name, analyzer.clone());
+    }
+    tokenizers
+}
+
+// Tantivy 기본 분석기와 이 플러그인이 제공하는 분석기
+fn builtin_tokenizer_manager() -> TokenizerManager {
+    let tokenizers = TokenizerManager::default();
+    for language in TextLanguage::ALL {
+        tokenizers.register(&language.tokenizer_name(), language.analyzer());
+    }
+    tokenizers.register(
+        CJK_BIGRAM_TOKENIZER,
+        TextAnalyzer::builder(CjkBigramTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .build(),
+    );
+    tokenizers.register(
+        KOREAN_JAMO_TOKENIZER,
+        TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .filter(HangulJamoFilter)
+            .build(),
+    );
+    tokenizers.register(
+        HTML_TOKENIZER,
+        TextAnalyzer::builder(MarkupStripTokenizer::new(
+            SimpleTokenizer::default(),
+            Markup::Html,
+        ))
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .build(),
+    );
+    tokenizers.register(
+        MARKDOWN_TOKENIZER,
+        TextAnalyzer::builder(MarkupStripTokenizer::new(
+            SimpleTokenizer::default(),
+            Markup::Markdown,
+        ))
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .build(),
+    );
+    tokenizers.register(GEO_TOKENIZER, GeoHashTokenizer::default());
+    #[cfg(feature = "japanese")]
+    tokenizers.register(
+        JAPANESE_TOKENIZER,
+        TextAnalyzer::builder(JapaneseTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .build(),
+    );
+    #[cfg(feature = "chinese")]
+    tokenizers.register(
+        CHINESE_TOKENIZER,
+        TextAnalyzer::builder(ChineseTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .build(),
+    );
+    tokenizers
+}
+
+// 스키마가 등록되지 않은 토크나이저를 참조하면 색인 시 panic이 발생하므로 미리 검사
+fn check_tokenizers(schema: &Schema, tokenizers: &TokenizerManager) -> Result<()> {
+    for (_, entry) in schema.fields() {
+        if let tantivy::schema::FieldType::Str(options) = entry.field_type() {
+            if let Some(indexing) = options.get_indexing_options() {
+                if tokenizers.get(indexing.tokenizer()).is_none() {
+                    return Err(anyhow!(
+                        "Unknown tokenizer '{}' for field '{}'",
+                        indexing.tokenizer(),
+                        entry.name()
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// GeoPoint 필드는 geo 분석기로 색인된 Str 필드
+fn is_geo_field(entry: &FieldEntry) -> bool {
+    match entry.field_type() {
+        tantivy::schema::FieldType::Str(options) => options
+            .get_indexing_options()
+            .is_some_and(|indexing| indexing.tokenizer() == GEO_TOKENIZER),
+        _ => false,
+    }
+}
+
+// 지정한 분석기를 사용하는 전문 검색용 색인 옵션 (구문 검색을 위해 위치 정보 포함)
+fn text_indexing(tokenizer: &str) -> TextFieldIndexing {
+    TextFieldIndexing::default()
+        .set_tokenizer(tokenizer)
+        .set_index_option(IndexRecordOption::WithFreqsAndPositions)
+}
+
+// 역색인에 단어별로 저장하는 정보 (많이 저장할수록 색인이 커짐)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordOption {
+    // 단어가 포함된 문서만 저장 (ID, 태그 등 일치 여부만 필요한 필드)
+    Basic,
+    // 문서 안의 단어 빈도도 저장 (BM25 점수 계산에 사용)
+    WithFreqs,
+    // 단어의 위치도 저장 (구문 검색에 필요)
+    WithFreqsAndPositions,
+}
+
+impl RecordOption {
+    fn index_option(self) -> IndexRecordOption {
+        match self {
+            RecordOption::Basic => IndexRecordOption::Basic,
+            RecordOption::WithFreqs => IndexRecordOption::WithFreqs,
+            RecordOption::WithFreqsAndPositions => IndexRecordOption::WithFreqsAndPositions,
+        }
+    }
+
+    fn from_index_option(option: IndexRecordOption) -> RecordOption {
+        match option {
+            IndexRecordOption::Basic => RecordOption::Basic,
+            IndexRecordOption::WithFreqs => RecordOption::WithFreqs,
+            IndexRecordOption::WithFreqsAndPositions => RecordOption::WithFreqsAndPositions,
+        }
+    }
+}
+
+// Flutter에서 전달하는 스키마 필드 정의
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct FieldDef {
+    pub name: String,
+    pub field_type: FieldType,
+    #[frb(default = true)]
+    pub stored: bool,
+    #[frb(default = true)]
+    pub indexed: bool,
+    // 정렬, 집계 등에 사용되는 컬럼 저장소(fast field) 사용 여부
+    #[frb(default = false)]
+    pub fast: bool,
+    // Text 필드의 분석기 이름 (예: cjk_bigram, lang_english), 생략하면 기본 토크나이저
+    pub tokenizer: Option<String>,
+    // 색인된 Text/String 필드의 역색인에 저장할 정보, 생략하면 Text는 WithFreqsAndPositions, String은 Basic
+    pub record_option: Option<RecordOption>,
+    // 같은 값을 다른 분석기로 함께 색인할 섀도 필드의 분석기 이름 (색인된 Text 필드만)
+    // 예: text 필드에 ["raw", "lang_english"]를 지정하면 text_raw, text_lang_english 필드가 추가되어
+    // 정확히 일치하는 검색과 어간 추출 검색을 함께 사용할 수 있습니다. 섀도 필드는 저장되지 않습니다.
+    #[frb(default = "const []")]
+    pub shadow_tokenizers: Vec<String>,
+    // 문서마다 언어를 감지하여 해당 언어의 분석기로도 색인할 언어 목록 (색인된 Text 필드만)
+    // 예: text 필드에 [English, German]을 지정하면 영어로 감지된 값은 text_auto_lang_english에,
+    // 독일어는 text_auto_lang_german에, 한국어/일본어/중국어는 text_auto_cjk_bigram에 추가로 색인됩니다.
+    // 이 필드들은 기본 검색 대상이므로 검색어의 언어를 지정하지 않아도 각 언어의 분석 결과로 검색됩니다.
+    #[frb(default = "const []")]
+    pub detect_languages: Vec<TextLanguage>,
+}
+
+// 섀도 필드 이름 (원본 필드 이름_분석기 이름)
+fn shadow_field_name(field_name: &str, tokenizer: &str) -> String {
+    format!("{}_{}", field_name, tokenizer)
+}
+
+// 스키마에서 (원본 필드, 섀도 필드) 쌍을 찾음
+// 섀도 필드는 저장되지 않는 색인된 Text 필드로, 이름이 원본 필드 이름_자신의 분석기 이름입니다.
+fn shadow_fields(schema: &Schema) -> Vec<(Field, Field)> {
+    let mut pairs = Vec::new();
+    for (shadow, shadow_entry) in schema.fields() {
+        if shadow_entry.is_stored() {
+            continue;
+        }
+        let Some(shadow_tokenizer) = text_tokenizer(shadow_entry) else {
+            continue;
+        };
+        for (source, source_entry) in schema.fields() {
+            if source != shadow
+                && text_tokenizer(source_entry).is_some()
+                && shadow_field_name(source_entry.name(), &shadow_tokenizer) == shadow_entry.name()
+            {
+                pairs.push((source, shadow));
+            }
+        }
+    }
+    pairs
+}
+
+// 색인된 Text/String 필드의 분석기 이름
+fn text_tokenizer(entry: &FieldEntry) -> Option<String> {
+    match entry.field_type() {
+        tantivy::schema::FieldType::Str(options) => options
+            .get_indexing_options()
+            .map(|indexing| indexing.tokenizer().to_string()),
+        _ => None,
+    }
+}
+
+// 원본 필드의 값을 섀도 필드에 복사
+fn fill_shadow_fields(tantivy_doc: &mut TantivyDocument, shadow_fields: &[(Field, Field)]) {
+    for &(source, shadow) in shadow_fields {
+        let values: Vec<String> = tantivy_doc
+            .get_all(source)
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        for value in values {
+            tantivy_doc.add_text(shadow, value);
+        }
+    }
+}
+
+// 언어 감지로 채워지는 필드 이름 (원본 필드 이름_auto_분석기 이름)
+fn language_field_name(field_name: &str, tokenizer: &str) -> String {
+    format!("{}_auto_{}", field_name, tokenizer)
+}
+
+// 언어 감지 필드의 분석기 (지정한 언어별 분석기와 한중일 문자용 cjk_bigram)
+fn detect_language_tokenizers(field: &FieldDef) -> Vec<String> {
+    if field.detect_languages.is_empty() {
+        return Vec::new();
+    }
+    let mut tokenizers: Vec<String> = Vec::new();
+    for language in &field.detect_languages {
+        let tokenizer = language.tokenizer_name();
+        if !tokenizers.contains(&tokenizer) {
+            tokenizers.push(tokenizer);
+        }
+    }
+    tokenizers.push(CJK_BIGRAM_TOKENIZER.to_string());
+    tokenizers
+}
+
+// 언어 감지 대상 필드와 감지된 언어별로 값을 색인할 필드
+struct LanguageFields {
+    source: Field,
+    targets: Vec<(Lang, Field)>,
+}
+
+// 스키마에서 언어 감지 필드를 찾음
+// 섀도 필드와 같이 저장되지 않는 색인된 Text 필드로, 이름이 원본 필드 이름_auto_자신의 분석기 이름입니다.
+fn language_fields(schema: &Schema) -> Vec<LanguageFields> {
+    let mut language_fields = Vec::new();
+    for (source, source_entry) in schema.fields() {
+        if text_tokenizer(source_entry).is_none() {
+            continue;
+        }
+        let mut targets = Vec::new();
+        for (field, entry) in schema.fields() {
+            if entry.is_stored() {
+                continue;
+            }
+            let Some(tokenizer) = text_tokenizer(entry) else {
+                continue;
+            };
+            if language_field_name(source_entry.name(), &tokenizer) == entry.name() {
+                targets.extend(
+                    detected_langs(&tokenizer)
+                        .into_iter()
+                        .map(|lang| (lang, field)),
+                );
+            }
+        }
+        if !targets.is_empty() {
+            language_fields.push(LanguageFields { source, targets });
+        }
+    }
+    language_fields
+}
+
+// 분석기 이름에 해당하는 감지 언어 (cjk_bigram은 한국어, 일본어, 중국어)
+fn detected_langs(tokenizer: &str) -> Vec<Lang> {
+    if tokenizer == CJK_BIGRAM_TOKENIZER {
+        return vec![Lang::Kor, Lang::Jpn, Lang::Cmn];
+    }
+    TextLanguage::ALL
+        .into_iter()
+        .filter(|language| language.tokenizer_name() == tokenizer)
+        .map(TextLanguage::detected_lang)
+        .collect()
+}
+
+// 원본 필드의 값마다 언어를 감지하여 해당 언어의 필드에 복사
+// 감지할 수 없거나 목록에 없는 언어의 값은 원본 필드에만 색인됩니다.
+fn fill_language_fields(tantivy_doc: &mut TantivyDocument, language_fields: &[LanguageFields]) {
+    for language_field in language_fields {
+        let values: Vec<String> = tantivy_doc
+            .get_all(language_field.source)
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        for value in values {
+            let Some(lang) = whatlang::detect_lang(&value) else {
+                continue;
+            };
+            if let Some(&(_, field)) = language_field.targets.iter().find(|&&(l, _)| l == lang) {
+                tantivy_doc.add_text(field, value);
+            }
+        }
+    }
+}
+
+// IndexWriter와 새 인덱스 설정 (생략한 항목은 기본값 사용)
+// 저사양 기기에서는 메모리 예산을 줄이고, 데스크톱에서는 스레드 수를 늘릴 수 있습니다.
+#[derive(Clone)]
+pub struct WriterOptions {
+    // 전체 색인 메모리 예산 (바이트, 기본 50MB, 스레드당 최소 15MB)
+    pub memory_budget_bytes: Option<usize>,
+    // 색인 스레드 수 (기본값은 CPU 코어 수와 메모리 예산에 따라 자동 결정)
+    pub num_threads: Option<usize>,
+    // 검색에 commit된 변경사항을 반영하는 시점 (기본 OnSearch)
+    pub reload_policy: Option<ReaderReloadPolicy>,
+    // 저장된 필드를 압축하는 방식 (기본 Lz4)
+    // 인덱스를 만들 때 저장되므로 기존 인덱스에는 reindex_with_schema로 다시 만들 때 적용됩니다.
+    pub docstore_compression: Option<DocstoreCompression>,
+    // 저장된 필드를 압축하는 블록 크기 (바이트, 기본 16KB)
+    // 클수록 압축률이 높아지지만 문서 하나를 읽을 때 풀어야 하는 양이 늘어납니다.
+    pub docstore_block_size: Option<usize>,
+    // 인덱스 파일을 읽는 방식 (기본 Mmap, 메모리 인덱스에는 적용되지 않음)
+    pub storage_backend: Option<StorageBackend>,
+    // 파일 접근이 거부되면 이 시간(밀리초)까지 다시 시도 (iOS에서 기기가 잠긴 동안 백그라운드 색인용)
+    // 지정하면 검색 전 리로드가 거부될 때 마지막으로 리로드한 결과로 검색합니다.
+    pub protected_data_wait_ms: Option<u64>,
+    // 인덱스 파일을 AES-256-GCM으로 암호화하는 32바이트 키 (None이면 암호화하지 않음)
+    // 새 인덱스를 만들 때 정해지며, 암호화한 인덱스는 같은 키로만 열 수 있습니다.
+    pub encryption_key: Option<Vec<u8>>,
+    // 인덱스의 최대 크기 (바이트, None이면 제한 없음)
+    // 문서를 추가하기 전에 확인하며, 넘으면 QuotaExceeded 오류로 거부합니다.
+    pub max_index_size_bytes: Option<u64>,
+    // 지정하면 최대 크기를 넘을 때 거부하지 않고 이 Date fast field가 가장 오래된 문서부터 삭제
+    pub evict_oldest_by: Option<String>,
+}
+
+// 암호화 키는 로그에 남지 않도록 출력하지 않음
+impl std::fmt::Debug for WriterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WriterOptions")
+            .field("memory_budget_bytes", &self.memory_budget_bytes)
+            .field("num_threads", &self.num_threads)
+            .field("reload_policy", &self.reload_policy)
+            .field("docstore_compression", &self.docstore_compression)
+            .field("docstore_block_size", &self.docstore_block_size)
+            .field("storage_backend", &self.storage_backend)
+            .field("protected_data_wait_ms", &self.protected_data_wait_ms)
+            .field(
+                "encryption_key",
+                &self.encryption_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field("max_index_size_bytes", &self.max_index_size_bytes)
+            .field("evict_oldest_by", &self.evict_oldest_by)
+            .finish()
+    }
+}
+
+impl WriterOptions {
+    // 지정한 항목 중 existing(열린 인덱스의 설정)과 다른 첫 항목의 이름 (암호화 키는 비교하지 않음)
+    fn conflicting_option(&self, existing: Option<&WriterOptions>) -> Option<&'static str> {
+        fn differs<T: PartialEq>(requested: Option<&T>, existing: Option<&T>) -> bool {
+            requested.is_some() && requested != existing
+        }
+        [
+            (
+                "memory_budget_bytes",
+                differs(
+                    self.memory_budget_bytes.as_ref(),
+                    existing.and_then(|e| e.memory_budget_bytes.as_ref()),
+                ),
+            ),
+            (
+                "num_threads",
+                differs(
+                    self.num_threads.as_ref(),
+                    existing.and_then(|e| e.num_threads.as_ref()),
+                ),
+            ),
+            (
+                "reload_policy",
+                differs(
+                    self.reload_policy.as_ref(),
+                    existing.and_then(|e| e.reload_policy.as_ref()),
+                ),
+            ),
+            (
+                "docstore_compression",
+                differs(
+                    self.docstore_compression.as_ref(),
+                    existing.and_then(|e| e.docstore_compression.as_ref()),
+                ),
+            ),
+            (
+                "docstore_block_size",
+                differs(
+                    self.docstore_block_size.as_ref(),
+                    existing.and_then(|e| e.docstore_block_size.as_ref()),
+                ),
+            ),
+            (
+                "storage_backend",
+                differs(
+                    self.storage_backend.as_ref(),
+                    existing.and_then(|e| e.storage_backend.as_ref()),
+                ),
+            ),
+            (
+                "protected_data_wait_ms",
+                differs(
+                    self.protected_data_wait_ms.as_ref(),
+                    existing.and_then(|e| e.protected_data_wait_ms.as_ref()),
+                ),
+            ),
+            (
+                "max_index_size_bytes",
+                differs(
+                    self.max_index_size_bytes.as_ref(),
+                    existing.and_then(|e| e.max_index_size_bytes.as_ref()),
+                ),
+            ),
+            (
+                "evict_oldest_by",
+                differs(
+                    self.evict_oldest_by.as_ref(),
+                    existing.and_then(|e| e.evict_oldest_by.as_ref()),
+                ),
+            ),
+        ]
+        .into_iter()
+        .find_map(|(name, differs)| differs.then_some(name))
+    }
+}
+
+// 저장된 필드(문서 저장소)의 압축 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocstoreCompression {
+    // 압축하지 않음 (가장 빠르지만 가장 큼)
+    None,
+    // 빠른 압축
+    Lz4,
+    // 느리지만 텍스트를 더 작게 압축 (저장 공간이 부족한 기기에 적합)
+    Zstd,
+}
+
+// 디스크 인덱스의 파일을 읽는 방식
+// 파일 형식은 같으므로 같은 인덱스를 다른 방식으로 다시 열 수 있습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageBackend {
+    // 파일을 메모리 매핑해서 필요한 부분만 운영체제가 읽음
+    Mmap,
+    // 일반 파일 읽기로 필요한 범위만 읽음 (mmap을 쓸 수 없거나 주소 공간이 작은 기기)
+    FileIo,
+    // 파일을 처음 열 때 전체를 메모리에 읽어 둠 (검색이 빠르지만 인덱스 크기만큼 메모리 사용)
+    Ram,
+}
+
+// 새 인덱스의 설정: base에 옵션으로 지정한 항목만 덮어씀
+fn index_settings(options: Option<&WriterOptions>, base: IndexSettings) -> Result<IndexSettings> {
+    let mut settings = base;
+    if let Some(compression) = options.and_then(|options| options.docstore_compression) {
+        settings.docstore_compression = match compression {
+            DocstoreCompression::None => Compressor::None,
+            DocstoreCompression::Lz4 => Compressor::Lz4,
+            DocstoreCompression::Zstd => Compressor::Zstd(ZstdCompressor::default()),
+        };
+    }
+    match options.and_then(|options| options.docstore_block_size) {
+        Some(0) => return Err(anyhow!("docstore_block_size must be at least 1")),
+        Some(block_size) => settings.docstore_blocksize = block_size,
+        None => {}
+    }
+    Ok(settings)
+}
+
+// 검색에 사용하는 reader가 새 commit을 반영하는 시점
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReaderReloadPolicy {
+    // 검색할 때마다 먼저 reader를 리로드 (commit 직후에도 항상 최신 결과, 검색마다 리로드 비용이 듦)
+    OnSearch,
+    // commit을 감지하면 백그라운드에서 리로드 (검색에 리로드 비용이 없지만, commit 직후 잠시 이전 결과가 보일 수 있음)
+    OnCommitWithDelay,
+    // refresh_reader를 호출할 때만 리로드
+    Manual,
+    // 검색할 때마다 *_no_commit 함수로 쌓인 변경을 먼저 commit하고 리로드
+    // (쓰기는 commit 없이 빠르게 하면서 채팅 메시지처럼 추가한 문서를 바로 검색해야 할 때 사용)
+    NearRealTime,
+}
+
+const DEFAULT_WRITER_MEMORY_BYTES: usize = 50_000_000; // 50MB heap
+
+// 설정에 맞는 IndexWriter 생성 (None이면 기본값)
+fn create_writer(index: &Index, options: Option<&WriterOptions>) -> Result<IndexWriter> {
+    let memory_budget = options
+        .and_then(|options| options.memory_budget_bytes)
+        .unwrap_or(DEFAULT_WRITER_MEMORY_BYTES);
+    let writer = match options.and_then(|options| options.num_threads) {
+        Some(0) => return Err(anyhow!("num_threads must be at least 1")),
+        Some(num_threads) => index.writer_with_num_threads(num_threads, memory_budget)?,
+        None => index.writer(memory_budget)?,
+    };
+    Ok(writer)
+}
+
+// 최대 크기를 넘었을 때 오래된 문서를 삭제하고 크기를 다시 확인하는 최대 횟수
+const QUOTA_EVICTION_ROUNDS: usize = 3;
+
+// writer 잠금을 다시 시도하는 시간과 간격
+const LOCK_RETRY_TIMEOUT: Duration = Duration::from_secs(1);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+// 잠금을 기다렸다가 writer 생성
+// 잠금은 프로세스가 종료되면 운영체제가 해제하므로 비정상 종료 후 남은 잠금 파일은 그대로 다시 잠글 수 있지만,
+// 앱을 다시 시작한 직후에는 종료 중인 이전 프로세스나 닫히는 중인 인덱스가 아직 잠금을 가지고 있을 수 있습니다.
+fn open_writer(index: &Index, options: Option<&WriterOptions>) -> Result<IndexWriter> {
+    let started = Instant::now();
+    loop {
+        match create_writer(index, options) {
+            Err(e) if is_lock_failure(&e) => {
+                if started.elapsed() >= LOCK_RETRY_TIMEOUT {
+                    return Err(TantivyError::new(
+                        TantivyErrorKind::LockBusy,
+                        "Index directory is locked by another writer; close the index that uses it, \
+                         or call force_unlock if no other process can be using it",
+                    )
+                    .into());
+                }
+                std::thread::sleep(LOCK_RETRY_INTERVAL);
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_lock_failure(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<tantivy::TantivyError>(),
+        Some(tantivy::TantivyError::LockFailure(..))
+    )
+}
+
+// 검색 대상 필드와 가중치 (예: title^2, body^1)
+#[derive(Debug, Clone)]
+pub struct FieldBoost {
+    pub field: String,
+    // 생략하면 1.0
+    pub boost: Option<f32>,
+}
+
+// 쿼리 파서 설정 (생략한 항목은 현재 설정 유지)
+#[derive(Debug, Clone)]
+pub struct QueryParserConfig {
+    // 필드를 지정하지 않은 검색어의 검색 대상 필드와 가중치 (기본값은 색인된 모든 Text 필드)
+    pub default_fields: Option<Vec<FieldBoost>>,
+    // true면 공백으로 구분된 단어가 모두 일치해야 함 (AND), false면 하나만 일치해도 됨 (OR, 기본값)
+    pub conjunction_by_default: Option<bool>,
+}
+
+// BM25 점수 계산 파라미터 (생략하면 Tantivy 기본값 k1 = 1.2, b = 0.75)
+#[derive(Debug, Clone)]
+pub struct Bm25Params {
+    // 단어 빈도 포화 정도 (클수록 반복된 단어의 점수가 계속 증가)
+    pub k1: Option<f32>,
+    // 문서 길이 정규화 정도 (0이면 길이 무시, 1이면 완전 정규화)
+    pub b: Option<f32>,
 }
 
 // Flutter에서 사용할 검색 결과 구조체
 #[derive(Debug, Clone)]
-pub struct SearchResult {
-    pub score: f32,
-    pub doc: Document,
+pub struct SearchResult {
+    pub score: f32,
+    pub doc: Document,
+    // 일치한 부분을 <b> 태그로 강조한 HTML 조각 (snippet 검색에서만 채워짐)
+    pub snippet: Option<String>,
+    // snippet 필드의 저장된 첫 번째 값에서 일치한 부분의 위치 (snippet 검색에서만 채워짐)
+    // HTML을 해석하지 않고 RichText 등으로 직접 강조할 때 사용합니다.
+    pub highlights: Vec<HighlightRange>,
+}
+
+// 저장된 필드 값에서 일치한 부분의 범위 (끝 위치는 포함하지 않음)
+// start/end는 Dart String의 인덱스(UTF-16 코드 단위), byte_start/byte_end는 UTF-8 바이트 위치입니다.
+#[derive(Debug, Clone)]
+pub struct HighlightRange {
+    pub start: usize,
+    pub end: usize,
+    pub byte_start: usize,
+    pub byte_end: usize,
+}
+
+// snippet 검색에서 강조 HTML과 일치 위치를 만드는 생성기
+struct Highlighter {
+    field: Field,
+    // max_chars 길이의 강조 조각을 만드는 생성기
+    snippet: SnippetGenerator,
+    // 필드 값 전체를 하나의 조각으로 보고 일치 위치를 찾는 생성기
+    full_text: SnippetGenerator,
+}
+
+impl Highlighter {
+    fn create(
+        searcher: &Searcher,
+        query: &dyn Query,
+        field: Field,
+        max_chars: Option<usize>,
+    ) -> Result<Self> {
+        let mut snippet = SnippetGenerator::create(searcher, query, field)?;
+        if let Some(max_chars) = max_chars {
+            snippet.set_max_num_chars(max_chars);
+        }
+        let mut full_text = SnippetGenerator::create(searcher, query, field)?;
+        full_text.set_max_num_chars(usize::MAX);
+        Ok(Highlighter {
+            field,
+            snippet,
+            full_text,
+        })
+    }
+
+    // 첫 번째 조각은 항상 0에서 시작하므로 길이 제한이 없으면 강조 범위가 곧 값 안의 위치가 됨
+    fn highlights(&self, doc: &TantivyDocument) -> Vec<HighlightRange> {
+        let Some(text) = doc.get_first(self.field).and_then(|v| v.as_str()) else {
+            return Vec::new();
+        };
+        let utf16_offset = |byte_offset: usize| text[..byte_offset].encode_utf16().count();
+        self.full_text
+            .snippet(text)
+            .highlighted()
+            .iter()
+            .map(|range| HighlightRange {
+                start: utf16_offset(range.start),
+                end: utf16_offset(range.end),
+                byte_start: range.start,
+                byte_end: range.end,
+            })
+            .collect()
+    }
+}
+
+// 페이지 단위 검색 결과 (전체 일치 문서 수 포함)
+#[derive(Debug, Clone)]
+pub struct SearchResponse {
+    // 쿼리와 일치하는 전체 문서 수
+    pub total_hits: usize,
+    pub results: Vec<SearchResult>,
+}
+
+// 인덱스 통계 (설정 화면의 저장 공간 표시, 병합 시점 판단 등에 사용)
+#[derive(Debug, Clone)]
+pub struct IndexStats {
+    // 검색 가능한 문서 수 (삭제된 문서 제외)
+    pub num_docs: u64,
+    // 삭제 표시되었지만 병합 전까지 디스크에 남아 있는 문서 수
+    pub num_deleted_docs: u64,
+    pub num_segments: u64,
+    // 인덱스 디렉토리의 전체 파일 크기
+    pub size_bytes: u64,
+}
+
+// 인덱스 디렉토리 점검 결과 (비정상 종료 후 재색인이 필요한지 판단하는 데 사용)
+#[derive(Debug, Clone)]
+pub struct IndexHealth {
+    // meta.json이 있는지 (없으면 나머지 항목은 비어 있음)
+    pub exists: bool,
+    // 누락되거나 손상된 파일 없이 열 수 있는지
+    pub healthy: bool,
+    // 다른 writer가 잠금을 잡고 있는지 (이 앱에서 열어 둔 경우 포함)
+    pub locked: bool,
+    // 이 앱에서 열려 있는 인덱스인지
+    pub open: bool,
+    pub num_segments: u64,
+    // 마지막 commit 기준 문서 수 (삭제된 문서 제외)
+    pub num_docs: u64,
+    // 마지막 commit이 참조하지만 디렉토리에 없는 파일
+    pub missing_files: Vec<String>,
+    // 체크섬이 맞지 않거나 잘린 파일
+    pub corrupted_files: Vec<String>,
+    // meta.json을 읽지 못하거나 인덱스를 열 수 없는 경우의 오류 메시지
+    pub error: Option<String>,
+}
+
+// repair_index 결과
+#[derive(Debug, Clone)]
+pub struct RepairReport {
+    // 새 인덱스로 옮긴 문서 수
+    pub recovered_docs: u64,
+    // 읽을 수 없어 버린 문서 수 (읽을 수 없는 세그먼트의 문서 포함)
+    pub lost_docs: u64,
+    // 저장소나 삭제 목록 파일을 읽을 수 없어 통째로 버린 세그먼트 수
+    pub lost_segments: u64,
+}
+
+// 관대한(lenient) 쿼리 파싱 검색 결과
+#[derive(Debug, Clone)]
+pub struct LenientSearchResponse {
+    pub results: Vec<SearchResult>,
+    // 파싱 중 무시된 부분에 대한 경고 메시지
+    pub warnings: Vec<String>,
+}
+
+// Facet 경로별 문서 수
+#[derive(Debug, Clone)]
+pub struct FacetCount {
+    pub path: String,
+    pub count: u64,
+}
+
+// Facet 집계가 포함된 검색 결과
+#[derive(Debug, Clone)]
+pub struct FacetSearchResponse {
+    pub results: Vec<SearchResult>,
+    // 지정한 경로 바로 아래의 하위 Facet별 일치 문서 수
+    pub facet_counts: Vec<FacetCount>,
+}
+
+// 같은 필드 값을 가진 일치 문서 묶음
+#[derive(Debug, Clone)]
+pub struct SearchGroup {
+    // 그룹 필드 값 (값이 없는 문서들은 None 그룹으로 묶임)
+    pub key: Option<String>,
+    // 그룹 안에서 쿼리와 일치하는 전체 문서 수
+    pub total_hits: usize,
+    // 점수가 높은 순서의 상위 문서
+    pub hits: Vec<SearchResult>,
+}
+
+// 그룹별로 묶인 검색 결과
+#[derive(Debug, Clone)]
+pub struct GroupedSearchResponse {
+    // 쿼리와 일치하는 전체 문서 수
+    pub total_hits: usize,
+    // 일치한 문서가 있는 전체 그룹 수
+    pub total_groups: usize,
+    // 가장 높은 점수의 문서 순서로 정렬된 상위 그룹
+    pub groups: Vec<SearchGroup>,
+}
+
+// 마크다운 문서의 제목 (anchor로 노트의 해당 위치로 바로 이동할 수 있음)
+#[derive(Debug, Clone)]
+pub struct MarkdownHeading {
+    // 1 (#) ~ 6 (######)
+    pub level: u32,
+    // 마크다운 문법을 제거한 제목
+    pub title: String,
+    // GitHub과 같은 방식의 앵커 (예: "Getting Started" -> "getting-started", 중복되면 "-1", "-2"를 붙임)
+    pub anchor: String,
+    // 제목이 있는 줄 번호 (1부터 시작)
+    pub line: usize,
+}
+
+// 조각으로 나누어 색인한 문서의 검색 결과 (원본 문서별로 하나)
+#[derive(Debug, Clone)]
+pub struct ChunkSearchResult {
+    // add_document_chunked에 전달한 문서의 ID
+    pub parent_id: String,
+    // 원본 문서에서 가장 잘 일치한 조각의 순번 (0부터 시작)
+    pub chunk_index: usize,
+    // 가장 잘 일치한 조각 (doc.text는 조각의 내용, 나머지 필드와 메타데이터는 원본 문서의 값)
+    pub chunk: SearchResult,
+    // 원본 문서에서 쿼리와 일치한 조각 수
+    pub matching_chunks: usize,
+}
+
+// 구조화된 쿼리의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryKind {
+    // must/should/must_not 하위 쿼리를 조합하는 불리언 쿼리
+    Boolean,
+    // 쿼리 문법으로 작성된 문자열 (text)
+    QueryString,
+    // 필드에 분석된 모든 단어가 포함된 문서 (field, text), Bool 필드는 text에 "true" 또는 "false"
+    Term,
+    // 단어들이 순서대로 나타나는 문서 (field, text, slop)
+    Phrase,
+    // 오타를 허용하는 검색 (field, text, max_distance)
+    Fuzzy,
+    // 필드 값이 범위 안에 있는 문서 (field, lower, upper)
+    Range,
+    // 필드에 값이 하나 이상 있는 문서 (field), must_not에 넣으면 값이 없는 문서
+    Exists,
+}
+
+// Range 쿼리의 경계값
+// 필드 타입에 맞는 값 하나만 지정합니다. (Date는 epoch 밀리초)
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct RangeBound {
+    pub text_value: Option<String>,
+    pub i64_value: Option<i64>,
+    pub u64_value: Option<u64>,
+    pub f64_value: Option<f64>,
+    pub date_value: Option<i64>,
+    // 경계값 자체를 포함할지 여부
+    #[frb(default = true)]
+    pub inclusive: bool,
+}
+
+// Flutter에서 문자열 조합 없이 쿼리를 구성하기 위한 구조체
+// kind에 따라 필요한 값만 지정하며, Boolean 쿼리는 하위 쿼리를 중첩할 수 있습니다.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    pub kind: QueryKind,
+    pub field: Option<String>,
+    pub text: Option<String>,
+    pub slop: Option<u32>,
+    pub max_distance: Option<u8>,
+    // Range 쿼리의 하한과 상한 (생략하면 제한 없음)
+    pub lower: Option<RangeBound>,
+    pub upper: Option<RangeBound>,
+    // 이 쿼리의 점수에 곱하는 가중치
+    pub boost: Option<f32>,
+    // 지정하면 관련도 계산 없이 일치한 문서에 이 점수를 부여 (필터 조건은 0.0)
+    pub const_score: Option<f32>,
+    #[frb(default = "const []")]
+    pub must: Vec<SearchQuery>,
+    #[frb(default = "const []")]
+    pub should: Vec<SearchQuery>,
+    #[frb(default = "const []")]
+    pub must_not: Vec<SearchQuery>,
+}
+
+// Tantivy의 핵심 로직을 관리하는 구조체
+struct TantivyApi {
+    index: Index,
+    // 인덱스 파일이 저장된 디렉토리 (None이면 메모리 인덱스)
+    index_dir: Option<PathBuf>,
+    // 인덱스 파일을 그대로 읽는 Directory (index.directory()는 footer를 떼고 읽으므로 백업에 사용)
+    raw_directory: Box<dyn Directory>,
+    writer: Mutex<IndexWriter>,
+    // 진행 중인 batch ID (begin_batch부터 commit_batch/abort_batch까지)
+    batch: Mutex<Option<u64>>,
+    // reopen_index에서 재사용할 writer 설정
+    writer_options: Option<WriterOptions>,
+    reader: IndexReader,
+    reload_policy: ReaderReloadPolicy,
+    schema: Schema,
+    id_field: Field,
+    text_field: Option<Field>,
+    metadata_field: Option<Field>,
+    // 문서 버전 필드 (버전 필드가 추가되기 전에 만든 인덱스는 None)
+    version_field: Option<Field>,
+    // 다음에 쓰는 문서에 부여할 버전
+    next_version: AtomicU64,
+    // 조각 문서의 원본 ID 필드 (원본 ID 필드가 추가되기 전에 만든 인덱스는 None)
+    parent_field: Option<Field>,
+    // 쿼리 파서의 기본 검색 대상 필드 (색인된 Text 필드)
+    default_fields: Vec<Field>,
+    // (원본 필드, 섀도 필드) 쌍, 문서를 추가할 때 원본 값을 섀도 필드에도 색인
+    shadow_fields: Vec<(Field, Field)>,
+    // 문서를 추가할 때 언어를 감지하여 해당 언어의 필드에도 색인할 원본 필드
+    language_fields: Vec<LanguageFields>,
+    // configure_query_parser로 변경한 쿼리 파서 설정
+    query_settings: RwLock<QuerySettings>,
+    // search_hit_handles에서 사용한 최근 searcher (핸들로 문서를 가져올 때 같은 시점의 데이터를 읽음)
+    handle_searchers: Mutex<VecDeque<Searcher>>,
+    // *_no_commit 함수로 쌓인 변경과 자동 commit 설정 (writer 잠금을 잡은 뒤에 잠금)
+    pending: Mutex<PendingChanges>,
+    // 인덱스가 최대 크기를 넘을 때 오래된 문서부터 삭제하는 기준 필드 (WriterOptions.evict_oldest_by)
+    evict_field: Option<Field>,
+}
+
+// 인덱스에 적용된 쿼리 파서 설정
+#[derive(Debug, Clone)]
+struct QuerySettings {
+    fields: Vec<Field>,
+    boosts: Vec<(Field, f32)>,
+    conjunction_by_default: bool,
+}
+
+impl TantivyApi {
+    // 검색에 사용할 searcher (OnSearch, NearRealTime 정책이면 먼저 리로드하여 최신 commit을 반영)
+    fn searcher(&self) -> Result<Searcher> {
+        match self.reload_policy {
+            ReaderReloadPolicy::OnSearch => self.reload_for_search()?,
+            ReaderReloadPolicy::NearRealTime => {
+                self.commit_pending()?;
+                self.reload_for_search()?;
+            }
+            ReaderReloadPolicy::OnCommitWithDelay | ReaderReloadPolicy::Manual => {}
+        }
+        Ok(self.reader.searcher())
+    }
+
+    // 검색 전 리로드 (protected_data_wait_ms를 지정했으면 파일 접근이 거부될 때 이전 searcher를 사용)
+    fn reload_for_search(&self) -> Result<()> {
+        match self.reader.reload() {
+            Err(e)
+                if protected_data_wait(self.writer_options.as_ref()).is_some()
+                    && tantivy_io_error(&e)
+                        .is_some_and(protected_directory::is_permission_denied) =>
+            {
+                Ok(())
+            }
+            result => Ok(result?),
+        }
+    }
+
+    // NearRealTime 정책에서 검색 전에 *_no_commit 함수로 쌓인 변경을 commit
+    // 다른 작업이 writer를 사용 중이거나 batch 중이면 기다리지 않고 마지막 commit을 검색합니다.
+    fn commit_pending(&self) -> Result<()> {
+        let Ok(mut writer) = self.writer.try_lock() else {
+            return Ok(());
+        };
+        if self.batch.lock().unwrap().is_some() {
+            return Ok(());
+        }
+        let mut pending = self.pending.lock().unwrap();
+        pending.sync(&writer);
+        if pending.count > 0 {
+            writer.commit()?;
+            pending.clear();
+        }
+        Ok(())
+    }
+
+    // commit하는 작업에서 사용할 writer 잠금
+    // 다른 호출자의 batch가 진행 중이면 중간에 commit되지 않도록 거부합니다.
+    fn commit_writer(&self) -> Result<MutexGuard<'_, IndexWriter>, TantivyError> {
+        let writer = self.writer.lock().unwrap();
+        if let Some(batch_id) = *self.batch.lock().unwrap() {
+            return Err(TantivyError::new(
+                TantivyErrorKind::BatchInProgress,
+                format!(
+                    "Batch {} is in progress; commit or abort it first",
+                    batch_id
+                ),
+            ));
+        }
+        Ok(writer)
+    }
+
+    // *_no_commit 함수로 쌓인 변경이 있으면 먼저 commit (commit_writer로 잠근 writer 사용)
+    // commit된 문서를 기준으로 확인하는 작업이 쌓인 변경까지 보도록 합니다.
+    fn commit_pending_locked(&self, writer: &mut IndexWriter) -> Result<()> {
+        let mut pending = self.pending.lock().unwrap();
+        pending.sync(writer);
+        if pending.count > 0 {
+            writer.commit()?;
+            pending.clear();
+        }
+        Ok(())
+    }
+
+    // *_no_commit 함수의 변경을 기록하고, 자동 commit 개수에 도달하면 바로 commit
+    // batch 중에는 batch가 끝날 때까지 commit하지 않습니다.
+    fn record_pending(&self, writer: &mut IndexWriter, opstamp: u64) -> Result<()> {
+        let mut pending = self.pending.lock().unwrap();
+        pending.sync(writer);
+        pending.count += 1;
+        pending.since.get_or_insert_with(Instant::now);
+        pending.last_opstamp = opstamp;
+
+        let max_docs = pending
+            .policy
+            .as_ref()
+            .and_then(|policy| policy.max_pending_docs);
+        if max_docs.is_some_and(|max_docs| pending.count >= max_docs)
+            && self.batch.lock().unwrap().is_none()
+        {
+            writer.commit()?;
+            pending.clear();
+        }
+        Ok(())
+    }
+
+    // 첫 변경 후 delay가 지났으면 commit (자동 commit 스레드에서 호출)
+    fn commit_if_due(&self, delay: Duration) -> Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        if self.batch.lock().unwrap().is_some() {
+            return Ok(());
+        }
+        let mut pending = self.pending.lock().unwrap();
+        pending.sync(&writer);
+        if pending.since.is_some_and(|since| since.elapsed() >= delay) {
+            writer.commit()?;
+            pending.clear();
+        }
+        Ok(())
+    }
+
+    // 인덱스 디렉토리의 전체 크기 (메모리 인덱스는 commit된 세그먼트 파일의 크기)
+    fn size_bytes(&self) -> Result<u64> {
+        let mut size_bytes = 0;
+        match &self.index_dir {
+            Some(index_dir) => {
+                for entry in std::fs::read_dir(index_dir)? {
+                    let metadata = entry?.metadata()?;
+                    if metadata.is_file() {
+                        size_bytes += metadata.len();
+                    }
+                }
+            }
+            None => {
+                let directory = self.index.directory();
+                for segment in self.index.searchable_segment_metas()? {
+                    for file in segment.list_files() {
+                        if let Ok(file) = directory.open_read(&file) {
+                            size_bytes += file.len() as u64;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(size_bytes)
+    }
+
+    // 문서를 추가하기 전에 인덱스 크기 확인 (commit_writer나 writer 잠금으로 잠근 writer 사용)
+    // 최대 크기를 넘으면 evict_field가 가장 오래된 문서를 삭제해서 최대 크기의 90%까지 줄이고,
+    // 삭제 기준이 없거나 batch 중이거나 삭제해도 줄지 않으면 QuotaExceeded 오류를 반환합니다.
+    fn enforce_quota(&self, writer: &mut IndexWriter) -> Result<()> {
+        let Some(max_size) = self
+            .writer_options
+            .as_ref()
+            .and_then(|options| options.max_index_size_bytes)
+        else {
+            return Ok(());
+        };
+        let mut size = self.size_bytes()?;
+        if size <= max_size {
+            return Ok(());
+        }
+        let exceeded = |size: u64| {
+            TantivyError::new(
+                TantivyErrorKind::QuotaExceeded,
+                format!(
+                    "Index size {} bytes exceeds the limit of {} bytes",
+                    size, max_size
+                ),
+            )
+        };
+        let Some(evict_field) = self.evict_field else {
+            return Err(exceeded(size).into());
+        };
+        if self.batch.lock().unwrap().is_some() {
+            return Err(exceeded(size).into());
+        }
+
+        let target = max_size / 10 * 9;
+        for _ in 0..QUOTA_EVICTION_ROUNDS {
+            self.commit_pending_locked(writer)?;
+            self.reader.reload()?;
+            let searcher = self.reader.searcher();
+            let num_docs = searcher.num_docs();
+            if num_docs == 0 {
+                break;
+            }
+            // 문서당 평균 크기로 목표 크기까지 줄이는 데 필요한 문서 수를 추정
+            let doc_size = (size / num_docs).max(1);
+            let count = (size - target).div_ceil(doc_size).clamp(1, num_docs) as usize;
+            let oldest = searcher.search(
+                &AllQuery,
+                &TopDocs::with_limit(count).order_by_fast_field::<DateTime>(
+                    self.schema.get_field_name(evict_field),
+                    Order::Asc,
+                ),
+            )?;
+            for (_, doc_address) in oldest {
+                let doc = searcher.doc::<TantivyDocument>(doc_address)?;
+                if let Some(id) = doc.get_first(self.id_field).and_then(|v| v.as_str()) {
+                    writer.delete_term(Term::from_field_text(self.id_field, id));
+                }
+            }
+            writer.commit()?;
+            // 삭제한 문서가 차지하던 공간은 병합해야 반환됨
+            let segment_ids = self.index.searchable_segment_ids()?;
+            if !segment_ids.is_empty() {
+                writer.merge(&segment_ids).wait()?;
+            }
+            writer.garbage_collect_files().wait()?;
+            size = self.size_bytes()?;
+            if size <= max_size {
+                return Ok(());
+            }
+        }
+        Err(exceeded(size).into())
+    }
+
+    // 결과로 반환할 저장 필드 목록을 찾음 (저장되지 않는 필드는 반환할 값이 없으므로 오류)
+    fn stored_fields(&self, names: &[String]) -> Result<Vec<Field>> {
+        names
+            .iter()
+            .map(|name| {
+                let field = self.field(name)?;
+                if !self.schema.get_field_entry(field).is_stored() {
+                    return Err(anyhow!("'{}' field is not stored", name));
+                }
+                Ok(field)
+            })
+            .collect()
+    }
+
+    // 이름으로 스키마 필드를 찾음
+    fn field(&self, name: &str) -> Result<Field> {
+        self.schema.get_field(name).map_err(|_| {
+            TantivyError::new(
+                TantivyErrorKind::FieldNotFound,
+                format!("'{}' field not found", name),
+            )
+            .into()
+        })
+    }
+
+    // 필드의 토크나이저로 검색어를 분석하여 색인된 형태의 Term 목록을 생성
+    // 토큰화되지 않는 필드는 검색어 그대로 하나의 Term이 됩니다.
+    fn analyze_terms(&self, field: Field, text: &str) -> Result<Vec<Term>> {
+        let mut tokenizer = self.index.tokenizer_for_field(field)?;
+        let mut token_stream = tokenizer.token_stream(text);
+        let mut terms = Vec::new();
+        token_stream.process(&mut |token| terms.push(Term::from_field_text(field, &token.text)));
+        Ok(terms)
+    }
+
+    // 쿼리 문자열을 기본 검색 필드 대상으로 파싱
+    fn parse_query(&self, query: &str) -> Result<Box<dyn Query>> {
+        Ok(self.query_parser().parse_query(query)?)
+    }
+
+    // 쿼리 문자열을 지정한 필드 대상으로 파싱하며, 필드별 가중치를 적용
+    fn parse_query_in_fields(&self, query: &str, fields: &[FieldBoost]) -> Result<Box<dyn Query>> {
+        let config = QueryParserConfig {
+            default_fields: Some(fields.to_vec()),
+            conjunction_by_default: None,
+        };
+        self.parse_query_with(query, &config)
+    }
+
+    // 인덱스의 쿼리 파서 설정 중 config에 지정한 항목만 바꾸어 파싱
+    fn parse_query_with(&self, query: &str, config: &QueryParserConfig) -> Result<Box<dyn Query>> {
+        let settings = self.query_settings_with(config)?;
+        Ok(self.query_parser_for(&settings).parse_query(query)?)
+    }
+
+    // 현재 쿼리 파서 설정에 config를 덮어쓴 설정을 만듦
+    fn query_settings_with(&self, config: &QueryParserConfig) -> Result<QuerySettings> {
+        let mut settings = self.query_settings.read().unwrap().clone();
+        if let Some(fields) = &config.default_fields {
+            if fields.is_empty() {
+                return Err(anyhow!("At least one search field must be given"));
+            }
+            settings.fields = Vec::with_capacity(fields.len());
+            settings.boosts = Vec::new();
+            for field_boost in fields {
+                let field = self.text_search_field(&field_boost.field)?;
+                settings.fields.push(field);
+                if let Some(boost) = field_boost.boost {
+                    settings.boosts.push((field, boost));
+                }
+            }
+        }
+        if let Some(conjunction_by_default) = config.conjunction_by_default {
+            settings.conjunction_by_default = conjunction_by_default;
+        }
+        Ok(settings)
+    }
+
+    // 쿼리 파서 설정을 필드 이름으로 나타낸 설정 (필드 순서가 다를 수 있는 다른 인덱스에 같은 설정을 적용할 때 사용)
+    fn query_parser_config(&self) -> QueryParserConfig {
+        let settings = self.query_settings.read().unwrap();
+        let default_fields = settings
+            .fields
+            .iter()
+            .map(|&field| FieldBoost {
+                field: self.schema.get_field_name(field).to_string(),
+                boost: settings
+                    .boosts
+                    .iter()
+                    .find(|(boosted, _)| *boosted == field)
+                    .map(|&(_, boost)| boost),
+            })
+            .collect::<Vec<_>>();
+        QueryParserConfig {
+            default_fields: (!default_fields.is_empty()).then_some(default_fields),
+            conjunction_by_default: Some(settings.conjunction_by_default),
+        }
+    }
+
+    // 인덱스의 쿼리 파서 설정을 적용한 쿼리 파서
+    fn query_parser(&self) -> QueryParser {
+        self.query_parser_for(&self.query_settings.read().unwrap())
+    }
+
+    fn query_parser_for(&self, settings: &QuerySettings) -> QueryParser {
+        let mut query_parser = QueryParser::for_index(&self.index, settings.fields.clone());
+        for &(field, boost) in &settings.boosts {
+            query_parser.set_field_boost(field, boost);
+        }
+        if settings.conjunction_by_default {
+            query_parser.set_conjunction_by_default();
+        }
+        query_parser
+    }
+
+    // 문법 오류가 있어도 해석 가능한 부분만으로 쿼리를 만들고 오류는 경고로 반환
+    fn parse_query_lenient(&self, query: &str) -> (Box<dyn Query>, Vec<String>) {
+        let query_parser = self.query_parser();
+        let (query, errors) = query_parser.parse_query_lenient(query);
+        (query, errors.iter().map(|e| e.to_string()).collect())
+    }
+
+    // 분석된 모든 Term이 일치해야 하는 쿼리
+    fn term_query(&self, field_name: &str, text: &str) -> Result<Box<dyn Query>> {
+        let field = self.field(field_name)?;
+        if self.schema.get_field_entry(field).field_type().value_type() == Type::Bool {
+            let value = text.trim().parse().map_err(|_| {
+                anyhow!(
+                    "'{}' field expects \"true\" or \"false\", got '{}'",
+                    field_name,
+                    text
+                )
+            })?;
+            return Ok(Box::new(TermQuery::new(
+                Term::from_field_bool(field, value),
+                IndexRecordOption::Basic,
+            )));
+        }
+        let field = self.text_search_field(field_name)?;
+        let terms = self.analyze_terms(field, text)?;
+        if terms.is_empty() {
+            return Err(anyhow!("Search term must not be empty"));
+        }
+        Ok(Box::new(BooleanQuery::new(
+            terms
+                .into_iter()
+                .map(|term| {
+                    let query: Box<dyn Query> =
+                        Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+                    (Occur::Must, query)
+                })
+                .collect(),
+        )))
+    }
+
+    // 입력 중인 검색어용 쿼리: 마지막 Term은 접두어로, 나머지 Term은 그대로 일치해야 함
+    // 기본 검색 필드 중 하나에서 모든 조건을 만족하면 일치합니다.
+    fn prefix_query(&self, text: &str) -> Result<Box<dyn Query>> {
+        let mut field_queries: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        let fields = self.query_settings.read().unwrap().fields.clone();
+        for field in fields {
+            let mut terms = self.analyze_terms(field, text)?;
+            let Some(prefix) = terms.pop() else {
+                continue;
+            };
+            let mut clauses: Vec<(Occur, Box<dyn Query>)> = terms
+                .into_iter()
+                .map(|term| {
+                    let query: Box<dyn Query> =
+                        Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+                    (Occur::Must, query)
+                })
+                .collect();
+            // Term이 하나뿐인 PhrasePrefixQuery는 접두어 범위 검색으로 동작
+            clauses.push((Occur::Must, Box::new(PhrasePrefixQuery::new(vec![prefix]))));
+            field_queries.push((Occur::Should, Box::new(BooleanQuery::new(clauses))));
+        }
+        if field_queries.is_empty() {
+            return Err(anyhow!("Search term must not be empty"));
+        }
+        Ok(Box::new(BooleanQuery::new(field_queries)))
+    }
+
+    // 분석된 각 Term이 max_distance 이내의 편집 거리로 일치해야 하는 퍼지 쿼리
+    fn fuzzy_query(
+        &self,
+        field_name: &str,
+        text: &str,
+        max_distance: u8,
+    ) -> Result<Box<dyn Query>> {
+        if max_distance > 2 {
+            return Err(anyhow!("max_distance must be between 0 and 2"));
+        }
+        let field = self.text_search_field(field_name)?;
+        let terms = self.analyze_terms(field, text)?;
+        if terms.is_empty() {
+            return Err(anyhow!("Search term must not be empty"));
+        }
+
+        // 인접 문자 전치(transposition)도 편집 거리 1로 계산
+        Ok(Box::new(BooleanQuery::new(
+            terms
+                .into_iter()
+                .map(|term| {
+                    let query: Box<dyn Query> =
+                        Box::new(FuzzyTermQuery::new(term, max_distance, true));
+                    (Occur::Must, query)
+                })
+                .collect(),
+        )))
+    }
+
+    // 단어들이 slop 이내의 간격으로 순서대로 나타나야 하는 구문 쿼리
+    fn phrase_query(&self, field_name: &str, text: &str, slop: u32) -> Result<Box<dyn Query>> {
+        let field = self.text_search_field(field_name)?;
+        let has_positions = match self.schema.get_field_entry(field).field_type() {
+            tantivy::schema::FieldType::Str(options) => options
+                .get_indexing_options()
+                .is_some_and(|indexing| indexing.index_option().has_positions()),
+            _ => false,
+        };
+        if !has_positions {
+            return Err(anyhow!(
+                "'{}' field does not support phrase queries",
+                field_name
+            ));
+        }
+
+        let mut terms = self.analyze_terms(field, text)?;
+        match terms.len() {
+            0 => Err(anyhow!("Phrase must not be empty")),
+            // PhraseQuery는 두 개 이상의 Term이 필요하므로 단일 단어는 TermQuery로 처리
+            1 => Ok(Box::new(TermQuery::new(
+                terms.remove(0),
+                IndexRecordOption::WithFreqs,
+            ))),
+            _ => {
+                let mut phrase_query = PhraseQuery::new(terms);
+                phrase_query.set_slop(slop);
+                Ok(Box::new(phrase_query))
+            }
+        }
+    }
+
+    // 텍스트 검색이 가능한 (Text/String) 필드를 찾음
+    fn text_search_field(&self, name: &str) -> Result<Field> {
+        let field = self.field(name)?;
+        if self.schema.get_field_entry(field).field_type().value_type() != Type::Str {
+            return Err(anyhow!("'{}' field is not a text field", name));
+        }
+        Ok(field)
+    }
+
+    // 필드에 값이 있는 문서를 찾는 쿼리
+    // fast 필드는 fast 값으로 확인하고, fast가 아닌 색인된 문자열 필드는 색인된 단어가 하나라도 있는지 확인합니다.
+    fn exists_query(&self, field_name: &str) -> Result<Box<dyn Query>> {
+        let field = self.field(field_name)?;
+        let field_type = self.schema.get_field_entry(field).field_type();
+        if field_type.is_fast() {
+            return Ok(Box::new(ExistsQuery::new(field_name.to_string(), false)));
+        }
+        if field_type.value_type() == Type::Str && field_type.is_indexed() {
+            return Ok(Box::new(RegexQuery::from_pattern(".*", field)?));
+        }
+        Err(anyhow!(
+            "'{}' field must be a fast field or an indexed text field for Exists queries",
+            field_name
+        ))
+    }
+
+    // 필드 값이 lower와 upper 사이에 있는 문서를 찾는 범위 쿼리
+    fn range_query(
+        &self,
+        field_name: &str,
+        lower: Option<&RangeBound>,
+        upper: Option<&RangeBound>,
+    ) -> Result<Box<dyn Query>> {
+        if lower.is_none() && upper.is_none() {
+            return Err(anyhow!("Range query requires 'lower' or 'upper'"));
+        }
+        let field = self.field(field_name)?;
+        let to_bound = |bound: Option<&RangeBound>| -> Result<Bound<Term>> {
+            Ok(match bound {
+                None => Bound::Unbounded,
+                Some(bound) if bound.inclusive => Bound::Included(self.range_term(field, bound)?),
+                Some(bound) => Bound::Excluded(self.range_term(field, bound)?),
+            })
+        };
+        Ok(Box::new(RangeQuery::new(
+            to_bound(lower)?,
+            to_bound(upper)?,
+        )))
+    }
+
+    // 범위 경계값을 필드 타입에 맞는 Term으로 변환
+    // Text/String 값은 분석하지 않고 색인된 단어와 그대로 비교합니다.
+    fn range_term(&self, field: Field, bound: &RangeBound) -> Result<Term> {
+        let field_entry = self.schema.get_field_entry(field);
+        let mismatch = || {
+            anyhow!(
+                "'{}' range bound expects a {:?} value",
+                field_entry.name(),
+                field_entry.field_type().value_type()
+            )
+        };
+        let term = match field_entry.field_type().value_type() {
+            Type::Str => {
+                Term::from_field_text(field, bound.text_value.as_ref().ok_or_else(mismatch)?)
+            }
+            Type::I64 => Term::from_field_i64(field, bound.i64_value.ok_or_else(mismatch)?),
+            Type::U64 => Term::from_field_u64(field, bound.u64_value.ok_or_else(mismatch)?),
+            Type::F64 => Term::from_field_f64(field, bound.f64_value.ok_or_else(mismatch)?),
+            Type::IpAddr => {
+                let ip = parse_ip(bound.text_value.as_ref().ok_or_else(mismatch)?)?;
+                Term::from_field_ip_addr(field, ip)
+            }
+            Type::Date => {
+                let date = DateTime::from_timestamp_millis(bound.date_value.ok_or_else(mismatch)?);
+                // fast field는 저장된 정밀도 그대로, 역색인은 색인 정밀도(초)로 비교
+                if field_entry.is_fast() {
+                    Term::from_field_date(field, date)
+                } else {
+                    Term::from_field_date_for_search(field, date)
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "'{}' field cannot be used in a range query",
+                    field_entry.name()
+                ))
+            }
+        };
+        Ok(term)
+    }
+
+    // 점수에 영향을 주지 않는 filter 조건을 쿼리에 AND로 추가
+    fn with_filter(&self, query: Box<dyn Query>, filter: &SearchQuery) -> Result<Box<dyn Query>> {
+        let filter: Box<dyn Query> = Box::new(ConstScoreQuery::new(self.build_query(filter)?, 0.0));
+        Ok(Box::new(BooleanQuery::new(vec![
+            (Occur::Must, query),
+            (Occur::Must, filter),
+        ])))
+    }
+
+    // Flutter에서 전달한 구조화된 쿼리를 Tantivy 쿼리로 변환 (중첩 가능)
+    fn build_query(&self, query: &SearchQuery) -> Result<Box<dyn Query>> {
+        let field = || {
+            query
+                .field
+                .as_deref()
+                .ok_or_else(|| anyhow!("{:?} query requires a field", query.kind))
+        };
+        let text = || {
+            query
+                .text
+                .as_deref()
+                .ok_or_else(|| anyhow!("{:?} query requires text", query.kind))
+        };
+
+        let mut built = match query.kind {
+            QueryKind::QueryString => self.parse_query(text()?),
+            QueryKind::Term => self.term_query(field()?, text()?),
+            QueryKind::Phrase => self.phrase_query(field()?, text()?, query.slop.unwrap_or(0)),
+            QueryKind::Fuzzy => {
+                self.fuzzy_query(field()?, text()?, query.max_distance.unwrap_or(1))
+            }
+            QueryKind::Range => {
+                self.range_query(field()?, query.lower.as_ref(), query.upper.as_ref())
+            }
+            QueryKind::Exists => self.exists_query(field()?),
+            QueryKind::Boolean => {
+                let mut clauses = Vec::new();
+                for (occur, subqueries) in [
+                    (Occur::Must, &query.must),
+                    (Occur::Should, &query.should),
+                    (Occur::MustNot, &query.must_not),
+                ] {
+                    for subquery in subqueries {
+                        clauses.push((occur, self.build_query(subquery)?));
+                    }
+                }
+                // 제외 조건만 있는 경우 전체 문서에서 제외하도록 AllQuery를 추가
+                if query.must.is_empty() && query.should.is_empty() {
+                    clauses.push((Occur::Must, Box::new(AllQuery)));
+                }
+                let boolean_query: Box<dyn Query> = Box::new(BooleanQuery::new(clauses));
+                Ok(boolean_query)
+            }
+        }?;
+
+        // 점수 조정: const_score로 고정한 뒤 boost를 곱함
+        if let Some(score) = query.const_score {
+            built = Box::new(ConstScoreQuery::new(built, score));
+        }
+        if let Some(boost) = query.boost {
+            built = Box::new(BoostQuery::new(built, boost));
+        }
+        Ok(built)
+    }
+
+    // 스키마에 맞는 JSON 문자열을 Tantivy 문서로 변환하고 문서의 id Term을 함께 반환
+    fn parse_json_doc(&self, json: &str) -> Result<(Term, TantivyDocument)> {
+        let mut tantivy_doc = self.stamp_version(TantivyDocument::parse_json(&self.schema, json)?);
+        fill_shadow_fields(&mut tantivy_doc, &self.shadow_fields);
+        fill_language_fields(&mut tantivy_doc, &self.language_fields);
+        let id = tantivy_doc
+            .get_first(self.id_field)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow!("JSON document must have a string 'id' field"))?;
+        Ok((Term::from_field_text(self.id_field, id), tantivy_doc))
+    }
+
+    // Flutter 문서를 Tantivy 문서로 변환
+    fn to_tantivy_doc(&self, doc: &Document) -> Result<TantivyDocument> {
+        let mut tantivy_doc = TantivyDocument::new();
+        tantivy_doc.add_text(self.id_field, &doc.id);
+        if let Some(text_field) = self.text_field {
+            tantivy_doc.add_text(text_field, &doc.text);
+        }
+        if !doc.metadata.is_empty() {
+            let metadata_field = self
+                .metadata_field
+                .ok_or_else(|| anyhow!("This index does not support metadata"))?;
+            let metadata = doc
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.clone(), OwnedValue::Str(value.clone())))
+                .collect();
+            tantivy_doc.add_object(metadata_field, metadata);
+        }
+        for field_value in &doc.fields {
+            let field = self.field(&field_value.name)?;
+            if Some(field) == self.version_field || Some(field) == self.parent_field {
+                return Err(anyhow!(
+                    "'{}' field is managed by the index",
+                    field_value.name
+                ));
+            }
+            if field == self.id_field
+                || Some(field) == self.text_field
+                || Some(field) == self.metadata_field
+            {
+                return Err(anyhow!(
+                    "'{}' field must be set through Document.{}",
+                    field_value.name,
+                    field_value.name
+                ));
+            }
+            // 필드 타입에 맞는 값이 지정되었는지 확인
+            let field_entry = self.schema.get_field_entry(field);
+            let value_type = field_entry.field_type().value_type();
+            let mismatch = || {
+                anyhow!(
+                    "'{}' field expects a {:?} value",
+                    field_value.name,
+                    value_type
+                )
+            };
+            match value_type {
+                Type::Str if is_geo_field(field_entry) => {
+                    let point = field_value.geo_value.ok_or_else(|| {
+                        anyhow!("'{}' field expects a GeoPoint value", field_value.name)
+                    })?;
+                    tantivy_doc.add_text(field, geo::format_point(point.validate()?))
+                }
+                Type::Str => {
+                    let value = field_value.text_value.as_ref().ok_or_else(mismatch)?;
+                    tantivy_doc.add_text(field, value)
+                }
+                Type::I64 => {
+                    tantivy_doc.add_i64(field, field_value.i64_value.ok_or_else(mismatch)?)
+                }
+                Type::U64 => {
+                    tantivy_doc.add_u64(field, field_value.u64_value.ok_or_else(mismatch)?)
+                }
+                Type::F64 => {
+                    tantivy_doc.add_f64(field, field_value.f64_value.ok_or_else(mismatch)?)
+                }
+                Type::Date => {
+                    let millis = field_value.date_value.ok_or_else(mismatch)?;
+                    tantivy_doc.add_date(field, DateTime::from_timestamp_millis(millis))
+                }
+                Type::Bool => {
+                    tantivy_doc.add_bool(field, field_value.bool_value.ok_or_else(mismatch)?)
+                }
+                Type::Facet => {
+                    let path = field_value.text_value.as_ref().ok_or_else(mismatch)?;
+                    tantivy_doc.add_facet(field, Facet::from_text(path)?)
+                }
+                Type::IpAddr => {
+                    let ip = field_value.text_value.as_ref().ok_or_else(mismatch)?;
+                    tantivy_doc.add_ip_addr(field, parse_ip(ip)?)
+                }
+                Type::Bytes if !field_entry.is_fast() => {
+                    let bytes = field_value.bytes_value.as_ref().ok_or_else(mismatch)?;
+                    tantivy_doc.add_bytes(field, bytes)
+                }
+                Type::Bytes => {
+                    let vector = field_value.vector_value.as_ref().ok_or_else(mismatch)?;
+                    if vector.is_empty() || !vector.iter().all(|v| v.is_finite()) {
+                        return Err(anyhow!(
+                            "'{}' field: vectors must be non-empty and finite",
+                            field_value.name
+                        ));
+                    }
+                    tantivy_doc.add_bytes(field, &encode_vector(vector))
+                }
+                _ => return Err(mismatch()),
+            }
+        }
+        fill_shadow_fields(&mut tantivy_doc, &self.shadow_fields);
+        fill_language_fields(&mut tantivy_doc, &self.language_fields);
+        if let Some(version_field) = self.version_field {
+            tantivy_doc.add_u64(version_field, self.next_version());
+        }
+        Ok(tantivy_doc)
+    }
+
+    // root 아래 경로의 파일 문서 (문서 ID가 곧 경로이므로 ID의 범위로 찾음)
+    // file_schema_fields()의 필드가 없는 문서는 제외합니다.
+    fn file_documents_under(&self, root: &Path) -> Result<HashMap<String, FileDocument>> {
+        // "/docs/"로 시작하는 ID는 "/docs/" 이상 "/docs0" 미만
+        let lower = root.join("").to_string_lossy().into_owned();
+        let mut upper = lower.clone();
+        let separator = upper.pop().unwrap_or('/');
+        upper.push(char::from_u32(separator as u32 + 1).unwrap_or(char::MAX));
+        let query = RangeQuery::new(
+            Bound::Included(Term::from_field_text(self.id_field, &lower)),
+            Bound::Excluded(Term::from_field_text(self.id_field, &upper)),
+        );
+
+        let searcher = self.searcher()?;
+        let mut documents = HashMap::new();
+        for doc_address in searcher.search(&query, &DocSetCollector)? {
+            let doc = self.to_document(&searcher.doc::<TantivyDocument>(doc_address)?);
+            if let Ok(file_doc) = FileDocument::from_document(doc) {
+                documents.insert(file_doc.path.clone(), file_doc);
+            }
+        }
+        Ok(documents)
+    }
+
+    // index_directory에 필요한 file_schema_fields()의 필드가 모두 있는지 확인
+    fn check_file_fields(&self) -> Result<()> {
+        if self.text_field.is_none() {
+            return Err(anyhow!("This index has no 'text' field for file contents"));
+        }
+        for name in [
+            FILE_PATH_FIELD,
+            FILE_SIZE_FIELD,
+            FILE_MODIFIED_FIELD,
+            FILE_MIME_TYPE_FIELD,
+            FILE_HASH_FIELD,
+        ] {
+            if self.schema.get_field(name).is_err() {
+                return Err(anyhow!(
+                    "'{}' field not found; create the index with file_schema_fields()",
+                    name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // 조각 문서의 원본 ID 필드 (원본 ID 필드가 없는 인덱스면 오류)
+    fn chunk_parent_field(&self) -> Result<Field> {
+        self.parent_field.ok_or_else(|| {
+            anyhow!(
+                "This index was created without chunk support; rebuild it with reindex_with_schema"
+            )
+        })
+    }
+
+    // 문서를 쓸 때마다 증가하는 버전 (인덱스 안에서 고유)
+    fn next_version(&self) -> u64 {
+        self.next_version.fetch_add(1, Ordering::Relaxed)
+    }
+
+    // JSON 문서에 들어 있는 버전(export_documents의 출력 등)을 버리고 새 버전을 부여
+    fn stamp_version(&self, tantivy_doc: TantivyDocument) -> TantivyDocument {
+        let Some(version_field) = self.version_field else {
+            return tantivy_doc;
+        };
+        let mut stamped = TantivyDocument::new();
+        for (field, value) in tantivy_doc.field_values() {
+            if field != version_field {
+                stamped.add_field_value(field, value);
+            }
+        }
+        stamped.add_u64(version_field, self.next_version());
+        stamped
+    }
+
+    // 저장된 문서의 버전 (문서가 없거나 버전 없이 저장된 문서면 0)
+    fn stored_version(&self, searcher: &Searcher, id: &str) -> Result<u64> {
+        let id_term = Term::from_field_text(self.id_field, id);
+        let query = TermQuery::new(id_term, IndexRecordOption::Basic);
+        let Some(&(_, doc_address)) = searcher.search(&query, &TopDocs::with_limit(1))?.first()
+        else {
+            return Ok(0);
+        };
+        let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+        Ok(self
+            .version_field
+            .and_then(|field| retrieved_doc.get_first(field))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0))
+    }
+
+    // 저장된 Tantivy 문서를 Flutter 문서로 변환
+    fn to_document(&self, retrieved_doc: &TantivyDocument) -> Document {
+        self.to_document_with_fields(retrieved_doc, None)
+    }
+
+    // return_fields가 주어지면 id와 지정한 필드만 변환 (지정하지 않은 text는 빈 문자열)
+    fn to_document_with_fields(
+        &self,
+        retrieved_doc: &TantivyDocument,
+        return_fields: Option<&[Field]>,
+    ) -> Document {
+        let selected = |field: Field| return_fields.is_none_or(|fields| fields.contains(&field));
+        let id = retrieved_doc
+            .get_first(self.id_field)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let text = self
+            .text_field
+            .filter(|&field| selected(field))
+            .and_then(|field| retrieved_doc.get_first(field))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let mut fields = Vec::new();
+        let mut metadata = HashMap::new();
+        for (field, value) in retrieved_doc.field_values() {
+            if field == self.id_field
+                || Some(field) == self.text_field
+                || Some(field) == self.version_field
+                || Some(field) == self.parent_field
+                || !selected(field)
+            {
+                continue;
+            }
+            if Some(field) == self.metadata_field {
+                for (key, value) in value.as_object().into_iter().flatten() {
+                    if let Some(value) = value.as_str() {
+                        metadata.insert(key.to_string(), value.to_string());
+                    }
+                }
+                continue;
+            }
+            let field_entry = self.schema.get_field_entry(field);
+            let mut field_value = FieldValue::empty(field_entry.name().to_string());
+            if is_geo_field(field_entry) {
+                let Some((lat, lon)) = value.as_str().and_then(geo::parse_point) else {
+                    continue;
+                };
+                field_value.geo_value = Some(GeoPoint { lat, lon });
+            } else if let Some(v) = value.as_str() {
+                field_value.text_value = Some(v.to_string());
+            } else if let Some(v) = value.as_i64() {
+                field_value.i64_value = Some(v);
+            } else if let Some(v) = value.as_u64() {
+                field_value.u64_value = Some(v);
+            } else if let Some(v) = value.as_f64() {
+                field_value.f64_value = Some(v);
+            } else if let Some(v) = value.as_datetime() {
+                field_value.date_value = Some(v.into_timestamp_millis());
+            } else if let Some(v) = value.as_bool() {
+                field_value.bool_value = Some(v);
+            } else if let Some(facet) = value
+                .as_facet()
+                .and_then(|v| Facet::from_encoded(v.as_bytes().to_vec()).ok())
+            {
+                field_value.text_value = Some(facet.to_path_string());
+            } else if let Some(ip) = value.as_ip_addr() {
+                field_value.text_value = Some(format_ip(ip));
+            } else if let Some(bytes) = value.as_bytes() {
+                if field_entry.is_fast() {
+                    field_value.vector_value = Some(decode_vector(bytes));
+                } else {
+                    field_value.bytes_value = Some(bytes.to_vec());
+                }
+            } else {
+                continue;
+            }
+            fields.push(field_value);
+        }
+
+        Document {
+            id,
+            text,
+            fields,
+            metadata,
+        }
+    }
+
+    // 쿼리를 실행하고 상위 결과를 Flutter 검색 결과로 변환
+    fn search(&self, query: &dyn Query, top_k: usize) -> Result<Vec<SearchResult>> {
+        // TopDocs는 limit이 0이면 panic하므로 빈 결과를 바로 반환
+        if top_k == 0 {
+            return Ok(Vec::new());
+        }
+        let searcher = self.searcher()?;
+
+        let top_docs = searcher.search(query, &TopDocs::with_limit(top_k))?;
+
+        self.to_search_results(&searcher, top_docs, None)
+    }
+
+    // offset부터 limit개의 결과와 전체 일치 문서 수를 함께 반환
+    fn search_page(
+        &self,
+        query: &dyn Query,
+        offset: usize,
+        limit: usize,
+    ) -> Result<SearchResponse> {
+        let searcher = self.searcher()?;
+
+        // TopDocs는 limit이 0이면 panic하므로 개수만 계산
+        if limit == 0 {
+            let total_hits = searcher.search(query, &Count)?;
+            return Ok(SearchResponse {
+                total_hits,
+                results: Vec::new(),
+            });
+        }
+
+        let (total_hits, top_docs) = searcher.search(
+            query,
+            &(Count, TopDocs::with_limit(limit).and_offset(offset)),
+        )?;
+
+        Ok(SearchResponse {
+            total_hits,
+            results: self.to_search_results(&searcher, top_docs, None)?,
+        })
+    }
+
+    // 관련도 대신 fast field 값 순서로 offset부터 top_k개의 결과와 전체 일치 문서 수를 함께 반환
+    fn search_sorted(
+        &self,
+        query: &dyn Query,
+        sort_field: &str,
+        ascending: bool,
+        offset: usize,
+        top_k: usize,
+    ) -> Result<SearchResponse> {
+        let field = self.field(sort_field)?;
+        let field_type = self.schema.get_field_entry(field).field_type();
+        if !field_type.is_fast() {
+            return Err(anyhow!("'{}' field is not a fast field", sort_field));
+        }
+        let order = if ascending { Order::Asc } else { Order::Desc };
+
+        let searcher = self.searcher()?;
+
+        // TopDocs는 limit이 0이면 panic하므로 개수만 계산
+        if top_k == 0 {
+            let total_hits = searcher.search(query, &Count)?;
+            return Ok(SearchResponse {
+                total_hits,
+                results: Vec::new(),
+            });
+        }
+        let top_docs = TopDocs::with_limit(top_k).and_offset(offset);
+        let (total_hits, doc_addresses): (usize, Vec<DocAddress>) = match field_type.value_type() {
+            Type::I64 => {
+                let (total_hits, top_docs) = searcher.search(
+                    query,
+                    &(
+                        Count,
+                        top_docs.order_by_fast_field::<i64>(sort_field, order),
+                    ),
+                )?;
+                (
+                    total_hits,
+                    top_docs.into_iter().map(|(_, address)| address).collect(),
+                )
+            }
+            Type::U64 => {
+                let (total_hits, top_docs) = searcher.search(
+                    query,
+                    &(
+                        Count,
+                        top_docs.order_by_fast_field::<u64>(sort_field, order),
+                    ),
+                )?;
+                (
+                    total_hits,
+                    top_docs.into_iter().map(|(_, address)| address).collect(),
+                )
+            }
+            Type::F64 => {
+                let (total_hits, top_docs) = searcher.search(
+                    query,
+                    &(
+                        Count,
+                        top_docs.order_by_fast_field::<f64>(sort_field, order),
+                    ),
+                )?;
+                (
+                    total_hits,
+                    top_docs.into_iter().map(|(_, address)| address).collect(),
+                )
+            }
+            Type::Date => {
+                let (total_hits, top_docs) = searcher.search(
+                    query,
+                    &(
+                        Count,
+                        top_docs.order_by_fast_field::<DateTime>(sort_field, order),
+                    ),
+                )?;
+                (
+                    total_hits,
+                    top_docs.into_iter().map(|(_, address)| address).collect(),
+                )
+            }
+            Type::Str => {
+                let (total_hits, top_docs) = searcher.search(
+                    query,
+                    &(
+                        Count,
+                        top_docs.order_by_string_fast_field(sort_field, order),
+                    ),
+                )?;
+                (
+                    total_hits,
+                    top_docs.into_iter().map(|(_, address)| address).collect(),
+                )
+            }
+            _ => return Err(anyhow!("'{}' field cannot be used for sorting", sort_field)),
+        };
+
+        // 정렬 검색에서는 관련도 점수를 계산하지 않으므로 score는 0
+        let results = self.to_search_results(
+            &searcher,
+            doc_addresses
+                .into_iter()
+                .map(|address| (0.0, address))
+                .collect(),
+            None,
+        )?;
+        Ok(SearchResponse {
+            total_hits,
+            results,
+        })
+    }
+
+    // 지정한 k1, b로 BM25 점수를 다시 계산하여 상위 결과를 반환
+    // Tantivy의 BM25 상수는 고정되어 있으므로, 쿼리로 일치 여부만 판단하고
+    // 점수는 쿼리에 포함된 Term들의 BM25 합으로 계산합니다. (boost 등 쿼리 구조는 점수에 반영되지 않음)
+    fn search_bm25(
+        &self,
+        query: &dyn Query,
+        k1: f32,
+        b: f32,
+        top_k: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let searcher = self.searcher()?;
+
+        // TopDocs는 limit이 0이면 panic하므로 빈 결과를 바로 반환
+        if top_k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut terms = Vec::new();
+        query.query_terms(&mut |term, _| {
+            if !terms.contains(term) {
+                terms.push(term.clone());
+            }
+        });
+
+        // Term별 idf와 필드의 평균 길이를 미리 계산
+        let total_num_docs = searcher.total_num_docs()?;
+        let mut term_stats = Vec::with_capacity(terms.len());
+        for term in terms {
+            let doc_freq = searcher.doc_freq(&term)?.min(total_num_docs);
+            let idf =
+                (1.0 + ((total_num_docs - doc_freq) as f32 + 0.5) / (doc_freq as f32 + 0.5)).ln();
+            let average_fieldnorm =
+                searcher.total_num_tokens(term.field())? as f32 / total_num_docs.max(1) as f32;
+            term_stats.push((term, idf, average_fieldnorm));
+        }
+
+        let collector = TopDocs::with_limit(top_k).tweak_score(
+            move |segment_reader: &tantivy::SegmentReader| {
+                let mut segment_terms: Vec<_> = term_stats
+                    .iter()
+                    .filter_map(|(term, idf, average_fieldnorm)| {
+                        let postings = segment_reader
+                            .inverted_index(term.field())
+                            .ok()?
+                            .read_postings(term, IndexRecordOption::WithFreqs)
+                            .ok()??;
+                        let fieldnorms = segment_reader.get_fieldnorms_reader(term.field()).ok();
+                        Some((postings, fieldnorms, *idf, *average_fieldnorm))
+                    })
+                    .collect();
+
+                move |doc: DocId, _score: f32| {
+                    let mut score = 0.0;
+                    for (postings, fieldnorms, idf, average_fieldnorm) in &mut segment_terms {
+                        // 문서는 오름차순으로 전달되므로 앞으로만 이동
+                        if postings.doc() < doc {
+                            postings.seek(doc);
+                        }
+                        if postings.doc() != doc {
+                            continue;
+                        }
+                        let tf = postings.term_freq() as f32;
+                        let fieldnorm = fieldnorms
+                            .as_ref()
+                            .map_or(*average_fieldnorm, |reader| reader.fieldnorm(doc) as f32);
+                        let norm = 1.0 - b + b * fieldnorm / average_fieldnorm.max(f32::EPSILON);
+                        score += *idf * tf * (k1 + 1.0) / (tf + k1 * norm);
+                    }
+                    score
+                }
+            },
+        );
+
+        let top_docs = searcher.search(query, &collector)?;
+        self.to_search_results(&searcher, top_docs, None)
+    }
+
+    // 문서별 가중치를 관련도 점수에 곱하여 검색 (TopDocs::tweak_score)
+    // weight는 세그먼트마다 문서의 가중치를 계산하는 함수를 만듭니다.
+    fn search_weighted<W>(
+        &self,
+        query: &dyn Query,
+        top_k: usize,
+        weight: W,
+    ) -> Result<Vec<SearchResult>>
+    where
+        W: 'static + Send + Sync + Fn(&tantivy::SegmentReader) -> Box<dyn Fn(DocId) -> f32>,
+    {
+        let searcher = self.searcher()?;
+
+        // TopDocs는 limit이 0이면 panic하므로 빈 결과를 바로 반환
+        if top_k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let collector = TopDocs::with_limit(top_k).tweak_score(
+            move |segment_reader: &tantivy::SegmentReader| {
+                let weight = weight(segment_reader);
+                move |doc: DocId, score: f32| score * weight(doc)
+            },
+        );
+
+        let top_docs = searcher.search(query, &collector)?;
+        self.to_search_results(&searcher, top_docs, None)
+    }
+
+    // ID로 문서 주소를 찾음
+    fn doc_address(&self, searcher: &Searcher, id: &str) -> Result<DocAddress> {
+        let id_term = Term::from_field_text(self.id_field, id);
+        searcher
+            .search(
+                &TermQuery::new(id_term, IndexRecordOption::Basic),
+                &TopDocs::with_limit(1),
+            )?
+            .first()
+            .map(|(_, doc_address)| *doc_address)
+            .ok_or_else(|| anyhow!("Document '{}' not found", id))
+    }
+
+    // 검색된 문서 주소들을 Flutter 검색 결과로 변환
+    // highlighter가 주어지면 저장된 필드 값으로 강조 snippet과 일치 위치를 생성
+    fn to_search_results(
+        &self,
+        searcher: &Searcher,
+        top_docs: Vec<(f32, DocAddress)>,
+        highlighter: Option<&Highlighter>,
+    ) -> Result<Vec<SearchResult>> {
+        self.to_search_results_with_fields(searcher, top_docs, highlighter, None)
+    }
+
+    // return_fields가 주어지면 결과 문서에 id와 지정한 필드만 포함
+    fn to_search_results_with_fields(
+        &self,
+        searcher: &Searcher,
+        top_docs: Vec<(f32, DocAddress)>,
+        highlighter: Option<&Highlighter>,
+        return_fields: Option<&[Field]>,
+    ) -> Result<Vec<SearchResult>> {
+        let mut results = Vec::new();
+        for (score, doc_address) in top_docs {
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            results.push(SearchResult {
+                score,
+                doc: self.to_document_with_fields(&retrieved_doc, return_fields),
+                snippet: highlighter.map(|highlighter| {
+                    highlighter
+                        .snippet
+                        .snippet_from_doc(&retrieved_doc)
+                        .to_html()
+                }),
+                highlights: highlighter
+                    .map(|highlighter| highlighter.highlights(&retrieved_doc))
+                    .unwrap_or_default(),
+            });
+        }
+        Ok(results)
+    }
+}
+
+// 이름을 지정하지 않았을 때 사용하는 기본 인덱스 이름
+const DEFAULT_INDEX: &str = "default";
+
+// 이름으로 구분되는 인덱스 레지스트리를 Lazy와 Mutex로 안전하게 관리
+// 각 인덱스는 Arc로 공유되므로 작업 중에는 레지스트리 잠금을 잡고 있지 않습니다.
+// 검색마다 인덱스를 찾으므로 읽기 잠금으로 동시에 조회하고, 열기/닫기/교체할 때만 쓰기 잠금을 사용
+static INDEXES: Lazy<RwLock<HashMap<String, Arc<TantivyApi>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+// 이름으로 열린 인덱스를 찾는 함수 (None이면 기본 인덱스)
+fn get_index(index_name: Option<String>) -> Result<Arc<TantivyApi>, TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    INDEXES
+        .read()
+        .unwrap()
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| not_initialized(&name))
+}
+
+fn not_initialized(name: &str) -> TantivyError {
+    TantivyError::new(
+        TantivyErrorKind::NotInitialized,
+        format!("Tantivy index '{}' not initialized", name),
+    )
+}
+
+// 기본 스키마: id와 text 두 개의 필드
+// language를 지정하면 text 필드에 해당 언어의 분석기를 사용합니다.
+fn default_schema(language: Option<TextLanguage>) -> Schema {
+    let mut schema_builder = Schema::builder();
+    // ID 필드는 고유 식별자로 사용되며, 검색 가능하고 저장됩니다.
+    schema_builder.add_text_field("id", STRING | STORED);
+    // Text 필드는 전문 검색을 위해 사용됩니다.
+    let text_options = match language {
+        Some(language) => TEXT.set_indexing_options(text_indexing(&language.tokenizer_name())),
+        None => TEXT,
+    };
+    schema_builder.add_text_field("text", text_options | STORED);
+    add_metadata_field(&mut schema_builder);
+    add_version_field(&mut schema_builder);
+    add_parent_field(&mut schema_builder);
+    schema_builder.build()
+}
+
+// Document.metadata를 저장하는 필드 이름 (검색 대상이 아님)
+const METADATA_FIELD: &str = "_metadata";
+
+// 색인하지 않고 저장만 하는 JSON 필드로 메타데이터를 보관
+fn add_metadata_field(schema_builder: &mut SchemaBuilder) {
+    schema_builder.add_json_field(METADATA_FIELD, JsonObjectOptions::default().set_stored());
+}
+
+// 문서를 쓸 때마다 바뀌는 버전을 저장하는 필드 이름 (update_document_if_version에서 사용)
+const VERSION_FIELD: &str = "_version";
+
+// 인덱스를 열 때 가장 큰 버전을 빠르게 찾을 수 있도록 fast 필드로 저장
+fn add_version_field(schema_builder: &mut SchemaBuilder) {
+    schema_builder.add_u64_field(VERSION_FIELD, STORED | FAST);
+}
+
+// 조각으로 나누어 색인한 문서의 원본 ID를 저장하는 필드 이름 (add_document_chunked에서 사용)
+const PARENT_FIELD: &str = "_parent";
+
+// 원본 ID로 조각을 삭제하고 검색 결과를 원본별로 묶을 수 있도록 색인하고 fast 필드로 저장
+fn add_parent_field(schema_builder: &mut SchemaBuilder) {
+    schema_builder.add_text_field(PARENT_FIELD, STRING | STORED | FAST);
+}
+
+// Flutter에서 전달한 필드 정의로 스키마를 생성
+fn build_schema(fields: &[FieldDef]) -> Result<Schema> {
+    let mut schema_builder = Schema::builder();
+
+    // id 필드는 Update-or-Insert에 필요하므로 정의되지 않았다면 자동으로 추가
+    if !fields.iter().any(|f| f.name == "id") {
+        schema_builder.add_text_field("id", STRING | STORED);
+    }
+
+    add_metadata_field(&mut schema_builder);
+    add_version_field(&mut schema_builder);
+    add_parent_field(&mut schema_builder);
+
+    // 섀도 필드 이름이 다른 필드와 겹치면 Tantivy 내부에서 panic이 발생하므로 미리 검사
+    let mut names: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+    names.extend([
+        "id".to_string(),
+        METADATA_FIELD.to_string(),
+        VERSION_FIELD.to_string(),
+        PARENT_FIELD.to_string(),
+    ]);
+    for field in fields {
+        for tokenizer in &field.shadow_tokenizers {
+            let shadow_name = shadow_field_name(&field.name, tokenizer);
+            if names.contains(&shadow_name) {
+                return Err(anyhow!("Duplicate field '{}' in schema", shadow_name));
+            }
+            names.push(shadow_name);
+        }
+        for tokenizer in detect_language_tokenizers(field) {
+            let language_name = language_field_name(&field.name, &tokenizer);
+            if names.contains(&language_name) {
+                return Err(anyhow!("Duplicate field '{}' in schema", language_name));
+            }
+            names.push(language_name);
+        }
+    }
+
+    for (i, field) in fields.iter().enumerate() {
+        if field.name.is_empty() {
+            return Err(anyhow!("Field name must not be empty"));
+        }
+        if [METADATA_FIELD, VERSION_FIELD, PARENT_FIELD].contains(&field.name.as_str()) {
+            return Err(anyhow!("'{}' is a reserved field name", field.name));
+        }
+        // 중복된 필드 이름은 Tantivy 내부에서 panic을 일으키므로 미리 검사
+        if fields[..i].iter().any(|f| f.name == field.name) {
+            return Err(anyhow!("Duplicate field '{}' in schema", field.name));
+        }
+        if field.name == "id" && (field.field_type != FieldType::String || !field.indexed) {
+            return Err(anyhow!("'id' field must be an indexed String field"));
+        }
+
+        if field.name == "text" && !matches!(field.field_type, FieldType::Text | FieldType::String)
+        {
+            return Err(anyhow!("'text' field must be a Text or String field"));
+        }
+        if !field.shadow_tokenizers.is_empty()
+            && (field.field_type != FieldType::Text || !field.indexed)
+        {
+            return Err(anyhow!(
+                "'{}' field: shadow_tokenizers is only supported for indexed Text fields",
+                field.name
+            ));
+        }
+        if !field.detect_languages.is_empty()
+            && (field.field_type != FieldType::Text || !field.indexed)
+        {
+            return Err(anyhow!(
+                "'{}' field: detect_languages is only supported for indexed Text fields",
+                field.name
+            ));
+        }
+
+        if field.record_option.is_some()
+            && !matches!(field.field_type, FieldType::Text | FieldType::String)
+        {
+            return Err(anyhow!(
+                "'{}' field: record_option is only supported for Text and String fields",
+                field.name
+            ));
+        }
+        if field.tokenizer.as_deref() == Some(GEO_TOKENIZER)
+            || field.shadow_tokenizers.iter().any(|t| t == GEO_TOKENIZER)
+        {
+            return Err(anyhow!(
+                "'{}' field: the '{}' tokenizer is reserved for GeoPoint fields",
+                field.name,
+                GEO_TOKENIZER
+            ));
+        }
+
+        match field.field_type {
+            FieldType::Text | FieldType::String => {
+                if field.tokenizer.is_some()
+                    && (field.field_type != FieldType::Text || !field.indexed)
+                {
+                    return Err(anyhow!(
+                        "'{}' field: tokenizer is only supported for indexed Text fields",
+                        field.name
+                    ));
+                }
+                if field.record_option.is_some() && !field.indexed {
+                    return Err(anyhow!(
+                        "'{}' field: record_option is only supported for indexed fields",
+                        field.name
+                    ));
+                }
+                let mut options = match field.field_type {
+                    FieldType::Text if field.indexed => match &field.tokenizer {
+                        Some(tokenizer) => TEXT.set_indexing_options(text_indexing(tokenizer)),
+                        None => TEXT,
+                    },
+                    FieldType::String if field.indexed => STRING,
+                    _ => TextOptions::default(),
+                };
+                if let (Some(record_option), Some(indexing)) =
+                    (field.record_option, options.get_indexing_options())
+                {
+                    let indexing = indexing
+                        .clone()
+                        .set_index_option(record_option.index_option());
+                    options = options.set_indexing_options(indexing);
+                }
+                if field.stored {
+                    options = options.set_stored();
+                }
+                if field.fast {
+                    options = options.set_fast(None);
+                }
+                schema_builder.add_text_field(&field.name, options);
+                for tokenizer in &field.shadow_tokenizers {
+                    schema_builder.add_text_field(
+                        &shadow_field_name(&field.name, tokenizer),
+                        TextOptions::default().set_indexing_options(text_indexing(tokenizer)),
+                    );
+                }
+                for tokenizer in detect_language_tokenizers(field) {
+                    schema_builder.add_text_field(
+                        &language_field_name(&field.name, &tokenizer),
+                        TextOptions::default().set_indexing_options(text_indexing(&tokenizer)),
+                    );
+                }
+            }
+            FieldType::Facet => {
+                // Facet 필드는 항상 색인되며 FacetCollector를 위해 fast field로 저장됩니다.
+                let mut options = FacetOptions::default();
+                if field.stored {
+                    options = options.set_stored();
+                }
+                schema_builder.add_facet_field(&field.name, options);
+            }
+            FieldType::Date => {
+                // 밀리초 단위로 주고받으므로 fast field도 밀리초 정밀도로 저장
+                let mut options =
+                    DateOptions::default().set_precision(DateTimePrecision::Milliseconds);
+                if field.indexed {
+                    options = options.set_indexed();
+                }
+                if field.stored {
+                    options = options.set_stored();
+                }
+                if field.fast {
+                    options = options.set_fast();
+                }
+                schema_builder.add_date_field(&field.name, options);
+            }
+            FieldType::Vector => {
+                // knn_search가 모든 문서의 벡터를 fast field에서 읽으므로 항상 fast로 저장
+                let mut options = BytesOptions::default().set_fast();
+                if field.stored {
+                    options = options.set_stored();
+                }
+                schema_builder.add_bytes_field(&field.name, options);
+            }
+            FieldType::Bytes => {
+                // fast field가 아닌 bytes 필드로 Vector 필드와 구분
+                schema_builder.add_bytes_field(&field.name, BytesOptions::default().set_stored());
+            }
+            FieldType::GeoPoint => {
+                // 영역 검색은 지오해시 접두어 색인으로, 정확한 거리는 fast field의 좌표로 계산
+                let indexing = TextFieldIndexing::default()
+                    .set_tokenizer(GEO_TOKENIZER)
+                    .set_index_option(IndexRecordOption::Basic);
+                let mut options = TextOptions::default()
+                    .set_indexing_options(indexing)
+                    .set_fast(None);
+                if field.stored {
+                    options = options.set_stored();
+                }
+                schema_builder.add_text_field(&field.name, options);
+            }
+            FieldType::IpAddr => {
+                let mut options = IpAddrOptions::default();
+                if field.indexed {
+                    options = options.set_indexed();
+                }
+                if field.stored {
+                    options = options.set_stored();
+                }
+                if field.fast {
+                    options = options.set_fast();
+                }
+                schema_builder.add_ip_addr_field(&field.name, options);
+            }
+            FieldType::I64 | FieldType::U64 | FieldType::F64 | FieldType::Bool => {
+                let mut options = NumericOptions::default();
+                if field.indexed {
+                    options = options.set_indexed();
+                }
+                if field.stored {
+                    options = options.set_stored();
+                }
+                if field.fast {
+                    options = options.set_fast();
+                }
+                match field.field_type {
+                    FieldType::I64 => schema_builder.add_i64_field(&field.name, options),
+                    FieldType::U64 => schema_builder.add_u64_field(&field.name, options),
+                    FieldType::Bool => schema_builder.add_bool_field(&field.name, options),
+                    _ => schema_builder.add_f64_field(&field.name, options),
+                };
+            }
+        }
+    }
+
+    Ok(schema_builder.build())
+}
+
+// Tantivy 인덱스를 초기화하는 함수 (기본 인덱스)
+// 초기화는 빠른 작업이므로 sync로 처리
+// language를 지정하면 text 필드에 어간 추출과 불용어 제거가 적용됩니다.
+// writer_options로 색인 메모리 예산과 스레드 수를 조정할 수 있습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_tantivy(
+    dir_path: String,
+    language: Option<TextLanguage>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    register_index(
+        DEFAULT_INDEX.to_string(),
+        dir_path,
+        language.map(|language| default_schema(Some(language))),
+        writer_options,
+    )
+}
+
+// 사용자 정의 스키마로 Tantivy 인덱스를 초기화하는 함수 (기본 인덱스)
+// 'id' 필드가 없으면 자동으로 추가되며, 'text' 필드가 있으면 Document.text와 연결됩니다.
+// 이미 인덱스가 존재하는 경우 저장된 스키마와 일치해야 합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_tantivy_with_schema(
+    dir_path: String,
+    fields: Vec<FieldDef>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    let schema = build_schema(&fields)?;
+    register_index(
+        DEFAULT_INDEX.to_string(),
+        dir_path,
+        Some(schema),
+        writer_options,
+    )
+}
+
+// 이름을 붙여 사용자 정의 분석기를 등록하는 함수
+// FieldDef.tokenizer에 이 이름을 지정하여 사용하며, 스키마에 이름만 저장되므로
+// 해당 인덱스를 열기 전에 매 실행마다 등록해야 합니다. 이미 열린 인덱스에도 즉시 반영됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn register_tokenizer(name: String, config: TokenizerConfig) -> Result<(), TantivyError> {
+    if name.is_empty() {
+        return Err(anyhow!("Tokenizer name must not be empty").into());
+    }
+    if builtin_tokenizer_manager().get(&name).is_some() {
+        return Err(anyhow!("'{}' is a built-in tokenizer", name).into());
+    }
+    let analyzer = config.analyzer()?;
+
+    for api in INDEXES.read().unwrap().values() {
+        api.index.tokenizers().register(&name, analyzer.clone());
+    }
+    CUSTOM_TOKENIZERS.lock().unwrap().insert(name, analyzer);
+    Ok(())
+}
+
+// 이름을 지정하여 인덱스를 여는 함수
+// 이후 각 함수의 index_name 파라미터로 이 인덱스를 지정합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_index(
+    name: String,
+    dir_path: String,
+    language: Option<TextLanguage>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    register_index(
+        name,
+        dir_path,
+        language.map(|language| default_schema(Some(language))),
+        writer_options,
+    )
+}
+
+// 이름을 지정하여 사용자 정의 스키마로 인덱스를 여는 함수
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_index_with_schema(
+    name: String,
+    dir_path: String,
+    fields: Vec<FieldDef>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    let schema = build_schema(&fields)?;
+    register_index(name, dir_path, Some(schema), writer_options)
+}
+
+// 디스크에 저장하지 않는 메모리 인덱스를 초기화하는 함수 (기본 인덱스)
+// 테스트, 임시 필터링, 미리보기처럼 저장이 필요 없는 경우에 사용하며, 닫으면 모든 문서가 사라집니다.
+// 이미 메모리 인덱스로 열려 있으면 기존 문서를 그대로 유지합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_tantivy_in_memory(
+    language: Option<TextLanguage>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    register_memory_index(
+        DEFAULT_INDEX.to_string(),
+        default_schema(language),
+        writer_options,
+    )
+}
+
+// 사용자 정의 스키마로 메모리 인덱스를 초기화하는 함수 (기본 인덱스)
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_tantivy_in_memory_with_schema(
+    fields: Vec<FieldDef>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    let schema = build_schema(&fields)?;
+    register_memory_index(DEFAULT_INDEX.to_string(), schema, writer_options)
+}
+
+// 이름을 지정하여 메모리 인덱스를 여는 함수
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_index_in_memory(
+    name: String,
+    language: Option<TextLanguage>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    register_memory_index(name, default_schema(language), writer_options)
+}
+
+// 이름을 지정하여 사용자 정의 스키마로 메모리 인덱스를 여는 함수
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_index_in_memory_with_schema(
+    name: String,
+    fields: Vec<FieldDef>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    let schema = build_schema(&fields)?;
+    register_memory_index(name, schema, writer_options)
+}
+
+// 열린 인덱스를 닫는 함수 (None이면 기본 인덱스)
+// writer와 reader를 해제하고 잠금 파일을 풀어주므로, 이후 디렉토리를 삭제/이동하거나
+// 다른 경로로 다시 초기화할 수 있습니다. commit되지 않은 변경사항은 버려집니다.
+// 진행 중인 작업이 있으면 해당 작업이 끝나는 즉시 해제됩니다.
+pub fn close_index(index_name: Option<String>) -> Result<(), TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    let api = INDEXES
+        .write()
+        .unwrap()
+        .remove(&name)
+        .ok_or_else(|| not_initialized(&name))?;
+
+    // 마지막 참조라면 여기서 writer를 drop하여 색인 스레드 종료와 잠금 해제를 기다림
+    drop(api);
+
+    Ok(())
+}
+
+// 현재 열려 있는 인덱스 이름 목록 (TantivyIndex 핸들로 연 인덱스는 제외)
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_indexes() -> Vec<String> {
+    let mut names: Vec<String> = INDEXES
+        .read()
+        .unwrap()
+        .keys()
+        .filter(|name| !name.starts_with(HANDLE_NAME_PREFIX))
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
+// 핸들 이름 발급용 카운터
+static NEXT_HANDLE_ID: AtomicU64 = AtomicU64::new(1);
+
+// 핸들이 레지스트리에 등록되는 이름의 접두어 (open_index에서는 사용할 수 없음)
+const HANDLE_NAME_PREFIX: &str = "#handle-";
+
+// [INIT] Dart 객체로 사용하는 인덱스 핸들
+// 열 때마다 고유한 이름으로 등록되므로 여러 isolate나 Flutter 엔진(홈 화면 위젯, 백그라운드 작업 등)이
+// 같은 이름을 두고 서로의 인덱스를 닫거나 바꾸지 않고 각자 인덱스를 열고 닫을 수 있습니다.
+// 핸들이 가비지 컬렉션되거나 close를 호출하면 등록이 해제됩니다.
+// 메서드에 없는 기능은 name을 각 함수의 index_name으로 전달하여 사용합니다.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct TantivyIndex {
+    name: String,
+}
+
+impl TantivyIndex {
+    // 인덱스를 열거나 생성하여 핸들을 반환 (open_index와 같은 인자)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn open(
+        dir_path: String,
+        language: Option<TextLanguage>,
+        writer_options: Option<WriterOptions>,
+    ) -> Result<TantivyIndex, TantivyError> {
+        Self::register(
+            dir_path,
+            language.map(|language| default_schema(Some(language))),
+            writer_options,
+        )
+    }
+
+    // 사용자 정의 스키마로 인덱스를 열거나 생성하여 핸들을 반환 (open_index_with_schema와 같은 인자)
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn open_with_schema(
+        dir_path: String,
+        fields: Vec<FieldDef>,
+        writer_options: Option<WriterOptions>,
+    ) -> Result<TantivyIndex, TantivyError> {
+        let schema = build_schema(&fields)?;
+        Self::register(dir_path, Some(schema), writer_options)
+    }
+
+    fn register(
+        dir_path: String,
+        requested_schema: Option<Schema>,
+        writer_options: Option<WriterOptions>,
+    ) -> Result<TantivyIndex, TantivyError> {
+        let name = format!(
+            "{}{}",
+            HANDLE_NAME_PREFIX,
+            NEXT_HANDLE_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let index_dir = canonical_dir(dir_path)?;
+        register_shared(
+            name.clone(),
+            &index_dir,
+            requested_schema.as_ref(),
+            writer_options.as_ref(),
+            |_| Ok(true),
+            || {
+                open_api(
+                    index_dir.clone(),
+                    requested_schema.clone(),
+                    default_schema(None),
+                    writer_options.clone(),
+                )
+            },
+        )?;
+
+        Ok(TantivyIndex { name })
+    }
+
+    // 다른 함수의 index_name으로 전달할 이름
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    // 핸들의 등록을 해제 (이후 메서드는 NotInitialized 오류를 반환)
+    // 같은 디렉토리를 사용하는 다른 핸들이나 이름이 없으면 writer와 잠금이 해제됩니다.
+    pub fn close(&self) -> Result<(), TantivyError> {
+        close_index(Some(self.name.clone()))
+    }
+
+    pub fn add_document(&self, doc: Document) -> Result<(), TantivyError> {
+        add_document(doc, Some(self.name.clone()))
+    }
+
+    pub fn add_documents_batch(&self, docs: Vec<Document>) -> Result<(), TantivyError> {
+        add_documents_batch(docs, Some(self.name.clone()))
+    }
+
+    pub fn add_document_chunked(
+        &self,
+        doc: Document,
+        max_chars: Option<usize>,
+    ) -> Result<usize, TantivyError> {
+        add_document_chunked(doc, max_chars, Some(self.name.clone()))
+    }
+
+    pub fn add_markdown_document(
+        &self,
+        doc: Document,
+        headings_field: Option<String>,
+    ) -> Result<Vec<MarkdownHeading>, TantivyError> {
+        add_markdown_document(doc, headings_field, Some(self.name.clone()))
+    }
+
+    pub fn upsert_documents_batch(
+        &self,
+        docs: Vec<Document>,
+    ) -> Result<Vec<UpsertResult>, TantivyError> {
+        upsert_documents_batch(docs, Some(self.name.clone()))
+    }
+
+    pub fn update_document(&self, doc: Document) -> Result<(), TantivyError> {
+        update_document(doc, Some(self.name.clone()))
+    }
+
+    pub fn update_document_if_version(
+        &self,
+        doc: Document,
+        expected_version: u64,
+    ) -> Result<u64, TantivyError> {
+        update_document_if_version(doc, expected_version, Some(self.name.clone()))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_document_version(&self, id: String) -> Result<u64, TantivyError> {
+        get_document_version(id, Some(self.name.clone()))
+    }
+
+    pub fn delete_document(&self, id: String) -> Result<(), TantivyError> {
+        delete_document(id, Some(self.name.clone()))
+    }
+
+    pub fn delete_documents_batch(&self, ids: Vec<String>) -> Result<(), TantivyError> {
+        delete_documents_batch(ids, Some(self.name.clone()))
+    }
+
+    pub fn delete_chunked_document(&self, parent_id: String) -> Result<(), TantivyError> {
+        delete_chunked_document(parent_id, Some(self.name.clone()))
+    }
+
+    pub fn delete_by_query(&self, query: String) -> Result<usize, TantivyError> {
+        delete_by_query(query, Some(self.name.clone()))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_document_by_id(&self, id: String) -> Result<Option<Document>, TantivyError> {
+        get_document_by_id(id, Some(self.name.clone()))
+    }
+
+    pub fn search_documents(
+        &self,
+        query: String,
+        top_k: usize,
+        fields: Option<Vec<FieldBoost>>,
+        filter: Option<SearchQuery>,
+    ) -> Result<Vec<SearchResult>, TantivyError> {
+        search_documents(query, top_k, fields, filter, Some(self.name.clone()))
+    }
+
+    pub fn count_documents(&self, query: String) -> Result<usize, TantivyError> {
+        count_documents(query, Some(self.name.clone()))
+    }
+
+    pub fn search_grouped(
+        &self,
+        query: String,
+        group_field: String,
+        top_groups: usize,
+        hits_per_group: usize,
+    ) -> Result<GroupedSearchResponse, TantivyError> {
+        search_grouped(
+            query,
+            group_field,
+            top_groups,
+            hits_per_group,
+            Some(self.name.clone()),
+        )
+    }
+
+    pub fn knn_search(
+        &self,
+        field: String,
+        vector: Vec<f32>,
+        top_k: usize,
+        metric: Option<VectorMetric>,
+        filter: Option<SearchQuery>,
+    ) -> Result<Vec<SearchResult>, TantivyError> {
+        knn_search(
+            field,
+            vector,
+            top_k,
+            metric,
+            filter,
+            Some(self.name.clone()),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn hybrid_search(
+        &self,
+        query: String,
+        vector_field: String,
+        vector: Vec<f32>,
+        alpha: f32,
+        top_k: usize,
+        fusion: Option<FusionMethod>,
+        filter: Option<SearchQuery>,
+    ) -> Result<Vec<SearchResult>, TantivyError> {
+        hybrid_search(
+            query,
+            vector_field,
+            vector,
+            alpha,
+            top_k,
+            fusion,
+            filter,
+            Some(self.name.clone()),
+        )
+    }
+
+    pub fn geo_search(
+        &self,
+        query: String,
+        field: String,
+        filter: GeoFilter,
+        sort_by_distance: bool,
+        top_k: usize,
+    ) -> Result<Vec<GeoSearchResult>, TantivyError> {
+        geo_search(
+            query,
+            field,
+            filter,
+            sort_by_distance,
+            top_k,
+            Some(self.name.clone()),
+        )
+    }
+
+    pub fn search_chunked(
+        &self,
+        query: String,
+        top_k: usize,
+    ) -> Result<Vec<ChunkSearchResult>, TantivyError> {
+        search_chunked(query, top_k, Some(self.name.clone()))
+    }
+
+    pub fn aggregate(&self, query: String, aggregations: String) -> Result<String, TantivyError> {
+        aggregate(query, aggregations, Some(self.name.clone()))
+    }
+
+    pub fn date_histogram(
+        &self,
+        query: String,
+        date_field: String,
+        interval: DateInterval,
+        utc_offset_minutes: Option<i32>,
+    ) -> Result<Vec<DateBucket>, TantivyError> {
+        date_histogram(
+            query,
+            date_field,
+            interval,
+            utc_offset_minutes,
+            Some(self.name.clone()),
+        )
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn commit(&self) -> Result<(), TantivyError> {
+        commit(Some(self.name.clone()))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn rollback(&self) -> Result<(), TantivyError> {
+        rollback(Some(self.name.clone()))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn refresh_reader(&self) -> Result<(), TantivyError> {
+        refresh_reader(Some(self.name.clone()))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_schema(&self) -> Result<Vec<FieldDef>, TantivyError> {
+        get_schema(Some(self.name.clone()))
+    }
+
+    pub fn get_index_stats(&self) -> Result<IndexStats, TantivyError> {
+        get_index_stats(Some(self.name.clone()))
+    }
+}
+
+impl Drop for TantivyIndex {
+    fn drop(&mut self) {
+        // 레지스트리 잠금을 푼 뒤에 writer를 drop하여 다른 호출을 막지 않음
+        let api = INDEXES.write().unwrap().remove(&self.name);
+        drop(api);
+    }
+}
+
+// 같은 디렉토리가 다른 이름이나 핸들로 이미 열려 있으면 그 인덱스를 함께 사용
+// (한 디렉토리에는 writer를 하나만 열 수 있으므로, 마지막 참조가 해제될 때 잠금이 풀림)
+// 암호화 키는 항상 같아야 하며, writer_options에서 지정한 다른 항목도 열린 인덱스의 설정과 같아야 합니다.
+fn shared_api(
+    indexes: &HashMap<String, Arc<TantivyApi>>,
+    index_dir: &Path,
+    requested_schema: Option<&Schema>,
+    writer_options: Option<&WriterOptions>,
+) -> Result<Option<Arc<TantivyApi>>> {
+    let Some(api) = indexes
+        .values()
+        .find(|api| api.index_dir.as_deref() == Some(index_dir))
+    else {
+        return Ok(None);
+    };
+    if requested_schema.is_some_and(|requested| !same_fields(requested, &api.schema)) {
+        return Err(TantivyError::new(
+            TantivyErrorKind::SchemaMismatch,
+            "Existing index schema does not match the requested schema",
+        )
+        .into());
+    }
+    // 키 없이 또는 다른 키로 열어 복호화된 문서를 읽을 수 없도록 함
+    if encryption_key(writer_options) != encryption_key(api.writer_options.as_ref()) {
+        return Err(TantivyError::new(
+            TantivyErrorKind::EncryptionKey,
+            format!(
+                "Tantivy index at {} is already open with a different encryption key",
+                index_dir.display()
+            ),
+        )
+        .into());
+    }
+    if let Some(option) =
+        writer_options.and_then(|options| options.conflicting_option(api.writer_options.as_ref()))
+    {
+        return Err(TantivyError::new(
+            TantivyErrorKind::AlreadyOpen,
+            format!(
+                "Tantivy index at {} is already open with a different {}; pass the same value or omit it",
+                index_dir.display(),
+                option
+            ),
+        )
+        .into());
+    }
+    Ok(Some(api.clone()))
+}
+
+// 디렉토리의 인덱스를 name으로 등록 (같은 디렉토리가 이미 열려 있으면 그 인덱스를 함께 사용)
+// 디렉토리 입출력과 writer 잠금 대기가 다른 인덱스의 조회와 검색을 막지 않도록 레지스트리 잠금 없이 열고,
+// 등록할 때만 잠금을 잡아 check로 이름을 다시 확인합니다. check가 false이면 이미 등록된 것이므로 등록하지 않습니다.
+// 여는 동안 다른 호출자가 같은 디렉토리를 먼저 열었으면 그 인덱스를 사용하고 새로 연 인덱스는 버립니다.
+fn register_shared(
+    name: String,
+    index_dir: &Path,
+    requested_schema: Option<&Schema>,
+    writer_options: Option<&WriterOptions>,
+    check: impl Fn(&HashMap<String, Arc<TantivyApi>>) -> Result<bool, TantivyError>,
+    open: impl FnOnce() -> Result<TantivyApi>,
+) -> Result<(), TantivyError> {
+    let found = {
+        let indexes = INDEXES.read().unwrap();
+        if !check(&indexes)? {
+            return Ok(());
+        }
+        shared_api(&indexes, index_dir, requested_schema, writer_options)?
+    };
+    let api = match found {
+        Some(api) => api,
+        None => match open() {
+            Ok(api) => Arc::new(api),
+            // 다른 호출자가 writer 잠금을 먼저 얻어 열지 못한 경우
+            Err(e) => shared_api(
+                &INDEXES.read().unwrap(),
+                index_dir,
+                requested_schema,
+                writer_options,
+            )?
+            .ok_or(e)?,
+        },
+    };
+
+    let mut indexes = INDEXES.write().unwrap();
+    let registered = check(&indexes).and_then(|register| {
+        if !register {
+            return Ok(None);
+        }
+        let api = shared_api(&indexes, index_dir, requested_schema, writer_options)?
+            .unwrap_or_else(|| api.clone());
+        Ok(indexes.insert(name, api))
+    });
+    drop(indexes);
+
+    // 레지스트리 잠금을 푼 뒤에 사용하지 않는 writer를 drop하여 다른 호출을 막지 않음
+    drop(api);
+    registered.map(drop)
+}
+
+// 인덱스의 쿼리 파서 설정을 변경하는 함수 (None이면 기본 인덱스)
+// 필드를 지정하지 않은 검색어의 검색 대상 필드와 가중치, 단어 사이의 기본 연산자(AND/OR)를 정합니다.
+// 인덱스를 연 직후 한 번 설정하면 search_documents 등 쿼리 문자열을 사용하는 모든 검색에 적용되며,
+// 검색마다 다르게 하려면 search_documents_with_parser를 사용합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_query_parser(
+    config: QueryParserConfig,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let settings = api.query_settings_with(&config)?;
+    *api.query_settings.write().unwrap() = settings;
+
+    Ok(())
+}
+
+// 열린 인덱스를 다른 디렉토리로 전환하는 함수 (None이면 기본 인덱스)
+// 사용자 프로필 전환 등 실행 중에 인덱스 위치를 바꿀 때 사용합니다.
+// 새 경로에 인덱스가 없으면 현재 인덱스와 같은 스키마로 생성하며, writer 설정과 쿼리 파서 설정도 그대로 유지됩니다.
+// 새 경로에 이미 인덱스가 있으면 스키마가 현재 인덱스와 같아야 합니다 (다르면 SchemaMismatch 오류).
+// 새 인덱스를 연 뒤에 기존 인덱스를 닫으므로 실패하면 기존 인덱스가 그대로 유지됩니다.
+// 새 경로가 다른 이름이나 핸들로 이미 열려 있으면 그 인덱스를 함께 사용하며, 스키마와 writer 설정이 같아야 합니다.
+// 열려 있지 않은 이름이면 init_tantivy와 같이 기본 스키마로 엽니다.
+pub fn reopen_index(dir_path: String, index_name: Option<String>) -> Result<(), TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    let index_dir = canonical_dir(dir_path)?;
+    let current = INDEXES.read().unwrap().get(&name).cloned();
+    let (requested_schema, writer_options) = match &current {
+        Some(api) if api.index_dir.as_ref() == Some(&index_dir) => return Ok(()),
+        Some(api) => (Some(api.schema.clone()), api.writer_options.clone()),
+        None => (None, None),
+    };
+
+    // 등록할 때 다시 확인하여 그 사이에 같은 경로로 전환된 경우에는 그대로 둠
+    let check = |indexes: &HashMap<String, Arc<TantivyApi>>| {
+        Ok(indexes
+            .get(&name)
+            .is_none_or(|api| api.index_dir.as_ref() != Some(&index_dir)))
+    };
+    // 기존 인덱스는 등록이 교체된 뒤 마지막 참조라면 drop되어 잠금이 해제됨
+    register_shared(
+        name.clone(),
+        &index_dir,
+        requested_schema.as_ref(),
+        writer_options.as_ref(),
+        check,
+        || {
+            let new_schema = requested_schema
+                .clone()
+                .unwrap_or_else(|| default_schema(None));
+            let api = open_api(
+                index_dir.clone(),
+                requested_schema.clone(),
+                new_schema,
+                writer_options.clone(),
+            )?;
+            // 필드 번호는 인덱스마다 다를 수 있으므로 필드 이름으로 쿼리 파서 설정을 옮김
+            if let Some(current) = &current {
+                let settings = api.query_settings_with(&current.query_parser_config())?;
+                *api.query_settings.write().unwrap() = settings;
+            }
+            Ok(api)
+        },
+    )
+}
+
+// 두 스키마의 필드 정의가 같은지 비교 (필드 순서는 무시)
+// get_schema로 얻은 목록을 다시 전달해도 id, _metadata 필드의 순서 때문에 불일치로 판단하지 않도록 합니다.
+// 버전, 원본 ID 필드가 추가되기 전에 만든 인덱스도 같은 필드 정의로 열 수 있도록 두 필드는 비교하지 않습니다.
+fn same_fields(requested: &Schema, existing: &Schema) -> bool {
+    let compared =
+        |entry: &FieldEntry| entry.name() != VERSION_FIELD && entry.name() != PARENT_FIELD;
+    let user_fields =
+        |schema: &Schema| schema.fields().filter(|(_, entry)| compared(entry)).count();
+    user_fields(requested) == user_fields(existing)
+        && requested
+            .fields()
+            .filter(|(_, entry)| compared(entry))
+            .all(|(_, entry)| {
+                existing
+                    .get_field(entry.name())
+                    .is_ok_and(|field| existing.get_field_entry(field) == entry)
+            })
+}
+
+// Dart에서 받은 경로를 파일 시스템 경로로 변환
+// file:// URI는 경로로 바꾸고, Android 저장소 접근 프레임워크(SAF)의 content:// URI는 파일 경로가 아니므로 거부합니다.
+fn local_path(path: &str) -> Result<PathBuf> {
+    if path.starts_with("content://") {
+        return Err(anyhow!(
+            "content:// URIs cannot be used as index paths; use a file system directory such as an app-specific directory from getExternalStorageDirectories()"
+        ));
+    }
+    let Some(uri_path) = path.strip_prefix("file://") else {
+        return Ok(PathBuf::from(path));
+    };
+    let uri_path = uri_path.strip_prefix("localhost").unwrap_or(uri_path);
+    if !uri_path.starts_with('/') {
+        return Err(anyhow!("Unsupported file URI: {}", path));
+    }
+    // %XX로 인코딩된 바이트를 복원
+    let bytes = uri_path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| uri_path.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    let decoded =
+        String::from_utf8(decoded).map_err(|_| anyhow!("Unsupported file URI: {}", path))?;
+    Ok(PathBuf::from(decoded))
+}
+
+// 디렉토리를 생성하고 정규화된 경로를 반환
+fn canonical_dir(dir_path: String) -> Result<PathBuf> {
+    let index_dir = local_path(&dir_path)?;
+    std::fs::create_dir_all(&index_dir)?;
+    Ok(index_dir.canonicalize()?)
+}
+
+// 인덱스를 열거나 생성하여 레지스트리에 등록
+fn register_index(
+    name: String,
+    dir_path: String,
+    requested_schema: Option<Schema>,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    if name.starts_with(HANDLE_NAME_PREFIX) {
+        return Err(anyhow!(
+            "Index names starting with '{}' are reserved for TantivyIndex handles",
+            HANDLE_NAME_PREFIX
+        )
+        .into());
+    }
+    let index_dir = canonical_dir(dir_path)?;
+
+    let check = |indexes: &HashMap<String, Arc<TantivyApi>>| match indexes.get(&name) {
+        // 같은 경로로 이미 초기화된 경우
+        Some(api) if api.index_dir.as_ref() == Some(&index_dir) => Ok(false),
+        // 다른 경로를 조용히 무시하지 않도록 먼저 닫거나 reopen_index를 사용하도록 안내
+        Some(api) => Err(already_open(&name, api)),
+        None => Ok(true),
+    };
+    register_shared(
+        name.clone(),
+        &index_dir,
+        requested_schema.as_ref(),
+        writer_options.as_ref(),
+        check,
+        || {
+            open_api(
+                index_dir.clone(),
+                requested_schema.clone(),
+                default_schema(None),
+                writer_options.clone(),
+            )
+        },
+    )
+}
+
+// 이름이 이미 다른 위치에 열려 있는 경우의 오류
+fn already_open(name: &str, api: &TantivyApi) -> TantivyError {
+    let location = match &api.index_dir {
+        Some(index_dir) => format!("at {}", index_dir.display()),
+        None => "in memory".to_string(),
+    };
+    TantivyError::new(
+        TantivyErrorKind::AlreadyOpen,
+        format!(
+            "Tantivy index '{}' is already open {}; close it or use reopen_index to switch paths",
+            name, location
+        ),
+    )
+}
+
+// 메모리 인덱스를 생성하여 레지스트리에 등록
+// 같은 이름의 메모리 인덱스가 이미 열려 있으면 그대로 사용합니다.
+fn register_memory_index(
+    name: String,
+    schema: Schema,
+    writer_options: Option<WriterOptions>,
+) -> Result<(), TantivyError> {
+    if name.starts_with(HANDLE_NAME_PREFIX) {
+        return Err(anyhow!(
+            "Index names starting with '{}' are reserved for TantivyIndex handles",
+            HANDLE_NAME_PREFIX
+        )
+        .into());
+    }
+    let mut indexes = INDEXES.write().unwrap();
+
+    if let Some(api) = indexes.get(&name) {
+        if api.index_dir.is_none() {
+            return Ok(());
+        }
+        return Err(already_open(&name, api));
+    }
+
+    let tokenizers = tokenizer_manager();
+    check_tokenizers(&schema, &tokenizers)?;
+    let settings = index_settings(writer_options.as_ref(), IndexSettings::default())?;
+    let (mut index, memory_directory) = memory_index(schema, settings)?;
+    index.set_tokenizers(tokenizers);
+    let api = api_from_index(index, None, Box::new(memory_directory), writer_options)?;
+    indexes.insert(name, Arc::new(api));
+
+    Ok(())
+}
+
+// 디렉토리의 인덱스를 열거나, 없으면 new_schema로 생성
+// requested_schema가 있으면 기존 인덱스의 스키마와 일치해야 하며, 새 인덱스 생성에도 우선 사용됩니다.
+fn open_api(
+    index_dir: PathBuf,
+    requested_schema: Option<Schema>,
+    new_schema: Schema,
+    writer_options: Option<WriterOptions>,
+) -> Result<TantivyApi> {
+    let tokenizers = tokenizer_manager();
+    let directory = open_directory(
+        &index_dir,
+        writer_options.as_ref(),
+        encryption_key(writer_options.as_ref()),
+    )?;
+    let (mut index, _) = if index_dir.join("meta.json").exists() {
+        // 기존 인덱스 열기
+        let index = Index::open(directory)?;
+        let schema = index.schema();
+        if requested_schema.is_some_and(|requested| !same_fields(&requested, &schema)) {
+            return Err(TantivyError::new(
+                TantivyErrorKind::SchemaMismatch,
+                "Existing index schema does not match the requested schema",
+            )
+            .into());
+        }
+        check_tokenizers(&schema, &tokenizers)?;
+        (index, schema)
+    } else {
+        // 새 인덱스 생성
+        let schema = requested_schema.unwrap_or(new_schema);
+        check_tokenizers(&schema, &tokenizers)?;
+        let settings = index_settings(writer_options.as_ref(), IndexSettings::default())?;
+        let index = Index::create(directory, schema.clone(), settings)?;
+        (index, schema)
+    };
+    index.set_tokenizers(tokenizers);
+
+    // 백업할 때 읽은 파일이 메모리에 남지 않도록 Ram 저장 방식도 파일 읽기로 읽음
+    let raw_directory = storage_directory(
+        &index_dir,
+        match storage_backend(writer_options.as_ref()) {
+            StorageBackend::Ram => StorageBackend::FileIo,
+            backend => backend,
+        },
+        protected_data_wait(writer_options.as_ref()),
+    )?;
+    api_from_index(index, Some(index_dir), raw_directory, writer_options)
+}
+
+fn encryption_key(options: Option<&WriterOptions>) -> Option<&[u8]> {
+    options.and_then(|options| options.encryption_key.as_deref())
+}
+
+fn storage_backend(options: Option<&WriterOptions>) -> StorageBackend {
+    options
+        .and_then(|options| options.storage_backend)
+        .unwrap_or(StorageBackend::Mmap)
+}
+
+fn protected_data_wait(options: Option<&WriterOptions>) -> Option<Duration> {
+    options
+        .and_then(|options| options.protected_data_wait_ms)
+        .map(Duration::from_millis)
+}
+
+// 저장 방식에 맞게 인덱스 디렉토리의 파일을 그대로 읽고 쓰는 Directory
+// protected_data_wait가 있으면 파일 접근이 거부될 때 그 시간까지 다시 시도합니다.
+fn storage_directory(
+    index_dir: &Path,
+    backend: StorageBackend,
+    protected_data_wait: Option<Duration>,
+) -> Result<Box<dyn Directory>> {
+    let directory: Box<dyn Directory> = match backend {
+        StorageBackend::Mmap => {
+            Box::new(MmapDirectory::open(index_dir).map_err(tantivy::TantivyError::from)?)
+        }
+        StorageBackend::FileIo => {
+            Box::new(FileDirectory::open(index_dir, false).map_err(tantivy::TantivyError::from)?)
+        }
+        StorageBackend::Ram => {
+            Box::new(FileDirectory::open(index_dir, true).map_err(tantivy::TantivyError::from)?)
+        }
+    };
+    Ok(match protected_data_wait {
+        Some(wait) => Box::new(ProtectedDirectory::new(directory, wait)),
+        None => directory,
+    })
+}
+
+// 인덱스 디렉토리를 여는 Directory (encryption_key가 있으면 파일을 암호화)
+// 기존 인덱스의 암호화 여부와 키가 맞지 않으면 EncryptionKey 오류를 반환합니다.
+fn open_directory(
+    index_dir: &Path,
+    options: Option<&WriterOptions>,
+    encryption_key: Option<&[u8]>,
+) -> Result<Box<dyn Directory>> {
+    let storage_directory = storage_directory(
+        index_dir,
+        storage_backend(options),
+        protected_data_wait(options),
+    )?;
+    let meta_path = Path::new("meta.json");
+    let encrypted = match storage_directory.atomic_read(meta_path) {
+        Ok(meta) => Some(encrypted_directory::is_encrypted(&meta)),
+        Err(_) => None,
+    };
+    let key_error = |message: String| TantivyError::new(TantivyErrorKind::EncryptionKey, message);
+    let Some(key) = encryption_key else {
+        if encrypted == Some(true) {
+            return Err(key_error(format!(
+                "Index at {} is encrypted; pass the encryption key to open it",
+                index_dir.display()
+            ))
+            .into());
+        }
+        return Ok(storage_directory);
+    };
+    if encrypted == Some(false) {
+        return Err(key_error(format!(
+            "Index at {} is not encrypted; open it without an encryption key",
+            index_dir.display()
+        ))
+        .into());
+    }
+    let directory = EncryptedDirectory::new(storage_directory, key).map_err(|e| anyhow!(e))?;
+    if encrypted == Some(true) && directory.atomic_read(meta_path).is_err() {
+        return Err(key_error(format!(
+            "Encryption key does not match the index at {}",
+            index_dir.display()
+        ))
+        .into());
+    }
+    Ok(Box::new(directory))
+}
+
+// 메모리 인덱스 생성 (파일 저장소를 함께 반환)
+fn memory_index(schema: Schema, settings: IndexSettings) -> Result<(Index, RamDirectory)> {
+    let directory = RamDirectory::create();
+    let index = Index::create(directory.clone(), schema, settings)?;
+    Ok((index, directory))
+}
+
+// 토크나이저가 등록된 인덱스로 writer와 reader를 만들어 TantivyApi 생성
+fn api_from_index(
+    index: Index,
+    index_dir: Option<PathBuf>,
+    raw_directory: Box<dyn Directory>,
+    writer_options: Option<WriterOptions>,
+) -> Result<TantivyApi> {
+    let schema = index.schema();
+    let id_field = schema.get_field("id").map_err(|_| anyhow!("'id' field not found"))?;
+    let text_field = schema.get_field("text").ok();
+    let metadata_field = schema.get_field(METADATA_FIELD).ok();
+    let version_field = schema.get_field(VERSION_FIELD).ok();
+    let parent_field = schema.get_field(PARENT_FIELD).ok();
+    let shadow_fields = shadow_fields(&schema);
+    let language_fields = language_fields(&schema);
+
+    // 색인된 Text 필드를 쿼리 파서의 기본 검색 대상으로 사용
+    let default_fields: Vec<Field> = schema
+        .fields()
+        .filter(|(_, entry)| match entry.field_type() {
+            tantivy::schema::FieldType::Str(options) => {
+                options.get_indexing_options().is_some_and(|indexing| {
+                    indexing.tokenizer() != "raw" && indexing.tokenizer() != GEO_TOKENIZER
+                })
+            }
+            _ => false,
+        })
+        .map(|(field, _)| field)
+        .collect();
+
+    let evict_field = match writer_options
+        .as_ref()
+        .and_then(|options| options.evict_oldest_by.as_ref())
+    {
+        Some(name) => {
+            let field = schema
+                .get_field(name)
+                .map_err(|_| anyhow!("evict_oldest_by field '{}' not found", name))?;
+            let field_type = schema.get_field_entry(field).field_type();
+            if !field_type.is_fast() || field_type.value_type() != Type::Date {
+                return Err(anyhow!("'{}' field must be a Date fast field", name));
+            }
+            Some(field)
+        }
+        None => None,
+    };
+    if writer_options
+        .as_ref()
+        .is_some_and(|options| options.max_index_size_bytes == Some(0))
+    {
+        return Err(anyhow!("max_index_size_bytes must be greater than 0"));
+    }
+
+    let writer = open_writer(&index, writer_options.as_ref())?;
+
+    // OnCommitWithDelay만 tantivy가 리로드하고, 나머지는 searcher()와 refresh_reader에서 리로드
+    let reload_policy = writer_options
+        .as_ref()
+        .and_then(|options| options.reload_policy)
+        .unwrap_or(ReaderReloadPolicy::OnSearch);
+    let reader = index
+        .reader_builder()
+        .reload_policy(match reload_policy {
+            ReaderReloadPolicy::OnCommitWithDelay => ReloadPolicy::OnCommitWithDelay,
+            ReaderReloadPolicy::OnSearch
+            | ReaderReloadPolicy::Manual
+            | ReaderReloadPolicy::NearRealTime => ReloadPolicy::Manual,
+        })
+        .try_into()?;
+
+    // 이미 저장된 가장 큰 버전 다음부터 부여 (삭제된 문서 포함)
+    let mut next_version = 1;
+    if version_field.is_some() {
+        for segment_reader in reader.searcher().segment_readers() {
+            if let Ok(column) = segment_reader.fast_fields().u64(VERSION_FIELD) {
+                next_version = next_version.max(column.max_value() + 1);
+            }
+        }
+    }
+
+    Ok(TantivyApi {
+        index,
+        index_dir,
+        raw_directory,
+        writer: Mutex::new(writer),
+        batch: Mutex::new(None),
+        writer_options,
+        reader,
+        reload_policy,
+        schema,
+        id_field,
+        text_field,
+        metadata_field,
+        version_field,
+        next_version: AtomicU64::new(next_version),
+        parent_field,
+        shadow_fields,
+        language_fields,
+        query_settings: RwLock::new(QuerySettings {
+            fields: default_fields.clone(),
+            boosts: Vec::new(),
+            conjunction_by_default: false,
+        }),
+        default_fields,
+        handle_searchers: Mutex::new(VecDeque::new()),
+        pending: Mutex::new(PendingChanges::new()),
+        evict_field,
+    })
+}
+
+// [CREATE] 새 문서를 추가하는 함수
+// 즉시 commit하므로 단일 문서 추가에 적합
+// 대량 추가는 add_documents_batch 사용 권장
+pub fn add_document(doc: Document, index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+
+    // 추가하기 전에 동일한 ID의 문서가 있다면 삭제 (Update-or-Insert)
+    let tantivy_doc = api.to_tantivy_doc(&doc)?;
+
+    let id_term = Term::from_field_text(api.id_field, &doc.id);
+    writer.delete_term(id_term.clone());
+
+    writer.add_document(tantivy_doc)?;
+    writer.commit()?;
+
+    Ok(())
+}
+
+// [READ] 쿼리로 문서를 검색하는 함수
+// fields를 지정하면 해당 필드들만 검색하며, 필드별 가중치(boost)로 순위를 조정합니다.
+// filter를 지정하면 filter와도 일치하는 문서만 반환하며, filter는 점수에 영향을 주지 않습니다. (카테고리 내 검색 등)
+pub fn search_documents(
+    query: String,
+    top_k: usize,
+    fields: Option<Vec<FieldBoost>>,
+    filter: Option<SearchQuery>,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut query = match fields {
+        Some(fields) => api.parse_query_in_fields(&query, &fields)?,
+        None => api.parse_query(&query)?,
+    };
+    if let Some(filter) = filter {
+        query = api.with_filter(query, &filter)?;
+    }
+
+    Ok(api.search(&query, top_k)?)
+}
+
+// [READ] 이 검색에만 쿼리 파서 설정을 바꾸어 검색하는 함수
+// config에 지정하지 않은 항목은 configure_query_parser로 설정한 인덱스의 값을 사용합니다.
+pub fn search_documents_with_parser(
+    query: String,
+    top_k: usize,
+    config: QueryParserConfig,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query_with(&query, &config)?;
+
+    Ok(api.search(&*query, top_k)?)
+}
+
+// [READ] 지정한 저장 필드만 반환하는 검색 함수
+// 결과 목록처럼 긴 본문이 필요 없는 화면에서 사용하며, 지정하지 않은 필드는 Flutter로 전달되지 않습니다.
+// id는 항상 포함되고, 'text'를 지정하지 않으면 Document.text는 빈 문자열입니다.
+// 메타데이터는 '_metadata'를 지정한 경우에만 포함됩니다.
+pub fn search_documents_with_return_fields(
+    query: String,
+    top_k: usize,
+    return_fields: Vec<String>,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let return_fields = api.stored_fields(&return_fields)?;
+    let query = api.parse_query(&query)?;
+    // TopDocs는 limit이 0이면 panic하므로 빈 결과를 바로 반환
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let searcher = api.searcher()?;
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k))?;
+
+    Ok(api.to_search_results_with_fields(&searcher, top_docs, None, Some(&return_fields))?)
+}
+
+// 저장된 내용 없이 문서 위치만 가리키는 검색 결과
+// fetch_documents로 화면에 보이는 행의 문서만 나중에 가져올 때 사용합니다.
+// generation은 검색 시점으로, 같은 시점의 searcher가 유지되는 동안만 유효합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitHandle {
+    pub score: f32,
+    pub segment_ord: u32,
+    pub doc_id: u32,
+    pub generation: u64,
+}
+
+// [READ] 문서 대신 가벼운 핸들을 반환하는 검색 함수
+// 긴 결과 목록에서 저장된 내용은 fetch_documents로 보이는 행만 가져옵니다.
+// 최근 4번의 검색 시점까지 핸들이 유효하며, 그보다 오래된 핸들은 Expired 오류가 됩니다.
+pub fn search_hit_handles(
+    query: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<HitHandle>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+    // TopDocs는 limit이 0이면 panic하므로 빈 결과를 바로 반환
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let searcher = api.searcher()?;
+    let generation = searcher.generation().generation_id();
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k))?;
+
+    let mut handle_searchers = api.handle_searchers.lock().unwrap();
+    if !handle_searchers
+        .iter()
+        .any(|s| s.generation().generation_id() == generation)
+    {
+        if handle_searchers.len() == HANDLE_SEARCHER_LIMIT {
+            handle_searchers.pop_front();
+        }
+        handle_searchers.push_back(searcher);
+    }
+
+    Ok(top_docs
+        .into_iter()
+        .map(|(score, doc_address)| HitHandle {
+            score,
+            segment_ord: doc_address.segment_ord,
+            doc_id: doc_address.doc_id,
+            generation,
+        })
+        .collect())
+}
+
+// [READ] search_hit_handles의 핸들로 문서를 가져오는 함수
+// 핸들과 같은 순서로 반환하며, return_fields를 지정하면 id와 해당 저장 필드만 포함합니다.
+// 핸들의 검색 시점이 더 이상 유지되지 않으면 Expired 오류를 반환하므로 다시 검색해야 합니다.
+pub fn fetch_documents(
+    handles: Vec<HitHandle>,
+    return_fields: Option<Vec<String>>,
+    index_name: Option<String>,
+) -> Result<Vec<Document>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let return_fields = match return_fields {
+        Some(names) => Some(api.stored_fields(&names)?),
+        None => None,
+    };
+
+    let handle_searchers = api.handle_searchers.lock().unwrap();
+    let mut documents = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let searcher = handle_searchers
+            .iter()
+            .find(|s| s.generation().generation_id() == handle.generation)
+            .ok_or_else(|| {
+                TantivyError::new(
+                    TantivyErrorKind::Expired,
+                    format!(
+                        "Search results of generation {} have expired; search again",
+                        handle.generation
+                    ),
+                )
+            })?;
+        let is_valid = searcher
+            .segment_readers()
+            .get(handle.segment_ord as usize)
+            .is_some_and(|segment_reader| handle.doc_id < segment_reader.max_doc());
+        if !is_valid {
+            return Err(anyhow!("Invalid hit handle {:?}", handle).into());
+        }
+        let retrieved_doc =
+            searcher.doc::<TantivyDocument>(DocAddress::new(handle.segment_ord, handle.doc_id))?;
+        documents.push(api.to_document_with_fields(&retrieved_doc, return_fields.as_deref()));
+    }
+
+    Ok(documents)
+}
+
+// [READ] 많은 결과를 여러 묶음으로 나누어 Stream으로 보내는 검색 함수
+// 첫 묶음은 상위 chunk_size개만 먼저 찾아 바로 보내므로, 나머지를 모으는 동안 첫 화면을 그릴 수 있습니다.
+// 이후 묶음은 관련도 순서를 이어서 chunk_size개씩 보내며, 최대 max_results개까지 보냅니다.
+// Dart 쪽에서 구독을 취소하면 남은 결과는 보내지 않고 종료합니다.
+// cancel_operation으로 취소하면 묶음 사이에서 멈추고 Stream에 Cancelled 오류가 전달됩니다.
+pub fn search_documents_stream(
+    query: String,
+    max_results: usize,
+    chunk_size: Option<usize>,
+    sink: StreamSink<Vec<SearchResult>>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    let operation = Operation::start(operation_id);
+
+    let chunk_size = chunk_size.unwrap_or(DEFAULT_STREAM_CHUNK_SIZE);
+    if chunk_size == 0 {
+        return Err(anyhow!("chunk_size must be greater than 0").into());
+    }
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+
+    // 상위 결과를 먼저 보내고, 나머지는 같은 searcher로 이어서 수집하여 순서가 겹치지 않음
+    let first_limit = chunk_size.min(max_results);
+    if first_limit == 0 {
+        return Ok(());
+    }
+    operation.check()?;
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(first_limit))?;
+    let found = top_docs.len();
+    let results = api.to_search_results(&searcher, top_docs, None)?;
+    if sink.add(results).is_err() || found < first_limit || max_results == first_limit {
+        return Ok(());
+    }
+
+    operation.check()?;
+    let rest = searcher.search(
+        &query,
+        &TopDocs::with_limit(max_results - first_limit).and_offset(first_limit),
+    )?;
+    for chunk in rest.chunks(chunk_size) {
+        operation.check()?;
+        let results = api.to_search_results(&searcher, chunk.to_vec(), None)?;
+        if sink.add(results).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// [READ] 태그로 결과를 거르는 검색 함수
+// tag_field는 색인된 String 필드여야 하며, 태그는 토큰화 없이 정확히 일치해야 합니다.
+// required_tags의 태그를 모두 가진 문서 중 excluded_tags의 태그가 하나도 없는 문서만 반환합니다.
+// 태그 조건은 점수에 영향을 주지 않으며, query가 비어 있으면 태그 조건만으로 검색합니다.
+pub fn search_with_tags(
+    query: String,
+    tag_field: String,
+    required_tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&tag_field)?;
+    let is_tag_field = match api.schema.get_field_entry(field).field_type() {
+        tantivy::schema::FieldType::Str(options) => options
+            .get_indexing_options()
+            .is_some_and(|indexing| indexing.tokenizer() == "raw"),
+        _ => false,
+    };
+    if !is_tag_field {
+        return Err(anyhow!("'{}' field must be an indexed String field", tag_field).into());
+    }
+
+    let query: Box<dyn Query> = if query.trim().is_empty() {
+        Box::new(AllQuery)
+    } else {
+        api.parse_query(&query)?
+    };
+    let tag_query = |tag: &String| -> Box<dyn Query> {
+        Box::new(TermQuery::new(
+            Term::from_field_text(field, tag),
+            IndexRecordOption::Basic,
+        ))
+    };
+
+    let mut clauses = vec![(Occur::Must, query)];
+    for tag in &required_tags {
+        let filter: Box<dyn Query> = Box::new(ConstScoreQuery::new(tag_query(tag), 0.0));
+        clauses.push((Occur::Must, filter));
+    }
+    for tag in &excluded_tags {
+        clauses.push((Occur::MustNot, tag_query(tag)));
+    }
+
+    Ok(api.search(&BooleanQuery::new(clauses), top_k)?)
+}
+
+// [READ] 문법 오류를 허용하는 검색 함수
+// 사용자가 직접 입력한 검색어처럼 `:^()` 등이 섞여 있어도 오류 대신 경고와 함께 결과를 반환합니다.
+pub fn search_documents_lenient(
+    query: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<LenientSearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let (query, warnings) = api.parse_query_lenient(&query);
+
+    Ok(LenientSearchResponse {
+        results: api.search(&*query, top_k)?,
+        warnings,
+    })
+}
+
+// 쿼리 문법의 특수 문자를 이스케이프하여 입력한 그대로 검색되도록 하는 함수
+#[flutter_rust_bridge::frb(sync)]
+pub fn escape_query(text: String) -> String {
+    const SPECIAL_CHARS: &[char] = &[
+        '+', '-', '!', '^', '`', ':', '{', '}', '"', '\'', '[', ']', '(', ')', '*', '~', '\\',
+    ];
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if SPECIAL_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// [READ] 일치한 부분을 강조한 snippet과 함께 문서를 검색하는 함수
+// snippet_field를 생략하면 'text' 필드(없으면 첫 번째 기본 검색 필드)를 사용합니다.
+// Tantivy의 토크나이저로 강조하므로 형태소/어간 처리와 일치하는 결과를 얻을 수 있습니다.
+pub fn search_documents_with_snippets(
+    query: String,
+    top_k: usize,
+    snippet_field: Option<String>,
+    max_chars: Option<usize>,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = match snippet_field {
+        Some(name) => api.field(&name)?,
+        None => api
+            .text_field
+            .or_else(|| api.default_fields.first().copied())
+            .ok_or_else(|| anyhow!("No text field available for snippets"))?,
+    };
+
+    let query = api.parse_query(&query)?;
+    // TopDocs는 limit이 0이면 panic하므로 하이라이터를 만들기 전에 빈 결과를 반환
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let searcher = api.searcher()?;
+
+    let highlighter = Highlighter::create(&searcher, &*query, field, max_chars)?;
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k))?;
+
+    Ok(api.to_search_results(&searcher, top_docs, Some(&highlighter))?)
+}
+
+// [READ] Facet 집계와 함께 문서를 검색하는 함수
+// facet_root(기본값 "/") 바로 아래의 하위 카테고리별 일치 문서 수를 반환합니다.
+// 특정 카테고리로 좁히려면 쿼리에 `category:/books/fiction`와 같이 지정하고 facet_root를 함께 내려가면 됩니다.
+pub fn search_with_facets(
+    query: String,
+    facet_field: String,
+    facet_root: Option<String>,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<FacetSearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&facet_field)?;
+    if api.schema.get_field_entry(field).field_type().value_type() != Type::Facet {
+        return Err(anyhow!("'{}' field is not a Facet field", facet_field).into());
+    }
+    let root =
+        Facet::from_text(facet_root.as_deref().unwrap_or("/")).map_err(anyhow::Error::from)?;
+
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+
+    let mut facet_collector = FacetCollector::for_field(&facet_field);
+    facet_collector.add_facet(root.clone());
+
+    // TopDocs는 limit이 0이면 panic하므로 최소 1개로 수집한 뒤 잘라냄
+    let (facet_counts, mut top_docs) = searcher.search(
+        &query,
+        &(facet_collector, TopDocs::with_limit(top_k.max(1))),
+    )?;
+    top_docs.truncate(top_k);
+
+    let facet_counts = facet_counts
+        .get(root)
+        .map(|(facet, count)| FacetCount {
+            path: facet.to_path_string(),
+            count,
+        })
+        .collect();
+
+    Ok(FacetSearchResponse {
+        results: api.to_search_results(&searcher, top_docs, None)?,
+        facet_counts,
+    })
+}
+
+// [READ] 상위 결과와 전체 일치 문서 수를 함께 반환하는 검색 함수
+// 모든 결과를 가져오지 않고도 "1,254개 결과"와 같이 표시할 수 있습니다.
+pub fn search_documents_with_count(
+    query: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<SearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+
+    Ok(api.search_page(&query, 0, top_k)?)
+}
+
+// [READ] 쿼리와 일치하는 문서 수만 반환하는 함수
+// 저장된 문서를 읽지 않으므로 "읽지 않은 메일 12개"와 같은 배지 표시에 적합합니다.
+pub fn count_documents(query: String, index_name: Option<String>) -> Result<usize, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+
+    Ok(searcher.search(&query, &Count)?)
+}
+
+// 벡터 유사도 계산 방식 (점수가 클수록 가까움)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorMetric {
+    // 코사인 유사도 (-1 ~ 1)
+    Cosine,
+    // 내적 (정규화된 임베딩이면 Cosine과 순위가 같고 더 빠름)
+    DotProduct,
+    // 1 / (1 + 유클리드 거리)
+    Euclidean,
+}
+
+impl VectorMetric {
+    fn similarity(self, query: &[f32], vector: &[f32]) -> f32 {
+        let dot = || query.iter().zip(vector).map(|(a, b)| a * b).sum::<f32>();
+        match self {
+            VectorMetric::Cosine => {
+                let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+                let norms = norm(query) * norm(vector);
+                if norms == 0.0 {
+                    0.0
+                } else {
+                    dot() / norms
+                }
+            }
+            VectorMetric::DotProduct => dot(),
+            VectorMetric::Euclidean => {
+                let distance = query
+                    .iter()
+                    .zip(vector)
+                    .map(|(a, b)| (a - b) * (a - b))
+                    .sum::<f32>()
+                    .sqrt();
+                1.0 / (1.0 + distance)
+            }
+        }
+    }
+}
+
+// Vector 필드 값은 f32를 little-endian 바이트로 이어 붙여 bytes 필드에 저장
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+// IpAddr 필드는 IPv4 주소도 IPv4-mapped IPv6 주소(::ffff:192.168.0.1)로 저장
+fn parse_ip(text: &str) -> Result<Ipv6Addr> {
+    let ip: IpAddr = text
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("'{}' is not a valid IP address", text))?;
+    Ok(match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    })
+}
+
+// 저장된 주소를 원래 형식으로 표시 (IPv4-mapped 주소는 IPv4로)
+fn format_ip(ip: Ipv6Addr) -> String {
+    match ip.to_ipv4_mapped() {
+        Some(ip) => ip.to_string(),
+        None => ip.to_string(),
+    }
+}
+
+// [READ] 임베딩이 vector와 가장 가까운 top_k개의 문서를 찾는 함수 (의미 기반 검색)
+// 모든 문서의 벡터와 비교하는 brute-force 방식이며, 점수는 metric의 유사도입니다 (기본값 Cosine).
+// filter를 지정하면 filter와 일치하는 문서 중에서만 찾고, 벡터가 없는 문서는 결과에 포함되지 않습니다.
+pub fn knn_search(
+    field: String,
+    vector: Vec<f32>,
+    top_k: usize,
+    metric: Option<VectorMetric>,
+    filter: Option<SearchQuery>,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let collector = KnnCollector::new(&api, field, vector, metric, top_k)?;
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let query: Box<dyn Query> = match filter {
+        Some(filter) => api.build_query(&filter)?,
+        None => Box::new(AllQuery),
+    };
+    let searcher = api.searcher()?;
+    let hits = searcher.search(&query, &collector)?;
+
+    Ok(api.to_search_results(&searcher, hits.into_sorted_hits(), None)?)
+}
+
+// hybrid_search에서 두 순위 목록을 합치는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FusionMethod {
+    // 점수 대신 순위로 합침 (1 / (60 + 순위)), 점수 분포가 다른 두 검색을 합칠 때 안정적
+    ReciprocalRank,
+    // 각 목록의 점수를 0 ~ 1로 정규화한 뒤 가중 합산
+    WeightedScore,
+}
+
+// hybrid_search에서 각 검색이 후보로 가져오는 최소 문서 수
+const HYBRID_MIN_CANDIDATES: usize = 100;
+
+// Reciprocal Rank Fusion의 순위 보정 상수 (일반적으로 사용하는 값)
+const RRF_K: f32 = 60.0;
+
+// [READ] 키워드 검색(BM25)과 벡터 검색(knn_search)의 결과를 합쳐 반환하는 함수
+// alpha는 벡터 검색의 가중치(0 ~ 1)이며, 0이면 키워드 검색만, 1이면 벡터 검색만 사용한 순위와 같습니다.
+// 두 검색에서 각각 상위 max(top_k, 100)개를 후보로 가져온 뒤 fusion 방식으로 합치며 (기본값 ReciprocalRank), 점수는 합친 점수입니다.
+// filter는 두 검색 모두에 적용됩니다.
+#[allow(clippy::too_many_arguments)]
+pub fn hybrid_search(
+    query: String,
+    vector_field: String,
+    vector: Vec<f32>,
+    alpha: f32,
+    top_k: usize,
+    fusion: Option<FusionMethod>,
+    filter: Option<SearchQuery>,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(anyhow!("alpha must be between 0 and 1").into());
+    }
+    let candidates = top_k.max(HYBRID_MIN_CANDIDATES);
+    let collector = KnnCollector::new(&api, vector_field, vector, None, candidates)?;
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut keyword_query = api.parse_query(&query)?;
+    let mut vector_query: Box<dyn Query> = Box::new(AllQuery);
+    if let Some(filter) = filter {
+        keyword_query = api.with_filter(keyword_query, &filter)?;
+        vector_query = api.build_query(&filter)?;
+    }
+
+    // 같은 searcher로 검색해야 두 목록의 문서 주소가 일치
+    let searcher = api.searcher()?;
+    let keyword_hits = searcher.search(&keyword_query, &TopDocs::with_limit(candidates))?;
+    let vector_hits = searcher
+        .search(&vector_query, &collector)?
+        .into_sorted_hits();
+
+    let mut hits = fuse_rankings(
+        [(1.0 - alpha, keyword_hits), (alpha, vector_hits)],
+        fusion.unwrap_or(FusionMethod::ReciprocalRank),
+    );
+    hits.truncate(top_k);
+
+    Ok(api.to_search_results(&searcher, hits, None)?)
+}
+
+// (가중치, 점수 순으로 정렬된 목록)들을 합쳐 합친 점수 순으로 정렬
+fn fuse_rankings(
+    rankings: [(f32, Vec<(f32, DocAddress)>); 2],
+    fusion: FusionMethod,
+) -> Vec<(f32, DocAddress)> {
+    let mut fused: HashMap<DocAddress, f32> = HashMap::new();
+    for (weight, ranking) in rankings {
+        // 가중치가 0인 검색의 문서는 결과에 포함하지 않음
+        if weight == 0.0 {
+            continue;
+        }
+        let lowest = ranking.last().map_or(0.0, |(score, _)| *score);
+        let highest = ranking.first().map_or(0.0, |(score, _)| *score);
+        for (rank, (score, address)) in ranking.into_iter().enumerate() {
+            let contribution = match fusion {
+                FusionMethod::ReciprocalRank => 1.0 / (RRF_K + rank as f32 + 1.0),
+                // 모든 점수가 같으면 모두 1로 취급
+                FusionMethod::WeightedScore if highest > lowest => {
+                    (score - lowest) / (highest - lowest)
+                }
+                FusionMethod::WeightedScore => 1.0,
+            };
+            *fused.entry(address).or_insert(0.0) += weight * contribution;
+        }
+    }
+    let mut hits: Vec<(f32, DocAddress)> = fused
+        .into_iter()
+        .map(|(address, score)| (score, address))
+        .collect();
+    hits.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    hits
+}
+
+// Vector 필드와 query 벡터의 유사도가 높은 상위 top_k개의 문서를 찾는 Collector
+struct KnnCollector {
+    field: String,
+    vector: Vec<f32>,
+    metric: VectorMetric,
+    top_k: usize,
+}
+
+impl KnnCollector {
+    // field가 Vector 필드인지, vector가 올바른지 확인 (metric의 기본값은 Cosine)
+    fn new(
+        api: &TantivyApi,
+        field: String,
+        vector: Vec<f32>,
+        metric: Option<VectorMetric>,
+        top_k: usize,
+    ) -> Result<KnnCollector> {
+        let field_entry = api.schema.get_field_entry(api.field(&field)?);
+        if field_entry.field_type().value_type() != Type::Bytes || !field_entry.is_fast() {
+            return Err(anyhow!("'{}' field is not a Vector field", field));
+        }
+        if vector.is_empty() || !vector.iter().all(|v| v.is_finite()) {
+            return Err(anyhow!("Query vector must be non-empty and finite"));
+        }
+        Ok(KnnCollector {
+            field,
+            vector,
+            metric: metric.unwrap_or(VectorMetric::Cosine),
+            top_k,
+        })
+    }
+}
+
+impl Collector for KnnCollector {
+    type Fruit = GroupHits;
+    type Child = KnnSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_ord: tantivy::SegmentOrdinal,
+        segment_reader: &tantivy::SegmentReader,
+    ) -> tantivy::Result<KnnSegmentCollector> {
+        // 같은 벡터는 사전에 한 번만 저장되므로 서로 다른 벡터마다 한 번씩 유사도를 계산 (사전 순번 = scores의 위치)
+        let vectors = segment_reader.fast_fields().bytes(&self.field)?;
+        let mut scores = Vec::new();
+        if let Some(vectors) = &vectors {
+            let mut stream = vectors.dictionary().stream()?;
+            while stream.advance() {
+                let vector = decode_vector(stream.key());
+                if vector.len() != self.vector.len() {
+                    return Err(tantivy::TantivyError::InvalidArgument(format!(
+                        "Vector field {} has {} dimensions, but the query vector has {}",
+                        self.field,
+                        vector.len(),
+                        self.vector.len()
+                    )));
+                }
+                scores.push(self.metric.similarity(&self.vector, &vector));
+            }
+        }
+        Ok(KnnSegmentCollector {
+            vectors,
+            scores,
+            segment_ord,
+            top_k: self.top_k,
+            hits: GroupHits::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(&self, fruits: Vec<GroupHits>) -> tantivy::Result<GroupHits> {
+        let mut merged = GroupHits::new();
+        for hits in fruits {
+            merged.merge(hits, self.top_k);
+        }
+        Ok(merged)
+    }
+}
+
+struct KnnSegmentCollector {
+    // 세그먼트에 벡터가 하나도 없으면 None
+    vectors: Option<BytesColumn>,
+    scores: Vec<f32>,
+    segment_ord: tantivy::SegmentOrdinal,
+    top_k: usize,
+    hits: GroupHits,
+}
+
+impl SegmentCollector for KnnSegmentCollector {
+    type Fruit = GroupHits;
+
+    fn collect(&mut self, doc: DocId, _: tantivy::Score) {
+        let Some(ord) = self
+            .vectors
+            .as_ref()
+            .and_then(|vectors| vectors.term_ords(doc).next())
+        else {
+            return;
+        };
+        self.hits.push(
+            self.scores[ord as usize],
+            DocAddress::new(self.segment_ord, doc),
+            self.top_k,
+        );
+    }
+
+    fn harvest(self) -> GroupHits {
+        self.hits
+    }
+}
+
+// geo_search의 위치 조건
+// radius_meters를 지정하면 center에서 반경 안의 문서만, south_west와 north_east를 지정하면 사각형 영역 안의 문서만 찾습니다.
+// 둘 다 지정하면 두 조건을 모두 만족하는 문서를 찾고, center만 지정하면 위치로 거르지 않고 거리만 계산합니다.
+// 영역이 경도 180도를 넘으면 south_west.lon이 north_east.lon보다 큽니다.
+#[derive(Debug, Clone)]
+pub struct GeoFilter {
+    pub center: Option<GeoPoint>,
+    pub radius_meters: Option<f64>,
+    pub south_west: Option<GeoPoint>,
+    pub north_east: Option<GeoPoint>,
+}
+
+// 위치 검색 결과
+#[derive(Debug, Clone)]
+pub struct GeoSearchResult {
+    pub result: SearchResult,
+    // GeoFilter.center에서 문서 위치까지의 거리 (미터, center를 지정한 경우에만 채워짐)
+    // 여러 위치를 가진 문서는 조건을 만족하는 가장 가까운 위치까지의 거리입니다.
+    pub distance_meters: Option<f64>,
+}
+
+// [READ] 쿼리와 일치하면서 GeoPoint 필드의 위치가 filter 조건을 만족하는 문서를 찾는 함수
+// 예: "coffee"를 현재 위치에서 1km 이내의 장소에서 검색 (빈 쿼리는 위치 조건만 적용)
+// sort_by_distance가 true이면 center에서 가까운 순서로, false이면 쿼리 점수 순서로 정렬합니다.
+// 위치가 없는 문서는 결과에 포함되지 않습니다.
+pub fn geo_search(
+    query: String,
+    field: String,
+    filter: GeoFilter,
+    sort_by_distance: bool,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<GeoSearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let geo_field = api.field(&field)?;
+    if !is_geo_field(api.schema.get_field_entry(geo_field)) {
+        return Err(anyhow!("'{}' field is not a GeoPoint field", field).into());
+    }
+    let collector = GeoCollector::new(field, &filter, sort_by_distance, top_k)?;
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let text_query: Box<dyn Query> = if query.trim().is_empty() {
+        Box::new(AllQuery)
+    } else {
+        api.parse_query(&query)?
+    };
+    let mut clauses = vec![(Occur::Must, text_query)];
+    // 영역을 덮는 지오해시 셀로 후보를 찾고, 정확한 위치는 GeoCollector가 확인
+    for area in collector.areas() {
+        let cells: Vec<(Occur, Box<dyn Query>)> = area
+            .covering_cells()
+            .into_iter()
+            .map(|cell| {
+                let term = Term::from_field_text(geo_field, &cell);
+                let query: Box<dyn Query> =
+                    Box::new(TermQuery::new(term, IndexRecordOption::Basic));
+                (Occur::Should, query)
+            })
+            .collect();
+        clauses.push((
+            Occur::Must,
+            Box::new(ConstScoreQuery::new(
+                Box::new(BooleanQuery::new(cells)),
+                0.0,
+            )),
+        ));
+    }
+    let query = BooleanQuery::new(clauses);
+
+    let searcher = api.searcher()?;
+    let hits = searcher.search(&query, &collector)?;
+
+    let top_docs = hits.iter().map(|hit| (hit.score, hit.address)).collect();
+    let results = api.to_search_results(&searcher, top_docs, None)?;
+    Ok(results
+        .into_iter()
+        .zip(hits)
+        .map(|(result, hit)| GeoSearchResult {
+            result,
+            distance_meters: collector.center.map(|_| hit.distance),
+        })
+        .collect())
+}
+
+// geo_search에서 조건을 만족한 문서
+struct GeoHit {
+    score: f32,
+    // center가 없으면 0
+    distance: f64,
+    address: DocAddress,
+}
+
+// GeoPoint 필드의 위치가 조건을 만족하는 상위 top_k개의 문서를 찾는 Collector
+struct GeoCollector {
+    field: String,
+    center: Option<(f64, f64)>,
+    radius_meters: Option<f64>,
+    bounds: Option<BoundingBox>,
+    sort_by_distance: bool,
+    top_k: usize,
+}
+
+impl GeoCollector {
+    // filter의 좌표와 조합이 올바른지 확인
+    fn new(
+        field: String,
+        filter: &GeoFilter,
+        sort_by_distance: bool,
+        top_k: usize,
+    ) -> Result<GeoCollector> {
+        let center = filter.center.map(GeoPoint::validate).transpose()?;
+        if let Some(radius) = filter.radius_meters {
+            if center.is_none() {
+                return Err(anyhow!("radius_meters requires a center"));
+            }
+            if !radius.is_finite() || radius <= 0.0 {
+                return Err(anyhow!("radius_meters must be a positive number"));
+            }
+        }
+        let bounds = match (filter.south_west, filter.north_east) {
+            (Some(south_west), Some(north_east)) => {
+                let (min_lat, min_lon) = south_west.validate()?;
+                let (max_lat, max_lon) = north_east.validate()?;
+                if min_lat > max_lat {
+                    return Err(anyhow!(
+                        "south_west.lat must not be greater than north_east.lat"
+                    ));
+                }
+                Some(BoundingBox {
+                    min_lat,
+                    min_lon,
+                    max_lat,
+                    max_lon,
+                })
+            }
+            (None, None) => None,
+            _ => {
+                return Err(anyhow!(
+                    "A bounding box requires both south_west and north_east"
+                ))
+            }
+        };
+        if center.is_none() && bounds.is_none() {
+            return Err(anyhow!("GeoFilter requires a center or a bounding box"));
+        }
+        if sort_by_distance && center.is_none() {
+            return Err(anyhow!("sort_by_distance requires a center"));
+        }
+        Ok(GeoCollector {
+            field,
+            center,
+            radius_meters: filter.radius_meters,
+            bounds,
+            sort_by_distance,
+            top_k,
+        })
+    }
+
+    // 후보를 찾을 사각형 영역 (center만 지정한 경우 없음)
+    fn areas(&self) -> Vec<BoundingBox> {
+        let radius_area = self
+            .center
+            .zip(self.radius_meters)
+            .map(|(center, radius)| BoundingBox::around(center, radius));
+        radius_area.into_iter().chain(self.bounds).collect()
+    }
+
+    // 조건을 만족하면 center까지의 거리 (center가 없으면 0)
+    fn distance(&self, point: (f64, f64)) -> Option<f64> {
+        if self.bounds.is_some_and(|bounds| !bounds.contains(point)) {
+            return None;
+        }
+        let distance = self
+            .center
+            .map_or(0.0, |center| geo::distance_meters(center, point));
+        if self.radius_meters.is_some_and(|radius| distance > radius) {
+            return None;
+        }
+        Some(distance)
+    }
+}
+
+// 거리 또는 점수 순서로 정렬하고 상위 top_k개만 남김
+fn sort_geo_hits(hits: &mut Vec<GeoHit>, sort_by_distance: bool, top_k: usize) {
+    hits.sort_by(|a, b| {
+        let order = if sort_by_distance {
+            a.distance.total_cmp(&b.distance)
+        } else {
+            b.score.total_cmp(&a.score)
+        };
+        order.then_with(|| a.address.cmp(&b.address))
+    });
+    hits.truncate(top_k);
+}
+
+impl Collector for GeoCollector {
+    type Fruit = Vec<GeoHit>;
+    type Child = GeoSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_ord: tantivy::SegmentOrdinal,
+        segment_reader: &tantivy::SegmentReader,
+    ) -> tantivy::Result<GeoSegmentCollector> {
+        // 같은 위치는 사전에 한 번만 저장되므로 서로 다른 위치마다 한 번씩 조건을 확인 (사전 순번 = distances의 위치)
+        let points = segment_reader.fast_fields().str(&self.field)?;
+        let mut distances = Vec::new();
+        if let Some(points) = &points {
+            let mut stream = points.dictionary().stream()?;
+            while stream.advance() {
+                let point = std::str::from_utf8(stream.key())
+                    .ok()
+                    .and_then(geo::parse_point);
+                distances.push(point.and_then(|point| self.distance(point)));
+            }
+        }
+        Ok(GeoSegmentCollector {
+            points,
+            distances,
+            segment_ord,
+            sort_by_distance: self.sort_by_distance,
+            top_k: self.top_k,
+            hits: Vec::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(&self, fruits: Vec<Vec<GeoHit>>) -> tantivy::Result<Vec<GeoHit>> {
+        let mut merged: Vec<GeoHit> = fruits.into_iter().flatten().collect();
+        sort_geo_hits(&mut merged, self.sort_by_distance, self.top_k);
+        Ok(merged)
+    }
+}
+
+struct GeoSegmentCollector {
+    // 세그먼트에 위치가 하나도 없으면 None
+    points: Option<StrColumn>,
+    // 조건을 만족하지 않는 위치는 None
+    distances: Vec<Option<f64>>,
+    segment_ord: tantivy::SegmentOrdinal,
+    sort_by_distance: bool,
+    top_k: usize,
+    hits: Vec<GeoHit>,
+}
+
+impl SegmentCollector for GeoSegmentCollector {
+    type Fruit = Vec<GeoHit>;
+
+    fn collect(&mut self, doc: DocId, score: tantivy::Score) {
+        let Some(points) = &self.points else {
+            return;
+        };
+        // 여러 위치를 가진 문서는 조건을 만족하는 가장 가까운 위치를 사용
+        let distance = points
+            .term_ords(doc)
+            .filter_map(|ord| self.distances[ord as usize])
+            .min_by(f64::total_cmp);
+        let Some(distance) = distance else {
+            return;
+        };
+        self.hits.push(GeoHit {
+            score,
+            distance,
+            address: DocAddress::new(self.segment_ord, doc),
+        });
+        // 일치 문서가 많아도 메모리를 top_k의 두 배 정도로 유지
+        if self.hits.len() >= 2 * self.top_k {
+            sort_geo_hits(&mut self.hits, self.sort_by_distance, self.top_k);
+        }
+    }
+
+    fn harvest(mut self) -> Vec<GeoHit> {
+        sort_geo_hits(&mut self.hits, self.sort_by_distance, self.top_k);
+        self.hits
+    }
+}
+
+// [READ] 일치한 문서를 group_field 값별로 묶어 반환하는 검색 함수
+// 긴 문서를 여러 조각으로 나누어 색인한 경우 같은 원본 문서의 조각들을 하나의 결과로 합칠 때 사용합니다.
+// group_field는 String fast field여야 하며, 그룹은 가장 높은 점수의 문서 순서로 정렬됩니다.
+// 각 그룹에는 점수가 높은 순서로 최대 hits_per_group개의 문서가 포함됩니다.
+pub fn search_grouped(
+    query: String,
+    group_field: String,
+    top_groups: usize,
+    hits_per_group: usize,
+    index_name: Option<String>,
+) -> Result<GroupedSearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&group_field)?;
+    let field_type = api.schema.get_field_entry(field).field_type();
+    if !field_type.is_fast() || field_type.value_type() != Type::Str {
+        return Err(anyhow!("'{}' field must be a String fast field", group_field).into());
+    }
+    if hits_per_group == 0 {
+        return Err(anyhow!("hits_per_group must be at least 1").into());
+    }
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+    let groups = searcher.search(
+        &query,
+        &GroupCollector {
+            field: group_field,
+            hits_per_group,
+        },
+    )?;
+
+    let total_hits = groups.values().map(|group| group.total_hits).sum();
+    let total_groups = groups.len();
+
+    let groups = rank_groups(groups, top_groups)
+        .into_iter()
+        .map(|(key, group)| {
+            Ok(SearchGroup {
+                key,
+                total_hits: group.total_hits,
+                hits: api.to_search_results(&searcher, group.into_sorted_hits(), None)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(GroupedSearchResponse {
+        total_hits,
+        total_groups,
+        groups,
+    })
+}
+
+// 가장 높은 점수의 문서 순서로 상위 limit개의 그룹을 선택
+fn rank_groups(
+    groups: HashMap<Option<String>, GroupHits>,
+    limit: usize,
+) -> Vec<(Option<String>, GroupHits)> {
+    let mut groups: Vec<(Option<String>, GroupHits)> = groups.into_iter().collect();
+    // 최고 점수가 같으면 그룹 값 순서로 정렬해 결과 순서를 고정
+    groups.sort_by(|(a_key, a), (b_key, b)| {
+        b.best_score()
+            .total_cmp(&a.best_score())
+            .then_with(|| a_key.cmp(b_key))
+    });
+    groups.truncate(limit);
+    groups
+}
+
+// 그룹 안의 일치 문서 수와 점수 상위 문서
+struct GroupHits {
+    total_hits: usize,
+    hits: Vec<(f32, DocAddress)>,
+}
+
+impl GroupHits {
+    fn new() -> Self {
+        GroupHits {
+            total_hits: 0,
+            hits: Vec::new(),
+        }
+    }
+
+    // 상위 limit개만 유지하며, 가득 찬 경우 가장 낮은 점수의 문서보다 높을 때만 교체
+    fn push(&mut self, score: f32, address: DocAddress, limit: usize) {
+        self.total_hits += 1;
+        if self.hits.len() < limit {
+            self.hits.push((score, address));
+            return;
+        }
+        let lowest = (0..self.hits.len())
+            .min_by(|&a, &b| self.hits[a].0.total_cmp(&self.hits[b].0))
+            .expect("limit is at least 1");
+        if score > self.hits[lowest].0 {
+            self.hits[lowest] = (score, address);
+        }
+    }
+
+    fn merge(&mut self, other: GroupHits, limit: usize) {
+        let total_hits = self.total_hits + other.total_hits;
+        for (score, address) in other.hits {
+            self.push(score, address, limit);
+        }
+        self.total_hits = total_hits;
+    }
+
+    fn best_score(&self) -> f32 {
+        self.hits
+            .iter()
+            .map(|(score, _)| *score)
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    fn into_sorted_hits(mut self) -> Vec<(f32, DocAddress)> {
+        self.hits
+            .sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        self.hits
+    }
+}
+
+// 일치한 문서를 String fast field 값별로 묶는 Collector
+struct GroupCollector {
+    field: String,
+    hits_per_group: usize,
+}
+
+impl Collector for GroupCollector {
+    type Fruit = HashMap<Option<String>, GroupHits>;
+    type Child = GroupSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_ord: tantivy::SegmentOrdinal,
+        segment_reader: &tantivy::SegmentReader,
+    ) -> tantivy::Result<GroupSegmentCollector> {
+        Ok(GroupSegmentCollector {
+            keys: segment_reader.fast_fields().str(&self.field)?,
+            segment_ord,
+            hits_per_group: self.hits_per_group,
+            groups: HashMap::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(
+        &self,
+        fruits: Vec<HashMap<Option<String>, GroupHits>>,
+    ) -> tantivy::Result<HashMap<Option<String>, GroupHits>> {
+        let mut merged: HashMap<Option<String>, GroupHits> = HashMap::new();
+        for groups in fruits {
+            for (key, group) in groups {
+                merged
+                    .entry(key)
+                    .or_insert_with(GroupHits::new)
+                    .merge(group, self.hits_per_group);
+            }
+        }
+        Ok(merged)
+    }
+}
+
+struct GroupSegmentCollector {
+    // 세그먼트에 그룹 필드 값이 하나도 없으면 None
+    keys: Option<StrColumn>,
+    segment_ord: tantivy::SegmentOrdinal,
+    hits_per_group: usize,
+    // 세그먼트 안에서는 값 대신 사전 순번으로 묶고 harvest에서 문자열로 변환
+    groups: HashMap<Option<u64>, GroupHits>,
+}
+
+impl SegmentCollector for GroupSegmentCollector {
+    type Fruit = HashMap<Option<String>, GroupHits>;
+
+    fn collect(&mut self, doc: DocId, score: tantivy::Score) {
+        let ord = self
+            .keys
+            .as_ref()
+            .and_then(|keys| keys.term_ords(doc).next());
+        self.groups.entry(ord).or_insert_with(GroupHits::new).push(
+            score,
+            DocAddress::new(self.segment_ord, doc),
+            self.hits_per_group,
+        );
+    }
+
+    fn harvest(self) -> HashMap<Option<String>, GroupHits> {
+        let mut groups = HashMap::new();
+        for (ord, group) in self.groups {
+            let key = match (ord, &self.keys) {
+                (Some(ord), Some(keys)) => {
+                    let mut key = String::new();
+                    // 사전에 있는 순번이므로 읽기에 실패하면 값이 없는 문서로 취급
+                    match keys.ord_to_str(ord, &mut key) {
+                        Ok(true) => Some(key),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            groups
+                .entry(key)
+                .or_insert_with(GroupHits::new)
+                .merge(group, self.hits_per_group);
+        }
+        groups
+    }
+}
+
+// [READ] 쿼리와 일치하는 문서를 집계하는 함수 (terms, histogram, stats 등)
+// Elasticsearch와 같은 형식의 집계 요청 JSON을 받아 결과를 JSON 문자열로 반환합니다.
+// 문서를 가져오지 않으므로 "월별 문서 수", "작성자 상위 10명" 같은 차트를 그릴 때 적합합니다.
+// 집계할 필드는 fast 옵션이 설정되어 있어야 하며, query가 비어 있으면 모든 문서를 집계합니다.
+pub fn aggregate(
+    query: String,
+    aggregations: String,
+    index_name: Option<String>,
+) -> Result<String, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let aggregations: Aggregations = serde_json::from_str(&aggregations)
+        .map_err(|e| anyhow!("Invalid aggregation request: {}", e))?;
+    let query: Box<dyn Query> = if query.trim().is_empty() {
+        Box::new(AllQuery)
+    } else {
+        api.parse_query(&query)?
+    };
+
+    let searcher = api.searcher()?;
+    let collector =
+        AggregationCollector::from_aggs(aggregations, AggregationLimitsGuard::default());
+    let results = searcher.search(&*query, &collector)?;
+
+    Ok(serde_json::to_string(&results).map_err(anyhow::Error::from)?)
+}
+
+// date_histogram의 구간 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateInterval {
+    Hour,
+    Day,
+    // 월요일부터 시작하는 주
+    Week,
+    // 달력 기준 월 (월마다 길이가 다름)
+    Month,
+    Year,
+}
+
+// date_histogram의 구간별 문서 수
+#[derive(Debug, Clone)]
+pub struct DateBucket {
+    // 구간 시작 시각 (Unix epoch 기준 밀리초)
+    pub start_millis: i64,
+    pub count: u64,
+}
+
+// date_histogram이 반환하는 최대 구간 수 (빈 구간 포함)
+const MAX_DATE_BUCKETS: usize = 10_000;
+
+// [READ] 쿼리와 일치하는 문서를 날짜 구간별로 세는 함수 (타임라인, 월별 문서 수 차트 등)
+// date_field는 Date fast field여야 하며, 구간은 utc_offset_minutes만큼 이동한 현지 시각 기준으로 나뉩니다.
+// 첫 구간부터 마지막 구간까지 문서가 없는 구간도 count 0으로 포함하며, 날짜가 없는 문서는 세지 않습니다.
+// query가 비어 있으면 모든 문서를 셉니다.
+pub fn date_histogram(
+    query: String,
+    date_field: String,
+    interval: DateInterval,
+    utc_offset_minutes: Option<i32>,
+    index_name: Option<String>,
+) -> Result<Vec<DateBucket>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&date_field)?;
+    let field_type = api.schema.get_field_entry(field).field_type();
+    if !field_type.is_fast() || field_type.value_type() != Type::Date {
+        return Err(anyhow!("'{}' field must be a Date fast field", date_field).into());
+    }
+    let query: Box<dyn Query> = if query.trim().is_empty() {
+        Box::new(AllQuery)
+    } else {
+        api.parse_query(&query)?
+    };
+
+    let searcher = api.searcher()?;
+    let date_buckets = DateBuckets {
+        interval,
+        offset_ms: i64::from(utc_offset_minutes.unwrap_or(0)) * 60_000,
+    };
+    let counts = searcher.search(
+        &*query,
+        &DateHistogramCollector {
+            field: date_field,
+            buckets: date_buckets,
+        },
+    )?;
+
+    let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) else {
+        return Ok(Vec::new());
+    };
+    let mut buckets = Vec::new();
+    let mut start = first;
+    while start <= last {
+        if buckets.len() == MAX_DATE_BUCKETS {
+            return Err(anyhow!(
+                "More than {} buckets; use a larger interval or narrow the query",
+                MAX_DATE_BUCKETS
+            )
+            .into());
+        }
+        buckets.push(DateBucket {
+            start_millis: start,
+            count: counts.get(&start).copied().unwrap_or(0),
+        });
+        start = date_buckets.next_bucket(start);
+    }
+
+    Ok(buckets)
+}
+
+const MILLIS_PER_HOUR: i64 = 3_600_000;
+const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+
+// 날짜 구간 계산 (구간 시작 시각은 UTC 밀리초)
+#[derive(Clone, Copy)]
+struct DateBuckets {
+    interval: DateInterval,
+    // 현지 시각 = UTC + offset_ms
+    offset_ms: i64,
+}
+
+impl DateBuckets {
+    // millis가 속한 구간의 시작 시각
+    fn bucket_start(&self, millis: i64) -> i64 {
+        let local = millis + self.offset_ms;
+        let days = local.div_euclid(MILLIS_PER_DAY);
+        let local_start = match self.interval {
+            DateInterval::Hour => local.div_euclid(MILLIS_PER_HOUR) * MILLIS_PER_HOUR,
+            DateInterval::Day => days * MILLIS_PER_DAY,
+            // 1970-01-01은 목요일이므로 3일을 더해 월요일 기준으로 계산
+            DateInterval::Week => ((days + 3).div_euclid(7) * 7 - 3) * MILLIS_PER_DAY,
+            DateInterval::Month => {
+                let (year, month, _) = civil_from_days(days);
+                days_from_civil(year, month, 1) * MILLIS_PER_DAY
+            }
+            DateInterval::Year => {
+                let (year, _, _) = civil_from_days(days);
+                days_from_civil(year, 1, 1) * MILLIS_PER_DAY
+            }
+        };
+        local_start - self.offset_ms
+    }
+
+    // start 다음 구간의 시작 시각
+    fn next_bucket(&self, start: i64) -> i64 {
+        let local = start + self.offset_ms;
+        let days = local.div_euclid(MILLIS_PER_DAY);
+        let local_next = match self.interval {
+            DateInterval::Hour => local + MILLIS_PER_HOUR,
+            DateInterval::Day => local + MILLIS_PER_DAY,
+            DateInterval::Week => local + 7 * MILLIS_PER_DAY,
+            DateInterval::Month => {
+                let (year, month, _) = civil_from_days(days);
+                let (year, month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                days_from_civil(year, month, 1) * MILLIS_PER_DAY
+            }
+            DateInterval::Year => {
+                let (year, _, _) = civil_from_days(days);
+                days_from_civil(year + 1, 1, 1) * MILLIS_PER_DAY
+            }
+        };
+        local_next - self.offset_ms
+    }
+}
+
+// 일치한 문서의 날짜를 구간 시작 시각별로 세는 Collector
+struct DateHistogramCollector {
+    field: String,
+    buckets: DateBuckets,
+}
+
+impl Collector for DateHistogramCollector {
+    type Fruit = BTreeMap<i64, u64>;
+    type Child = DateHistogramSegmentCollector;
+
+    fn for_segment(
+        &self,
+        _: tantivy::SegmentOrdinal,
+        segment_reader: &tantivy::SegmentReader,
+    ) -> tantivy::Result<DateHistogramSegmentCollector> {
+        Ok(DateHistogramSegmentCollector {
+            dates: segment_reader.fast_fields().date(&self.field)?,
+            buckets: self.buckets,
+            counts: BTreeMap::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(&self, fruits: Vec<BTreeMap<i64, u64>>) -> tantivy::Result<BTreeMap<i64, u64>> {
+        let mut merged = BTreeMap::new();
+        for counts in fruits {
+            for (start, count) in counts {
+                *merged.entry(start).or_insert(0) += count;
+            }
+        }
+        Ok(merged)
+    }
+}
+
+struct DateHistogramSegmentCollector {
+    dates: Column<DateTime>,
+    buckets: DateBuckets,
+    counts: BTreeMap<i64, u64>,
+}
+
+impl SegmentCollector for DateHistogramSegmentCollector {
+    type Fruit = BTreeMap<i64, u64>;
+
+    fn collect(&mut self, doc: DocId, _: tantivy::Score) {
+        if let Some(date) = self.dates.first(doc) {
+            let start = self.buckets.bucket_start(date.into_timestamp_millis());
+            *self.counts.entry(start).or_insert(0) += 1;
+        }
+    }
+
+    fn harvest(self) -> BTreeMap<i64, u64> {
+        self.counts
+    }
+}
+
+// 1970-01-01부터의 일수를 (년, 월, 일)로 변환 (그레고리력)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// (년, 월, 일)을 1970-01-01부터의 일수로 변환 (그레고리력)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// [READ] fast field 값 순서로 정렬하여 문서를 검색하는 함수
+// "최신순"처럼 관련도와 무관한 정렬이 필요할 때 사용하며, fast 옵션이 설정된
+// 숫자/Date/String 필드만 정렬 기준으로 사용할 수 있습니다.
+pub fn search_documents_sorted(
+    query: String,
+    sort_field: String,
+    ascending: bool,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+
+    Ok(api
+        .search_sorted(&*query, &sort_field, ascending, 0, top_k)?
+        .results)
+}
+
+// [READ] 검색어 없이 모든 문서를 가져오는 함수
+// 검색어를 입력하기 전의 목록 화면처럼 전체 문서를 탐색할 때 사용합니다. (빈 쿼리 문자열은 결과가 없음)
+// sort_field를 지정하면 해당 fast field 순서로 정렬하며, ascending을 생략하면 내림차순입니다.
+pub fn match_all(
+    top_k: usize,
+    offset: Option<usize>,
+    sort_field: Option<String>,
+    ascending: Option<bool>,
+    index_name: Option<String>,
+) -> Result<SearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+    let offset = offset.unwrap_or(0);
+
+    match sort_field {
+        Some(sort_field) => Ok(api.search_sorted(
+            &AllQuery,
+            &sort_field,
+            ascending.unwrap_or(false),
+            offset,
+            top_k,
+        )?),
+        None => Ok(api.search_page(&AllQuery, offset, top_k)?),
+    }
+}
+
+// [READ] BM25 파라미터를 조정하여 검색하는 함수
+// 책의 장(chapter)처럼 매우 긴 문서는 b를 낮추면 길이에 따른 불이익이 줄어듭니다.
+pub fn search_documents_with_bm25(
+    query: String,
+    top_k: usize,
+    params: Bm25Params,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let k1 = params.k1.unwrap_or(1.2);
+    let b = params.b.unwrap_or(0.75);
+    if k1.is_nan() || k1 < 0.0 {
+        return Err(anyhow!("k1 must not be negative").into());
+    }
+    if !(0.0..=1.0).contains(&b) {
+        return Err(anyhow!("b must be between 0 and 1").into());
+    }
+
+    let query = api.parse_query(&query)?;
+
+    Ok(api.search_bm25(&*query, k1, b, top_k)?)
+}
+
+// [READ] 인기도 필드로 순위를 보정하여 검색하는 함수
+// popularity_field는 숫자(I64/U64/F64) fast field여야 하며, 관련도 점수에 1 + ln(1 + 값)을 곱합니다.
+// 자주 열어본 노트 등을 결과 상단으로 올릴 때 사용하며, 값이 없거나 0 이하인 문서는 점수가 그대로입니다.
+pub fn search_documents_with_popularity(
+    query: String,
+    popularity_field: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&popularity_field)?;
+    let field_type = api.schema.get_field_entry(field).field_type();
+    let value_type = field_type.value_type();
+    if !field_type.is_fast() || !matches!(value_type, Type::I64 | Type::U64 | Type::F64) {
+        return Err(anyhow!("'{}' field must be a numeric fast field", popularity_field).into());
+    }
+
+    let query = api.parse_query(&query)?;
+
+    Ok(api.search_weighted(&*query, top_k, move |segment_reader| {
+        let popularity = numeric_column(segment_reader, &popularity_field, value_type);
+        Box::new(move |doc| {
+            let value = popularity(doc).unwrap_or(0.0).max(0.0);
+            (1.0 + value.ln_1p()) as f32
+        })
+    })?)
+}
+
+// [READ] 최신 문서를 우선하여 검색하는 함수
+// date_field는 Date fast field여야 하며, 관련도 점수에 0.5^(경과 시간 / half_life_ms)를 곱합니다.
+// 예: half_life_ms가 7일이면 7일 전 문서는 점수가 절반, 14일 전 문서는 1/4이 됩니다.
+// 미래 날짜는 경과 시간 0으로 취급하고, 날짜가 없는 문서는 점수가 0이 되어 가장 뒤에 옵니다.
+pub fn search_documents_with_recency(
+    query: String,
+    date_field: String,
+    half_life_ms: i64,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.field(&date_field)?;
+    let field_type = api.schema.get_field_entry(field).field_type();
+    if !field_type.is_fast() || field_type.value_type() != Type::Date {
+        return Err(anyhow!("'{}' field must be a Date fast field", date_field).into());
+    }
+    if half_life_ms <= 0 {
+        return Err(anyhow!("half_life_ms must be greater than 0").into());
+    }
+
+    let query = api.parse_query(&query)?;
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as i64);
+
+    Ok(api.search_weighted(&*query, top_k, move |segment_reader| {
+        let dates = segment_reader.fast_fields().date(&date_field).ok();
+        Box::new(
+            move |doc| match dates.as_ref().and_then(|column| column.first(doc)) {
+                Some(date) => {
+                    let age_ms = (now_ms - date.into_timestamp_millis()).max(0);
+                    0.5_f64.powf(age_ms as f64 / half_life_ms as f64) as f32
+                }
+                None => 0.0,
+            },
+        )
+    })?)
+}
+
+// 세그먼트의 숫자 fast field 값을 f64로 읽는 함수를 만듦
+fn numeric_column(
+    segment_reader: &tantivy::SegmentReader,
+    field_name: &str,
+    value_type: Type,
+) -> Box<dyn Fn(DocId) -> Option<f64>> {
+    let fast_fields = segment_reader.fast_fields();
+    match value_type {
+        Type::I64 => {
+            let column = fast_fields.i64(field_name).ok();
+            Box::new(move |doc| column.as_ref()?.first(doc).map(|v| v as f64))
+        }
+        Type::U64 => {
+            let column = fast_fields.u64(field_name).ok();
+            Box::new(move |doc| column.as_ref()?.first(doc).map(|v| v as f64))
+        }
+        _ => {
+            let column = fast_fields.f64(field_name).ok();
+            Box::new(move |doc| column.as_ref()?.first(doc))
+        }
+    }
+}
+
+// [READ] 페이지 단위로 문서를 검색하는 함수
+// 무한 스크롤 등을 위해 offset부터 limit개의 결과와 전체 일치 문서 수를 반환합니다.
+pub fn search_documents_paged(
+    query: String,
+    offset: usize,
+    limit: usize,
+    index_name: Option<String>,
+) -> Result<SearchResponse, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+
+    Ok(api.search_page(&query, offset, limit)?)
+}
+
+// [READ] 오타를 허용하는 퍼지 검색 함수
+// term을 필드의 토크나이저로 분석한 뒤 각 토큰이 max_distance(최대 2) 이내의 편집 거리로
+// 일치하는 문서를 찾습니다. 여러 토큰이면 모든 토큰이 일치해야 합니다.
+pub fn fuzzy_search(
+    field: String,
+    term: String,
+    max_distance: u8,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.fuzzy_query(&field, &term, max_distance)?;
+
+    Ok(api.search(&*query, top_k)?)
+}
+
+// [READ] 입력 중인 검색어로 문서를 검색하는 함수 (search-as-you-type)
+// 마지막 단어는 접두어로 취급되어 "flu"를 입력하는 도중에도 "flutter"가 포함된 문서를 찾습니다.
+pub fn prefix_search(
+    query: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.prefix_query(&query)?;
+
+    Ok(api.search(&*query, top_k)?)
+}
+
+// [READ] 지정한 문서와 비슷한 문서를 찾는 함수 (관련 노트 등)
+// 문서의 저장된 필드 값에서 특징적인 단어를 골라 검색하며, 기준 문서는 결과에서 제외됩니다.
+pub fn find_similar(
+    doc_id: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let searcher = api.searcher()?;
+
+    let source = api.doc_address(&searcher, &doc_id)?;
+
+    // 기준 문서에만 있는 단어는 다른 문서와 일치하지 않으므로 2개 이상의 문서에 있는 단어만 사용
+    let query = MoreLikeThisQuery::builder()
+        .with_min_doc_frequency(2)
+        .with_min_term_frequency(1)
+        .with_document(source);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k + 1))?;
+    let top_docs = top_docs
+        .into_iter()
+        .filter(|(_, doc_address)| *doc_address != source)
+        .take(top_k)
+        .collect();
+
+    Ok(api.to_search_results(&searcher, top_docs, None)?)
+}
+
+// [READ] 정규식과 일치하는 단어를 포함한 문서를 검색하는 함수
+// 로그처럼 오류 코드(E\d{4} 등)를 찾을 때 사용하며, 정규식은 분석된 개별 단어 전체와 일치해야 합니다.
+pub fn regex_search(
+    field: String,
+    pattern: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.text_search_field(&field)?;
+    let query = RegexQuery::from_pattern(&pattern, field)?;
+
+    Ok(api.search(&query, top_k)?)
+}
+
+// [READ] 문서가 쿼리에 대해 받은 점수의 계산 과정을 JSON으로 반환하는 함수
+// 가중치나 분석기를 조정할 때 문서의 순위가 왜 그렇게 나오는지 확인하는 디버깅 용도입니다.
+// 문서가 쿼리와 일치하지 않으면 오류를 반환합니다.
+pub fn explain_score(
+    query: String,
+    doc_id: String,
+    index_name: Option<String>,
+) -> Result<String, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+
+    let doc_address = api.doc_address(&searcher, &doc_id)?;
+    let explanation = query.explain(&searcher, doc_address)?;
+
+    Ok(explanation.to_pretty_json())
+}
+
+// [READ] 구조화된 쿼리로 문서를 검색하는 함수
+// 쿼리 문법 문자열을 조합하거나 이스케이프할 필요 없이 복잡한 조건을 구성할 수 있습니다.
+pub fn search_with_query(
+    query: SearchQuery,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.build_query(&query)?;
+
+    Ok(api.search(&*query, top_k)?)
+}
+
+// [READ] 구문(phrase) 검색 함수
+// phrase의 단어들이 순서대로 나타나는 문서를 찾으며, slop만큼 단어 사이의 간격을 허용합니다.
+// 위치 정보가 색인된 Text 필드에서만 사용할 수 있습니다.
+pub fn phrase_search(
+    field: String,
+    phrase: String,
+    slop: u32,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let query = api.phrase_query(&field, &phrase, slop)?;
+
+    Ok(api.search(&*query, top_k)?)
+}
+
+// [READ] Date 필드의 기간으로 문서를 검색하는 함수
+// from은 포함, to는 제외하는 [from, to) 구간이며 값은 Unix epoch 기준 밀리초입니다.
+// 한쪽 경계를 생략하면 열린 구간으로 검색합니다.
+pub fn search_by_date_range(
+    field: String,
+    from: Option<i64>,
+    to: Option<i64>,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<SearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let date_field = api.field(&field)?;
+    let field_type = api.schema.get_field_entry(date_field).field_type();
+    if field_type.value_type() != Type::Date {
+        return Err(anyhow!("'{}' field is not a Date field", field).into());
+    }
+    if from.is_none() && to.is_none() {
+        return Err(anyhow!("At least one of 'from' or 'to' must be set").into());
+    }
+
+    // fast field는 저장된 정밀도 그대로, 역색인은 색인 정밀도(초)로 비교
+    let is_fast = field_type.is_fast();
+    let to_term = |millis: i64| {
+        let date = DateTime::from_timestamp_millis(millis);
+        if is_fast {
+            Term::from_field_date(date_field, date)
+        } else {
+            Term::from_field_date_for_search(date_field, date)
+        }
+    };
+    let lower = from.map_or(Bound::Unbounded, |v| Bound::Included(to_term(v)));
+    let upper = to.map_or(Bound::Unbounded, |v| Bound::Excluded(to_term(v)));
+
+    Ok(api.search(&RangeQuery::new(lower, upper), top_k)?)
+}
+
+// [READ] 입력 중인 접두어로 시작하는 색인된 단어를 추천하는 함수 (검색어 자동완성)
+// 접두어는 필드의 토크나이저로 분석한 마지막 토큰을 사용하며,
+// 해당 단어가 포함된 문서 수가 많은 순서로 최대 limit개를 반환합니다.
+pub fn suggest_terms(
+    prefix: String,
+    field: String,
+    limit: usize,
+    index_name: Option<String>,
+) -> Result<Vec<String>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let field = api.text_search_field(&field)?;
+    let Some(prefix_term) = api.analyze_terms(field, &prefix)?.pop() else {
+        return Ok(Vec::new());
+    };
+    let prefix = prefix_term.serialized_value_bytes();
+
+    let searcher = api.searcher()?;
+
+    // segment별 단어 사전에서 접두어 범위를 순회하며 문서 수를 합산
+    let mut doc_freqs: HashMap<String, u64> = HashMap::new();
+    for segment_reader in searcher.segment_readers() {
+        let inverted_index = segment_reader.inverted_index(field)?;
+        let mut stream = inverted_index.terms().range().ge(prefix).into_stream()?;
+        while stream.advance() {
+            if !stream.key().starts_with(prefix) {
+                break;
+            }
+            if let Ok(term) = std::str::from_utf8(stream.key()) {
+                *doc_freqs.entry(term.to_string()).or_default() +=
+                    u64::from(stream.value().doc_freq);
+            }
+        }
+    }
+
+    let mut suggestions: Vec<(String, u64)> = doc_freqs.into_iter().collect();
+    suggestions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    Ok(suggestions
+        .into_iter()
+        .take(limit)
+        .map(|(term, _)| term)
+        .collect())
+}
+
+// [READ] ID로 특정 문서를 가져오는 함수
+// ID 조회는 비교적 빠른 작업이므로 sync로 처리
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_document_by_id(
+    id: String,
+    index_name: Option<String>,
+) -> Result<Option<Document>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let searcher = api.searcher()?;
+
+    let id_term = Term::from_field_text(api.id_field, &id);
+    let query = tantivy::query::TermQuery::new(id_term, IndexRecordOption::Basic);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(1))?;
+
+    if let Some((_, doc_address)) = top_docs.first() {
+        let retrieved_doc = searcher.doc::<TantivyDocument>(*doc_address)?;
+        return Ok(Some(api.to_document(&retrieved_doc)));
+    }
+
+    Ok(None)
+}
+
+// 삭제되지 않은 문서 주소를 segment 순서대로 offset부터 limit개 반환
+// 순서는 색인 내부 순서이며, segment가 병합되면 바뀔 수 있습니다.
+fn alive_doc_addresses(searcher: &Searcher, offset: usize, limit: usize) -> Vec<DocAddress> {
+    searcher
+        .segment_readers()
+        .iter()
+        .enumerate()
+        .flat_map(|(segment_ord, segment_reader)| {
+            segment_reader
+                .doc_ids_alive()
+                .map(move |doc_id| DocAddress::new(segment_ord as u32, doc_id))
+        })
+        .skip(offset)
+        .take(limit)
+        .collect()
+}
+
+// [READ] 색인된 문서 ID를 offset부터 limit개 가져오는 함수
+// 검색어 없이 전체 문서를 순회하므로 SQLite 등 원본 데이터와 인덱스를 대조할 때 사용합니다.
+pub fn list_document_ids(
+    offset: usize,
+    limit: usize,
+    index_name: Option<String>,
+) -> Result<Vec<String>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let searcher = api.searcher()?;
+
+    let mut ids = Vec::new();
+    for doc_address in alive_doc_addresses(&searcher, offset, limit) {
+        let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+        if let Some(id) = retrieved_doc
+            .get_first(api.id_field)
+            .and_then(|v| v.as_str())
+        {
+            ids.push(id.to_string());
+        }
+    }
+
+    Ok(ids)
+}
+
+// [READ] 색인된 문서를 offset부터 limit개 가져오는 함수
+pub fn get_all_documents(
+    offset: usize,
+    limit: usize,
+    index_name: Option<String>,
+) -> Result<Vec<Document>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let searcher = api.searcher()?;
+
+    let mut docs = Vec::new();
+    for doc_address in alive_doc_addresses(&searcher, offset, limit) {
+        let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+        docs.push(api.to_document(&retrieved_doc));
+    }
+
+    Ok(docs)
+}
+
+// [UTILITY] 저장된 모든 문서를 JSON Lines 파일로 내보내는 함수
+// 한 줄에 문서 하나씩 스키마의 필드 이름을 키로 하는 JSON 객체로 기록하며, 내보낸 문서 수를 반환합니다.
+// 백업이나 새 스키마로의 이전에 사용하며, 저장(stored)되지 않은 필드 값은 포함되지 않습니다.
+// 취소되면 쓰던 파일을 삭제합니다.
+pub fn export_documents(
+    path: String,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<usize, TantivyError> {
+    let api = get_index(index_name)?;
+    let operation = Operation::start(operation_id);
+
+    api.reader.reload()?;
+    let searcher = api.reader.searcher();
+
+    let mut file = BufWriter::new(File::create(&path)?);
+    let mut count = 0;
+    let result = (|| -> Result<()> {
+        for doc_address in alive_doc_addresses(&searcher, 0, usize::MAX) {
+            operation.check()?;
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            writeln!(file, "{}", retrieved_doc.to_json(&api.schema))?;
+            count += 1;
+        }
+        file.flush()?;
+        Ok(())
+    })();
+    if let Err(e) = result {
+        drop(file);
+        let _ = std::fs::remove_file(&path);
+        return Err(e.into());
+    }
+
+    Ok(count)
+}
+
+// [UTILITY] 인덱스 디렉토리의 스냅샷을 dest_path에 복사하는 함수
+// 마지막 commit 시점의 meta.json과 그 commit이 참조하는 세그먼트 파일만 복사하므로
+// commit되지 않은 변경사항은 포함되지 않습니다. dest_path는 비어 있거나 없는 디렉토리여야 합니다.
+// 복사하는 동안 commit을 막고, 병합이 끝나도 복사 중인 파일이 삭제되지 않도록 meta 잠금을 잡습니다.
+pub fn backup_index(dest_path: String, index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let dest_dir = local_path(&dest_path)?;
+    if dest_dir.exists() && std::fs::read_dir(&dest_dir)?.next().is_some() {
+        return Err(anyhow!("Backup destination {} is not empty", dest_dir.display()).into());
+    }
+
+    let _writer = api.commit_writer()?;
+    let _meta_lock = api
+        .index
+        .directory()
+        .acquire_lock(&META_LOCK)
+        .map_err(tantivy::TantivyError::from)?;
+
+    // 메모리 인덱스도 백업할 수 있도록 파일 시스템 대신 raw_directory로 읽음
+    // 백그라운드 병합이 meta.json을 바꾸는 중이면 다시 읽음
+    let directory = &api.raw_directory;
+    let meta_path = Path::new("meta.json");
+    let (meta, files) = loop {
+        let meta = directory
+            .atomic_read(meta_path)
+            .map_err(tantivy::TantivyError::from)?;
+        let files: HashSet<PathBuf> = api
+            .index
+            .searchable_segment_metas()?
+            .iter()
+            .flat_map(|segment| segment.list_files())
+            .collect();
+        if directory
+            .atomic_read(meta_path)
+            .map_err(tantivy::TantivyError::from)?
+            == meta
+        {
+            break (meta, files);
+        }
+    };
+
+    std::fs::create_dir_all(&dest_dir)?;
+    let mut managed = vec!["\"meta.json\"".to_string()];
+    for file in &files {
+        // 문서가 없는 세그먼트 구성요소는 파일이 생성되지 않을 수 있음
+        if directory
+            .exists(file)
+            .map_err(tantivy::TantivyError::from)?
+        {
+            let data = directory
+                .open_read(file)
+                .map_err(tantivy::TantivyError::from)?
+                .read_bytes()?;
+            std::fs::write(dest_dir.join(file), data.as_slice())?;
+            managed.push(format!("{:?}", file.display().to_string()));
+        }
+    }
+    // 복원한 인덱스에서 오래된 세그먼트 파일이 정리되도록 관리 파일 목록을 함께 기록
+    // (암호화한 인덱스는 같은 키로 읽을 수 있도록 암호화해서 기록)
+    let managed_list = format!("[{}]", managed.join(","));
+    match encryption_key(api.writer_options.as_ref()) {
+        Some(key) => {
+            let dest_directory =
+                MmapDirectory::open(&dest_dir).map_err(tantivy::TantivyError::from)?;
+            EncryptedDirectory::new(Box::new(dest_directory), key)
+                .map_err(|e| anyhow!(e))?
+                .atomic_write(Path::new(".managed.json"), managed_list.as_bytes())?;
+        }
+        None => std::fs::write(dest_dir.join(".managed.json"), managed_list)?,
+    }
+    std::fs::write(dest_dir.join("meta.json"), meta)?;
+
+    Ok(())
+}
+
+// [UTILITY] 디렉토리에 인덱스가 있는지 확인하는 함수 (meta.json 존재 여부)
+#[flutter_rust_bridge::frb(sync)]
+pub fn index_exists(path: String) -> bool {
+    local_path(&path).is_ok_and(|path| path.join("meta.json").exists())
+}
+
+// [UTILITY] 디렉토리의 인덱스를 열지 않고 점검하는 함수
+// meta.json, 마지막 commit이 참조하는 세그먼트 파일의 존재와 체크섬, writer 잠금 상태를 확인합니다.
+// 모든 세그먼트 파일을 끝까지 읽으므로 큰 인덱스에서는 시간이 걸립니다.
+// 암호화한 인덱스는 encryption_key를 지정해야 하며, 키가 없거나 다르면 error에 이유를 담아 반환합니다.
+pub fn validate_index(
+    path: String,
+    encryption_key: Option<Vec<u8>>,
+) -> Result<IndexHealth, TantivyError> {
+    let index_dir = local_path(&path)?;
+    let mut health = IndexHealth {
+        exists: index_dir.join("meta.json").exists(),
+        healthy: false,
+        locked: false,
+        open: false,
+        num_segments: 0,
+        num_docs: 0,
+        missing_files: Vec::new(),
+        corrupted_files: Vec::new(),
+        error: None,
+    };
+    if !health.exists {
+        return Ok(health);
+    }
+
+    let index_dir = index_dir.canonicalize()?;
+    health.open = INDEXES
+        .read()
+        .unwrap()
+        .values()
+        .any(|api| api.index_dir.as_ref() == Some(&index_dir));
+
+    // 잠금을 잡을 수 있으면 바로 해제
+    let raw_directory = MmapDirectory::open(&index_dir).map_err(tantivy::TantivyError::from)?;
+    health.locked = raw_directory.acquire_lock(&INDEX_WRITER_LOCK).is_err();
+
+    let index = match open_directory(&index_dir, None, encryption_key.as_deref())
+        .and_then(|directory| Ok(Index::open(directory)?))
+    {
+        Ok(index) => index,
+        Err(e) => {
+            health.error = Some(e.to_string());
+            return Ok(health);
+        }
+    };
+    let segments = match index.searchable_segment_metas() {
+        Ok(segments) => segments,
+        Err(e) => {
+            health.error = Some(e.to_string());
+            return Ok(health);
+        }
+    };
+    health.num_segments = segments.len() as u64;
+    health.num_docs = segments
+        .iter()
+        .map(|segment| u64::from(segment.num_docs()))
+        .sum();
+
+    // 생성되지 않은 세그먼트 구성요소는 관리 파일 목록에도 없으므로 누락으로 보지 않음
+    let directory = index.directory();
+    let managed_files = directory.list_managed_files();
+    let mut files: Vec<PathBuf> = segments
+        .iter()
+        .flat_map(|segment| segment.list_files())
+        .collect();
+    files.sort();
+    for file in files {
+        if !directory.exists(&file).unwrap_or(false) {
+            if managed_files.contains(&file) {
+                health.missing_files.push(file.display().to_string());
+            }
+        } else if !directory.validate_checksum(&file).unwrap_or(false) {
+            health.corrupted_files.push(file.display().to_string());
+        }
+    }
+
+    if health.missing_files.is_empty() && health.corrupted_files.is_empty() {
+        // 파일 외의 문제(지원하지 않는 형식 등)는 reader를 열어 확인
+        let reader: tantivy::Result<IndexReader> = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into();
+        match reader {
+            Ok(_) => health.healthy = true,
+            Err(e) => health.error = Some(e.to_string()),
+        }
+    }
+
+    Ok(health)
+}
+
+// [UTILITY] 디렉토리의 writer 잠금 파일을 강제로 삭제하는 함수
+// 잠금을 가진 프로세스가 남아 있지 않은데도 LockBusy 오류로 인덱스를 열 수 없을 때만 사용합니다.
+// 다른 프로세스가 실제로 인덱스를 쓰는 중에 호출하면 writer가 두 개 열려 인덱스가 손상될 수 있습니다.
+// 이 앱에서 열려 있는 디렉토리는 잠금을 삭제하지 않고 LockBusy 오류를 반환하므로 먼저 닫아야 합니다.
+pub fn force_unlock(path: String) -> Result<(), TantivyError> {
+    let index_dir = local_path(&path)?.canonicalize()?;
+    if INDEXES
+        .read()
+        .unwrap()
+        .values()
+        .any(|api| api.index_dir.as_ref() == Some(&index_dir))
+    {
+        return Err(TantivyError::new(
+            TantivyErrorKind::LockBusy,
+            format!(
+                "Index at {} is open in this app; close it instead of forcing the lock",
+                index_dir.display()
+            ),
+        ));
+    }
+
+    for lock in [&INDEX_WRITER_LOCK, &META_LOCK] {
+        let lock_file = index_dir.join(&lock.filepath);
+        if lock_file.exists() {
+            std::fs::remove_file(lock_file)?;
+        }
+    }
+
+    Ok(())
+}
+
+// 인덱스 디렉토리를 new_dir로 교체하고 이전 디렉토리의 경로를 반환
+// 새 디렉토리로 옮기지 못하면 이전 디렉토리를 원래 위치로 되돌리고 오류를 반환합니다.
+// 교체 후의 작업이 실패하면 revert_index_dir로 되돌릴 수 있도록 이전 디렉토리는 호출자가 지웁니다.
+fn swap_index_dir(index_dir: &Path, new_dir: &Path) -> Result<PathBuf> {
+    let old_dir = PathBuf::from(format!("{}.old", index_dir.display()));
+    if old_dir.exists() {
+        std::fs::remove_dir_all(&old_dir)?;
+    }
+    std::fs::rename(index_dir, &old_dir)?;
+    if let Err(e) = std::fs::rename(new_dir, index_dir) {
+        std::fs::rename(&old_dir, index_dir)?;
+        return Err(e.into());
+    }
+    Ok(old_dir)
+}
+
+// 교체가 끝난 뒤 이전 디렉토리를 지움
+// 교체는 이미 성공했으므로 지우지 못해도 실패로 처리하지 않습니다 (다음 교체 때 다시 지움).
+fn remove_old_dir(old_dir: &Path) {
+    let _ = std::fs::remove_dir_all(old_dir);
+}
+
+// [UTILITY] 손상된 인덱스에서 읽을 수 있는 문서를 새 인덱스로 옮기는 함수
+// 앱이 쓰는 도중 종료되어 세그먼트 파일이 잘린 경우, 남은 세그먼트에 저장된 문서로 인덱스를 다시 만듭니다.
+// 같은 스키마로 임시 인덱스를 만들어 문서를 색인한 뒤 인덱스 디렉토리와 교체하며, 실패하면 기존 디렉토리가 그대로 유지됩니다.
+// 저장(stored)되지 않은 필드는 복구할 수 없고, meta.json을 읽을 수 없으면 Corrupted 오류를 반환합니다.
+// 이 앱에서 열려 있는 디렉토리는 먼저 닫아야 합니다.
+// 암호화한 인덱스는 encryption_key를 지정해야 하며, 복구한 인덱스도 같은 키로 암호화됩니다.
+pub fn repair_index(
+    path: String,
+    operation_id: Option<u64>,
+    encryption_key: Option<Vec<u8>>,
+) -> Result<RepairReport, TantivyError> {
+    let operation = Operation::start(operation_id);
+    let index_dir = local_path(&path)?;
+    if !index_dir.join("meta.json").exists() {
+        return Err(anyhow!("No index found at {}", index_dir.display()).into());
+    }
+    let index_dir = index_dir.canonicalize()?;
+    if INDEXES
+        .read()
+        .unwrap()
+        .values()
+        .any(|api| api.index_dir.as_ref() == Some(&index_dir))
+    {
+        return Err(TantivyError::new(
+            TantivyErrorKind::LockBusy,
+            format!(
+                "Index at {} is open in this app; close it before repairing",
+                index_dir.display()
+            ),
+        ));
+    }
+
+    // 복구하는 동안 다른 writer가 열리지 않도록 잠금 유지
+    let raw_directory = MmapDirectory::open(&index_dir).map_err(tantivy::TantivyError::from)?;
+    let lock = raw_directory
+        .acquire_lock(&INDEX_WRITER_LOCK)
+        .map_err(|_| {
+            TantivyError::new(
+                TantivyErrorKind::LockBusy,
+                "Index directory is locked by another writer; close the index that uses it before repairing",
+            )
+        })?;
+
+    let index = Index::open(open_directory(&index_dir, None, encryption_key.as_deref())?)?;
+    let schema = index.schema();
+    let tokenizers = tokenizer_manager();
+    check_tokenizers(&schema, &tokenizers)?;
+    let segments = index.searchable_segment_metas()?;
+
+    let repair_dir = PathBuf::from(format!("{}.repair", index_dir.display()));
+    if repair_dir.exists() {
+        std::fs::remove_dir_all(&repair_dir)?;
+    }
+    std::fs::create_dir_all(&repair_dir)?;
+    let repaired = (|| -> Result<RepairReport> {
+        let directory = open_directory(&repair_dir, None, encryption_key.as_deref())?;
+        let mut new_index = Index::create(directory, schema.clone(), index.settings().clone())?;
+        new_index.set_tokenizers(tokenizers);
+        let mut writer = create_writer(&new_index, None)?;
+        let shadow_fields = shadow_fields(&schema);
+        let language_fields = language_fields(&schema);
+        let mut report = RepairReport {
+            recovered_docs: 0,
+            lost_docs: 0,
+            lost_segments: 0,
+        };
+
+        for meta in segments {
+            operation.check()?;
+            // 삭제 목록을 읽을 수 없으면 삭제된 문서가 되살아나므로 세그먼트를 버림
+            let segment = index.segment(meta.clone());
+            let opened = (|| -> tantivy::Result<(StoreReader, Option<AliveBitSet>)> {
+                let store = StoreReader::open(segment.open_read(SegmentComponent::Store)?, 1)?;
+                let alive = if meta.has_deletes() {
+                    let bytes = segment.open_read(SegmentComponent::Delete)?.read_bytes()?;
+                    Some(AliveBitSet::open(bytes))
+                } else {
+                    None
+                };
+                Ok((store, alive))
+            })();
+            let Ok((store, alive)) = opened else {
+                report.lost_segments += 1;
+                report.lost_docs += u64::from(meta.num_docs());
+                continue;
+            };
+
+            for doc_id in 0..meta.max_doc() {
+                if alive.as_ref().is_some_and(|alive| alive.is_deleted(doc_id)) {
+                    continue;
+                }
+                match store.get::<TantivyDocument>(doc_id) {
+                    Ok(mut tantivy_doc) => {
+                        fill_shadow_fields(&mut tantivy_doc, &shadow_fields);
+                        fill_language_fields(&mut tantivy_doc, &language_fields);
+                        writer.add_document(tantivy_doc)?;
+                        report.recovered_docs += 1;
+                    }
+                    Err(_) => report.lost_docs += 1,
+                }
+            }
+        }
+
+        operation.check()?;
+        writer.commit()?;
+        writer.wait_merging_threads()?;
+        Ok(report)
+    })();
+    let report = match repaired {
+        Ok(report) => report,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&repair_dir);
+            return Err(e.into());
+        }
+    };
+
+    // 잠금을 해제한 뒤 디렉토리 교체
+    drop(index);
+    drop(lock);
+    match swap_index_dir(&index_dir, &repair_dir) {
+        Ok(old_dir) => remove_old_dir(&old_dir),
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&repair_dir);
+            return Err(e.into());
+        }
+    }
+
+    Ok(report)
+}
+
+// [UTILITY] backup_index로 만든 스냅샷으로 열린 인덱스를 교체하는 함수
+// 스냅샷을 임시 디렉토리에 복사해 검증한 뒤 인덱스 디렉토리와 교체하고, 같은 writer 설정으로 다시 엽니다.
+// 인덱스의 스키마는 스냅샷의 스키마로 바뀌며, commit되지 않은 변경사항은 버려집니다.
+// 다른 작업이 진행 중이면 교체하지 않고 LockBusy 오류를 반환합니다.
+// 교체하거나 다시 열지 못하면 기존 인덱스 디렉토리로 되돌리고 기존 인덱스를 다시 열어 등록합니다.
+pub fn restore_index(src_path: String, index_name: Option<String>) -> Result<(), TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    let api = get_index(Some(name.clone()))?;
+    let Some(index_dir) = api.index_dir.clone() else {
+        return Err(anyhow!(
+            "Cannot restore into in-memory index '{}'; open the backup directory instead",
+            name
+        )
+        .into());
+    };
+
+    let src_dir = local_path(&src_path)?;
+    if !src_dir.join("meta.json").exists() {
+        return Err(anyhow!("No index backup found at {}", src_dir.display()).into());
+    }
+
+    // 잠금 파일을 제외하고 임시 디렉토리에 복사한 뒤 열어서 검증
+    // 복사하는 동안에는 레지스트리를 잠그지 않으므로 다른 인덱스와 이 인덱스의 검색이 계속 동작
+    let restore_dir = PathBuf::from(format!("{}.restore", index_dir.display()));
+    if restore_dir.exists() {
+        std::fs::remove_dir_all(&restore_dir)?;
+    }
+    std::fs::create_dir_all(&restore_dir)?;
+    let validated = (|| -> Result<()> {
+        for entry in std::fs::read_dir(&src_dir)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            if entry.file_type()?.is_file() && !file_name.to_string_lossy().starts_with(".tantivy-")
+            {
+                std::fs::copy(entry.path(), restore_dir.join(&file_name))?;
+            }
+        }
+        let directory = open_directory(
+            &restore_dir,
+            api.writer_options.as_ref(),
+            encryption_key(api.writer_options.as_ref()),
+        )?;
+        let index = Index::open(directory)?;
+        check_tokenizers(&index.schema(), &tokenizer_manager())?;
+        Ok(())
+    })();
+    if let Err(e) = validated {
+        let _ = std::fs::remove_dir_all(&restore_dir);
+        return Err(e.into());
+    }
+
+    // 레지스트리 잠금을 잡고 있으므로 다른 호출자가 새로 참조를 얻을 수 없음
+    // (레지스트리와 이 함수가 가진 참조 외에 다른 참조가 있으면 진행 중인 작업이 있는 것)
+    let mut indexes = INDEXES.write().unwrap();
+    let registered = indexes
+        .get(&name)
+        .is_some_and(|current| Arc::ptr_eq(current, &api));
+    if !registered || Arc::strong_count(&api) > 2 {
+        let _ = std::fs::remove_dir_all(&restore_dir);
+        return Err(TantivyError::new(
+            TantivyErrorKind::LockBusy,
+            format!("Tantivy index '{}' is in use; retry the restore", name),
+        ));
+    }
+
+    // 등록을 해제한 뒤에는 다른 인덱스의 조회와 검색을 막지 않도록 레지스트리 잠금을 풀고 교체
+    // (교체하는 동안 이 이름의 호출은 NotInitialized 오류를 반환)
+    let writer_options = api.writer_options.clone();
+    let query_config = api.query_parser_config();
+    let removed = indexes.remove(&name);
+    drop(indexes);
+
+    // 기존 writer를 drop하여 잠금을 해제한 뒤 디렉토리 교체
+    drop(removed);
+    drop(api);
+    let restored = swap_index_dir(&index_dir, &restore_dir).and_then(|old_dir| {
+        match open_api(
+            index_dir.clone(),
+            None,
+            default_schema(None),
+            writer_options.clone(),
+        ) {
+            Ok(api) => {
+                remove_old_dir(&old_dir);
+                Ok(api)
+            }
+            Err(e) => {
+                revert_index_dir(&index_dir, &old_dir)?;
+                Err(e)
+            }
+        }
+    });
+    match restored {
+        Ok(api) => insert_index(name, Arc::new(api)),
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&restore_dir);
+            reregister_index(name, index_dir, writer_options, &query_config);
+            Err(e.into())
+        }
+    }
+}
+
+// swap_index_dir로 교체한 디렉토리를 지우고 이전 디렉토리를 원래 위치로 되돌림
+fn revert_index_dir(index_dir: &Path, old_dir: &Path) -> Result<()> {
+    std::fs::remove_dir_all(index_dir)?;
+    std::fs::rename(old_dir, index_dir)?;
+    Ok(())
+}
+
+// 교체에 실패하여 되돌린 기존 인덱스를 같은 설정으로 다시 열어 등록
+// 원래 오류를 반환할 수 있도록 다시 열지 못해도 오류를 반환하지 않습니다.
+fn reregister_index(
+    name: String,
+    index_dir: PathBuf,
+    writer_options: Option<WriterOptions>,
+    query_config: &QueryParserConfig,
+) {
+    let Ok(previous) = open_api(index_dir, None, default_schema(None), writer_options) else {
+        return;
+    };
+    if let Ok(settings) = previous.query_settings_with(query_config) {
+        *previous.query_settings.write().unwrap() = settings;
+    }
+    let _ = insert_index(name, Arc::new(previous));
+}
+
+// 등록을 해제하고 다시 연 인덱스를 name으로 등록
+fn insert_index(name: String, api: Arc<TantivyApi>) -> Result<(), TantivyError> {
+    let mut indexes = INDEXES.write().unwrap();
+    if let Some(current) = indexes.get(&name) {
+        // 다시 여는 동안 같은 이름으로 다른 인덱스가 열린 경우
+        let error = already_open(&name, current);
+        drop(indexes);
+        // 레지스트리 잠금을 푼 뒤에 writer를 drop하여 다른 호출을 막지 않음
+        drop(api);
+        return Err(error);
+    }
+    indexes.insert(name, api);
+    Ok(())
+}
+
+// [UTILITY] 새 스키마로 인덱스를 다시 만드는 함수 (스키마 변경)
+// 새 스키마로 임시 인덱스를 만들어 저장된 모든 문서를 복사한 뒤 인덱스 디렉토리와 교체합니다.
+// 필드 이름이 같은 값은 그대로 옮겨지고, 새 스키마에 없는 필드는 버려집니다.
+// 저장(stored)되지 않은 필드는 복사할 수 없으므로 새 인덱스에서 비어 있게 됩니다.
+// 1000건마다 진행 상황을 보내고, 교체까지 끝나면 done = true인 이벤트를 보냅니다.
+// 복사하는 동안 commit을 막으며, 실패하면 기존 인덱스가 그대로 유지됩니다.
+// 디렉토리를 교체하거나 새 인덱스를 열지 못하면 기존 디렉토리로 되돌리고 기존 인덱스를 다시 열어 등록합니다.
+pub fn reindex_with_schema(
+    fields: Vec<FieldDef>,
+    sink: StreamSink<BatchProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    let api = get_index(Some(name.clone()))?;
+    let operation = Operation::start(operation_id);
+
+    let schema = build_schema(&fields)?;
+    let tokenizers = tokenizer_manager();
+    check_tokenizers(&schema, &tokenizers)?;
+
+    let writer = api.commit_writer()?;
+
+    api.reader.reload()?;
+    let searcher = api.reader.searcher();
+    let total = searcher.num_docs() as usize;
+
+    // 메모리 인덱스는 새 메모리 인덱스로 복사
+    let reindex_dir = match &api.index_dir {
+        Some(index_dir) => {
+            let reindex_dir = PathBuf::from(format!("{}.reindex", index_dir.display()));
+            if reindex_dir.exists() {
+                std::fs::remove_dir_all(&reindex_dir)?;
+            }
+            std::fs::create_dir_all(&reindex_dir)?;
+            Some(reindex_dir)
+        }
+        None => None,
+    };
+    let remove_reindex_dir = || {
+        if let Some(reindex_dir) = &reindex_dir {
+            let _ = std::fs::remove_dir_all(reindex_dir);
+        }
+    };
+    let copied = (|| -> Result<(Index, Option<RamDirectory>)> {
+        // 기존 인덱스의 압축 설정을 유지하되 writer_options로 지정한 설정이 있으면 적용
+        let settings = index_settings(api.writer_options.as_ref(), api.index.settings().clone())?;
+        let (mut index, memory_directory) = match &reindex_dir {
+            Some(reindex_dir) => {
+                let directory = open_directory(
+                    reindex_dir,
+                    api.writer_options.as_ref(),
+                    encryption_key(api.writer_options.as_ref()),
+                )?;
+                (Index::create(directory, schema.clone(), settings)?, None)
+            }
+            None => {
+                let (index, memory_directory) = memory_index(schema.clone(), settings)?;
+                (index, Some(memory_directory))
+            }
+        };
+        index.set_tokenizers(tokenizers);
+        let mut new_writer = create_writer(&index, api.writer_options.as_ref())?;
+        let new_shadow_fields = shadow_fields(&schema);
+        let new_language_fields = language_fields(&schema);
+
+        for (i, doc_address) in alive_doc_addresses(&searcher, 0, usize::MAX)
+            .into_iter()
+            .enumerate()
+        {
+            operation.check()?;
+            // 필드 이름을 기준으로 옮기기 위해 JSON을 거쳐 새 스키마의 문서로 변환
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            let json = retrieved_doc.to_json(&api.schema);
+            let mut new_doc = TantivyDocument::parse_json(&schema, &json)
+                .map_err(|e| anyhow!("Document {} does not fit the new schema: {}", json, e))?;
+            fill_shadow_fields(&mut new_doc, &new_shadow_fields);
+            fill_language_fields(&mut new_doc, &new_language_fields);
+            new_writer.add_document(new_doc)?;
+
+            let processed = i + 1;
+            if processed % BATCH_PROGRESS_INTERVAL == 0 && processed < total {
+                // Dart 쪽에서 구독을 취소해도 색인은 끝까지 진행
+                let _ = sink.add(BatchProgress {
+                    processed,
+                    total,
+                    done: false,
+                });
+            }
+        }
+
+        operation.check()?;
+        new_writer.commit()?;
+        new_writer.wait_merging_threads()?;
+        Ok((index, memory_directory))
+    })();
+    let (new_index, memory_directory) = match copied {
+        Ok(copied) => copied,
+        Err(e) => {
+            remove_reindex_dir();
+            return Err(e.into());
+        }
+    };
+
+    // 레지스트리 잠금을 잡고 있으므로 다른 호출자가 새로 참조를 얻을 수 없음
+    // (레지스트리와 이 함수가 가진 참조 외에 다른 참조가 있으면 진행 중인 작업이 있는 것)
+    let mut indexes = INDEXES.write().unwrap();
+    if Arc::strong_count(&api) > 2 {
+        remove_reindex_dir();
+        return Err(TantivyError::new(
+            TantivyErrorKind::LockBusy,
+            format!("Tantivy index '{}' is in use; retry the reindex", name),
+        ));
+    }
+
+    let writer_options = api.writer_options.clone();
+    match (api.index_dir.clone(), &reindex_dir) {
+        (Some(index_dir), Some(reindex_dir)) => {
+            // 등록을 해제한 뒤에는 다른 인덱스의 조회와 검색을 막지 않도록 레지스트리 잠금을 풀고 교체
+            let query_config = api.query_parser_config();
+            drop(writer);
+            drop(searcher);
+            let removed = indexes.remove(&name);
+            drop(indexes);
+
+            // 기존 writer를 drop하여 잠금을 해제한 뒤 디렉토리 교체
+            drop(removed);
+            drop(api);
+            drop(new_index);
+            let reindexed = swap_index_dir(&index_dir, reindex_dir).and_then(|old_dir| {
+                match open_api(
+                    index_dir.clone(),
+                    Some(schema.clone()),
+                    schema,
+                    writer_options.clone(),
+                ) {
+                    Ok(api) => {
+                        remove_old_dir(&old_dir);
+                        Ok(api)
+                    }
+                    Err(e) => {
+                        revert_index_dir(&index_dir, &old_dir)?;
+                        Err(e)
+                    }
+                }
+            });
+            match reindexed {
+                Ok(new_api) => insert_index(name, Arc::new(new_api))?,
+                Err(e) => {
+                    remove_reindex_dir();
+                    reregister_index(name, index_dir, writer_options, &query_config);
+                    return Err(e.into());
+                }
+            }
+        }
+        _ => {
+            // 메모리 인덱스는 교체할 디렉토리가 없으므로 새 인덱스를 만든 뒤 등록만 바꿈
+            let memory_directory = memory_directory.expect("in-memory reindex keeps its directory");
+            let new_api =
+                api_from_index(new_index, None, Box::new(memory_directory), writer_options)?;
+            let previous = indexes.insert(name, Arc::new(new_api));
+            drop(indexes);
+            drop(writer);
+            drop(searcher);
+            drop(previous);
+        }
+    }
+
+    let _ = sink.add(BatchProgress {
+        processed: total,
+        total,
+        done: true,
+    });
+
+    Ok(())
+}
+
+// [UTILITY] 기존 인덱스에 필드를 추가하는 함수 (앱 업데이트로 스키마가 늘어난 경우)
+// 현재 스키마(get_schema)에 fields를 더한 스키마로 reindex_with_schema를 실행하므로 진행 상황과 취소, 실패 시 동작이 같습니다.
+// 기존 문서에는 새 필드 값이 없으며, 이후 update_document 등으로 채울 수 있습니다.
+// 저장(stored)되지 않은 기존 필드는 복사할 수 없으므로, 문서가 있는 인덱스에 그런 필드가 있으면 값이 사라지지 않도록 실패합니다.
+pub fn add_schema_fields(
+    fields: Vec<FieldDef>,
+    sink: StreamSink<BatchProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let name = index_name.unwrap_or_else(|| DEFAULT_INDEX.to_string());
+    let api = get_index(Some(name.clone()))?;
+
+    if fields.is_empty() {
+        return Err(anyhow!("No fields to add").into());
+    }
+    let mut schema_fields = get_schema(Some(name.clone()))?;
+    for field in &fields {
+        if schema_fields.iter().any(|f| f.name == field.name) {
+            return Err(anyhow!("'{}' field already exists", field.name).into());
+        }
+    }
+    if api.searcher()?.num_docs() > 0 {
+        if let Some(field) = schema_fields.iter().find(|f| !f.stored) {
+            return Err(anyhow!(
+                "'{}' field is not stored and would lose its values; rebuild the index with reindex_with_schema instead",
+                field.name
+            )
+            .into());
+        }
+    }
+    drop(api);
+
+    schema_fields.extend(fields);
+    reindex_with_schema(schema_fields, sink, operation_id, Some(name))
+}
+
+// [UPDATE] 문서를 업데이트하는 함수
+pub fn update_document(doc: Document, index_name: Option<String>) -> Result<(), TantivyError> {
+    // add_document가 내부적으로 delete & add 로직을 수행하므로 그대로 호출
+    add_document(doc, index_name)
+}
+
+// [UPDATE] 저장된 문서의 버전이 expected_version일 때만 문서를 교체하는 함수 (낙관적 동시성 제어)
+// 문서를 읽은 뒤 다른 곳에서 수정했다면 VersionConflict 오류를 반환하므로, 양방향 동기화에서 동시 수정을 감지할 수 있습니다.
+// expected_version이 0이면 같은 ID의 문서가 없을 때만 추가합니다. 성공하면 새 버전을 반환합니다.
+// 비교 전에 *_no_commit 함수로 쌓인 변경을 먼저 commit하여 최신 버전과 비교합니다.
+pub fn update_document_if_version(
+    doc: Document,
+    expected_version: u64,
+    index_name: Option<String>,
+) -> Result<u64, TantivyError> {
+    let api = get_index(index_name)?;
+    if api.version_field.is_none() {
+        return Err(anyhow!(
+            "This index was created without document versions; rebuild it with reindex_with_schema"
+        )
+        .into());
+    }
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+    api.commit_pending_locked(&mut writer)?;
+
+    // writer 잠금을 잡고 있으므로 비교한 뒤 교체할 때까지 다른 호출자가 문서를 바꿀 수 없음
+    api.reader.reload()?;
+    let current_version = api.stored_version(&api.reader.searcher(), &doc.id)?;
+    if current_version != expected_version {
+        return Err(TantivyError::new(
+            TantivyErrorKind::VersionConflict,
+            format!(
+                "Document '{}' has version {}, expected {}",
+                doc.id, current_version, expected_version
+            ),
+        ));
+    }
+
+    let tantivy_doc = api.to_tantivy_doc(&doc)?;
+    let new_version = tantivy_doc
+        .get_first(api.version_field.expect("checked above"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    writer.delete_term(Term::from_field_text(api.id_field, &doc.id));
+    writer.add_document(tantivy_doc)?;
+    writer.commit()?;
+
+    Ok(new_version)
+}
+
+// [READ] 문서의 현재 버전을 가져오는 함수 (문서가 없거나 버전 없이 저장된 문서면 0)
+// 문서를 쓸 때마다 바뀌며, update_document_if_version의 expected_version으로 전달합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_document_version(id: String, index_name: Option<String>) -> Result<u64, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let searcher = api.searcher()?;
+    Ok(api.stored_version(&searcher, &id)?)
+}
+
+// [DELETE] 문서를 삭제하는 함수
+pub fn delete_document(id: String, index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
+    let id_term = Term::from_field_text(api.id_field, &id);
+
+    writer.delete_term(id_term);
+    writer.commit()?;
+
+    Ok(())
+}
+
+// [CREATE] JSON 문자열로 문서를 추가하는 함수
+// Document 구조체 대신 스키마의 필드 이름을 키로 하는 JSON 객체를 전달합니다.
+// 예: {"id": "1", "text": "...", "price": 1200, "created_at": "2024-01-01T00:00:00Z"}
+// Date는 RFC 3339 문자열, Facet은 "/a/b" 형식의 문자열이며, 같은 id의 문서는 교체됩니다.
+pub fn add_document_json(json: String, index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+
+    let (id_term, tantivy_doc) = api.parse_json_doc(&json)?;
+    writer.delete_term(id_term);
+    writer.add_document(tantivy_doc)?;
+    writer.commit()?;
+
+    Ok(())
+}
+
+// [CREATE] 파일에서 본문 텍스트를 추출하여 doc_id 문서의 text로 추가하는 함수
+// 파일을 Rust에서 직접 읽으므로 큰 파일도 Dart 메모리로 불러오지 않고 색인할 수 있습니다.
+// mime_type을 지정하지 않으면 확장자로 형식을 판단합니다. (txt, md, csv, html, pdf, epub)
+// HTML은 태그를 제거하고 엔티티를 디코딩하며, PDF와 EPUB은 각각 pdf, epub cargo feature가 필요합니다.
+pub fn index_file(
+    path: String,
+    doc_id: String,
+    mime_type: Option<String>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let text = extract_file_text(Path::new(&path), mime_type.as_deref())?;
+
+    add_document(
+        Document {
+            id: doc_id,
+            text,
+            fields: Vec::new(),
+            metadata: HashMap::new(),
+        },
+        index_name,
+    )
+}
+
+// file_schema_fields의 파일 정보 필드 이름
+const FILE_PATH_FIELD: &str = "path";
+const FILE_SIZE_FIELD: &str = "size";
+const FILE_MODIFIED_FIELD: &str = "modified";
+const FILE_MIME_TYPE_FIELD: &str = "mime_type";
+const FILE_HASH_FIELD: &str = "content_hash";
+
+// 파일 검색용 문서 (index_directory가 색인하며, 문서 ID는 파일 경로)
+#[derive(Debug, Clone)]
+pub struct FileDocument {
+    pub path: String,
+    // 파일 크기 (바이트)
+    pub size: u64,
+    // 수정 시각 (Unix epoch 기준 밀리초)
+    pub modified: i64,
+    pub mime_type: String,
+    // 파일 내용의 해시 (sync_directory가 바뀐 파일을 찾는 데 사용)
+    pub content_hash: String,
+    // 파일에서 추출한 본문
+    pub text: String,
+}
+
+impl FileDocument {
+    // file_schema_fields()로 만든 인덱스의 검색 결과 문서를 FileDocument로 변환
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_document(doc: Document) -> Result<FileDocument, TantivyError> {
+        let value = |name: &str| {
+            doc.fields
+                .iter()
+                .find(|field_value| field_value.name == name)
+                .ok_or_else(|| anyhow!("Document '{}' has no '{}' field", doc.id, name))
+        };
+        let path = value(FILE_PATH_FIELD)?
+            .text_value
+            .clone()
+            .unwrap_or_default();
+        let size = value(FILE_SIZE_FIELD)?.u64_value.unwrap_or_default();
+        let modified = value(FILE_MODIFIED_FIELD)?.date_value.unwrap_or_default();
+        let mime_type = value(FILE_MIME_TYPE_FIELD)?
+            .text_value
+            .clone()
+            .unwrap_or_default();
+        let content_hash = value(FILE_HASH_FIELD)?
+            .text_value
+            .clone()
+            .unwrap_or_default();
+        Ok(FileDocument {
+            path,
+            size,
+            modified,
+            mime_type,
+            content_hash,
+            text: doc.text,
+        })
+    }
+
+    // 파일의 정보를 읽고 본문을 추출
+    fn read(path: &Path, mime_type: &str) -> Result<FileDocument> {
+        let (size, modified) = file_size_and_modified(path)?;
+        Ok(FileDocument {
+            path: path.to_string_lossy().into_owned(),
+            size,
+            modified,
+            mime_type: mime_type.to_string(),
+            content_hash: content_hash(path)?,
+            text: extract_file_text(path, Some(mime_type))?,
+        })
+    }
+
+    fn into_document(self) -> Document {
+        let fields = vec![
+            FieldValue {
+                text_value: Some(self.path.clone()),
+                ..FieldValue::empty(FILE_PATH_FIELD.to_string())
+            },
+            FieldValue {
+                u64_value: Some(self.size),
+                ..FieldValue::empty(FILE_SIZE_FIELD.to_string())
+            },
+            FieldValue {
+                date_value: Some(self.modified),
+                ..FieldValue::empty(FILE_MODIFIED_FIELD.to_string())
+            },
+            FieldValue {
+                text_value: Some(self.mime_type),
+                ..FieldValue::empty(FILE_MIME_TYPE_FIELD.to_string())
+            },
+            FieldValue {
+                text_value: Some(self.content_hash),
+                ..FieldValue::empty(FILE_HASH_FIELD.to_string())
+            },
+        ];
+        Document {
+            id: self.path,
+            text: self.text,
+            fields,
+            metadata: HashMap::new(),
+        }
+    }
+}
+
+// 파일 크기 (바이트)와 수정 시각 (Unix epoch 기준 밀리초)
+fn file_size_and_modified(path: &Path) -> Result<(u64, i64)> {
+    let metadata = std::fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as i64);
+    Ok((metadata.len(), modified))
+}
+
+// index_directory, sync_directory가 색인할 파일과 MIME 타입
+// globs가 비어 있으면 index_file이 지원하는 형식의 파일만, 아니면 globs와 일치한 그 외 형식의 파일도 일반 텍스트로 색인
+fn directory_files(path: &str, globs: &[String]) -> Result<Vec<(PathBuf, &'static str)>> {
+    Ok(walk_files(Path::new(path), globs)?
+        .into_iter()
+        .filter_map(|file| {
+            let mime_type = mime_type_from_extension(&file)
+                .or_else(|| (!globs.is_empty()).then_some("text/plain"))?;
+            Some((file, mime_type))
+        })
+        .collect())
+}
+
+// 파일 검색용 스키마 필드 (id, text, path, size, modified, mime_type, content_hash)
+// path는 단어 단위로 검색할 수 있고, size와 modified는 범위 검색과 정렬, mime_type은 필터에 사용할 수 있습니다.
+// 다른 필드를 추가하여 init_tantivy_with_schema, open_index_with_schema에 전달해도 됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn file_schema_fields() -> Vec<FieldDef> {
+    let field = |name: &str, field_type: FieldType, fast: bool| FieldDef {
+        name: name.to_string(),
+        field_type,
+        stored: true,
+        indexed: true,
+        fast,
+        tokenizer: None,
+        record_option: None,
+        shadow_tokenizers: Vec::new(),
+        detect_languages: Vec::new(),
+    };
+    vec![
+        field("id", FieldType::String, false),
+        field("text", FieldType::Text, false),
+        field(FILE_PATH_FIELD, FieldType::Text, false),
+        field(FILE_SIZE_FIELD, FieldType::U64, true),
+        field(FILE_MODIFIED_FIELD, FieldType::Date, true),
+        field(FILE_MIME_TYPE_FIELD, FieldType::String, true),
+        field(FILE_HASH_FIELD, FieldType::String, false),
+    ]
+}
+
+// add_markdown_document가 제목 목록을 JSON 배열로 기록하는 메타데이터 키
+const MARKDOWN_HEADINGS_KEY: &str = "markdown_headings";
+
+// [CREATE] 마크다운 노트를 추가하는 함수
+// 본문의 제목을 찾아 메타데이터 "markdown_headings"에 JSON 배열([{"level", "title", "anchor", "line"}])로 기록합니다.
+// headings_field를 지정하면 제목들을 해당 문자열 필드에도 색인하므로, 쿼리 파서의 boosts로 제목 일치에 가중치를 줄 수 있습니다.
+// 본문은 그대로 저장되므로 text 필드에는 "markdown" 분석기를 사용하세요.
+pub fn add_markdown_document(
+    doc: Document,
+    headings_field: Option<String>,
+    index_name: Option<String>,
+) -> Result<Vec<MarkdownHeading>, TantivyError> {
+    let headings = markdown_headings(&doc.text);
+    let headings: Vec<MarkdownHeading> = heading_anchors(&headings)
+        .into_iter()
+        .zip(headings)
+        .map(|(anchor, heading)| MarkdownHeading {
+            level: heading.level,
+            title: heading.title,
+            anchor,
+            line: heading.line,
+        })
+        .collect();
+
+    let mut doc = doc;
+    if let Some(headings_field) = headings_field {
+        doc.fields.extend(headings.iter().map(|heading| FieldValue {
+            text_value: Some(heading.title.clone()),
+            ..FieldValue::empty(headings_field.clone())
+        }));
+    }
+    let metadata: Vec<serde_json::Value> = headings
+        .iter()
+        .map(|heading| {
+            serde_json::json!({
+                "level": heading.level,
+                "title": heading.title,
+                "anchor": heading.anchor,
+                "line": heading.line,
+            })
+        })
+        .collect();
+    doc.metadata.insert(
+        MARKDOWN_HEADINGS_KEY.to_string(),
+        serde_json::Value::Array(metadata).to_string(),
+    );
+
+    add_document(doc, index_name)?;
+    Ok(headings)
+}
+
+// [BATCH] 여러 JSON 문서를 한 번에 추가하는 함수
+// 모든 문서를 먼저 파싱하므로 하나라도 잘못되면 아무 문서도 추가되지 않습니다.
+pub fn add_documents_json_batch(
+    jsons: Vec<String>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut parsed = Vec::with_capacity(jsons.len());
+    for (i, json) in jsons.iter().enumerate() {
+        let doc = api
+            .parse_json_doc(json)
+            .map_err(|e| anyhow!("Invalid JSON document at index {}: {}", i, e))?;
+        parsed.push(doc);
+    }
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+    for (id_term, tantivy_doc) in parsed {
+        writer.delete_term(id_term);
+        writer.add_document(tantivy_doc)?;
+    }
+    writer.commit()?;
+
+    Ok(())
+}
+
+// [BATCH] 여러 문서를 한 번에 추가하는 함수 (성능 최적화)
+pub fn add_documents_batch(
+    docs: Vec<Document>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+
+    // 변환에 실패한 문서가 있으면 일부만 쌓이지 않도록 writer에 넣기 전에 모두 변환
+    let tantivy_docs = docs
+        .iter()
+        .map(|doc| api.to_tantivy_doc(doc))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (doc, tantivy_doc) in docs.iter().zip(tantivy_docs) {
+        // 기존 문서가 있다면 삭제 (Update-or-Insert)
+        let id_term = Term::from_field_text(api.id_field, &doc.id);
+        writer.delete_term(id_term);
+
+        writer.add_document(tantivy_doc)?;
+    }
+
+    // 모든 문서를 추가한 후 한 번만 commit
+    writer.commit()?;
+
+    Ok(())
+}
+
+// add_document_chunked에서 max_chars를 지정하지 않았을 때의 조각 최대 길이 (문자 수)
+const DEFAULT_CHUNK_MAX_CHARS: usize = 1000;
+
+// [CREATE] 긴 문서를 여러 조각으로 나누어 색인하는 함수 (전자책, PDF 등)
+// text를 문장 경계에서 최대 max_chars 문자 길이의 조각으로 나누고, 각 조각을 '<id>#<순번>' ID의 문서로 추가합니다.
+// 문서의 나머지 필드와 메타데이터는 모든 조각에 복사되므로 필터 검색에도 그대로 사용할 수 있습니다.
+// 같은 ID로 다시 호출하면 기존 조각을 모두 교체하며, 추가한 조각 수를 반환합니다.
+pub fn add_document_chunked(
+    doc: Document,
+    max_chars: Option<usize>,
+    index_name: Option<String>,
+) -> Result<usize, TantivyError> {
+    let api = get_index(index_name)?;
+    let parent_field = api.chunk_parent_field()?;
+    if api.text_field.is_none() {
+        return Err(anyhow!("This index has no 'text' field to split").into());
+    }
+    let max_chars = max_chars.unwrap_or(DEFAULT_CHUNK_MAX_CHARS);
+    if max_chars == 0 {
+        return Err(anyhow!("max_chars must be at least 1").into());
+    }
+
+    // 조각을 모두 변환한 뒤에 기존 조각을 삭제하여, 잘못된 필드 값으로 실패해도 기존 문서를 유지
+    let chunks = split_chunks(&doc.text, max_chars);
+    let mut tantivy_docs = Vec::with_capacity(chunks.len());
+    for (i, chunk) in chunks.iter().enumerate() {
+        let mut tantivy_doc = api.to_tantivy_doc(&Document {
+            id: format!("{}#{}", doc.id, i),
+            text: chunk.to_string(),
+            fields: doc.fields.clone(),
+            metadata: doc.metadata.clone(),
+        })?;
+        tantivy_doc.add_text(parent_field, &doc.id);
+        tantivy_docs.push(tantivy_doc);
+    }
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+    writer.delete_term(Term::from_field_text(api.id_field, &doc.id));
+    writer.delete_term(Term::from_field_text(parent_field, &doc.id));
+    for tantivy_doc in tantivy_docs {
+        writer.add_document(tantivy_doc)?;
+    }
+    writer.commit()?;
+
+    Ok(chunks.len())
+}
+
+// [DELETE] add_document_chunked로 추가한 문서의 조각을 모두 삭제하는 함수
+pub fn delete_chunked_document(
+    parent_id: String,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    let parent_field = api.chunk_parent_field()?;
+
+    let mut writer = api.commit_writer()?;
+    writer.delete_term(Term::from_field_text(parent_field, &parent_id));
+    writer.commit()?;
+
+    Ok(())
+}
+
+// [READ] 조각으로 나누어 색인한 문서를 원본 문서 단위로 검색하는 함수
+// 같은 원본의 조각들은 하나의 결과로 합쳐지며, 가장 잘 일치한 조각과 그 점수로 순위를 매깁니다.
+// add_document_chunked로 추가하지 않은 문서는 결과에 포함되지 않습니다.
+pub fn search_chunked(
+    query: String,
+    top_k: usize,
+    index_name: Option<String>,
+) -> Result<Vec<ChunkSearchResult>, TantivyError> {
+    let api = get_index(index_name)?;
+    api.chunk_parent_field()?;
+
+    let query = api.parse_query(&query)?;
+
+    let searcher = api.searcher()?;
+    let mut groups = searcher.search(
+        &query,
+        &GroupCollector {
+            field: PARENT_FIELD.to_string(),
+            hits_per_group: 1,
+        },
+    )?;
+    groups.remove(&None);
+
+    let mut results = Vec::new();
+    for (parent_id, group) in rank_groups(groups, top_k) {
+        let parent_id = parent_id.expect("ungrouped documents were removed");
+        let matching_chunks = group.total_hits;
+        let Some(chunk) = api
+            .to_search_results(&searcher, group.into_sorted_hits(), None)?
+            .pop()
+        else {
+            continue;
+        };
+        let chunk_index = chunk
+            .doc
+            .id
+            .strip_prefix(parent_id.as_str())
+            .and_then(|suffix| suffix.strip_prefix('#'))
+            .and_then(|index| index.parse().ok())
+            .unwrap_or_default();
+        results.push(ChunkSearchResult {
+            parent_id,
+            chunk_index,
+            chunk,
+            matching_chunks,
+        });
+    }
+
+    Ok(results)
+}
+
+// 문장 끝을 나타내는 문자 (전각 문장 부호는 뒤에 공백이 없어도 문장 끝으로 취급)
+fn sentence_end(c: char, next: Option<char>) -> bool {
+    match c {
+        '\n' | '。' | '！' | '？' => true,
+        '.' | '!' | '?' => next.is_none_or(char::is_whitespace),
+        _ => false,
+    }
+}
+
+// text를 최대 max_chars 문자 길이의 조각으로 나눔 (빈 text도 하나의 조각)
+// 문장을 이어 붙이다가 길이를 넘으면 새 조각을 시작하고, max_chars보다 긴 문장은 공백 위치에서 나눕니다.
+fn split_chunks(text: &str, max_chars: usize) -> Vec<&str> {
+    // 문장 단위로 나눔 (문장 끝 뒤의 공백은 앞 문장에 포함)
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if !sentence_end(c, chars.peek().map(|&(_, next)| next)) {
+            continue;
+        }
+        let mut end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if !next.is_whitespace() {
+                break;
+            }
+            end = j + next.len_utf8();
+            chars.next();
+        }
+        sentences.push(&text[start..end]);
+        start = end;
+    }
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+
+    let mut chunks = Vec::new();
+    let mut chunk_start = 0;
+    let mut chunk_chars = 0;
+    let mut offset = 0;
+    for sentence in sentences {
+        let sentence_chars = sentence.chars().count();
+        if chunk_chars > 0 && chunk_chars + sentence_chars > max_chars {
+            chunks.push(&text[chunk_start..offset]);
+            chunk_start = offset;
+            chunk_chars = 0;
+        }
+        offset += sentence.len();
+        chunk_chars += sentence_chars;
+        // 한 문장이 max_chars보다 길면 max_chars 안의 마지막 공백(없으면 max_chars 위치)에서 나눔
+        while chunk_chars > max_chars {
+            let rest = &text[chunk_start..offset];
+            let limit = rest
+                .char_indices()
+                .nth(max_chars)
+                .map_or(rest.len(), |(i, _)| i);
+            let split = rest[..limit]
+                .rfind(char::is_whitespace)
+                .filter(|&i| i > 0)
+                .map_or(limit, |i| {
+                    i + rest[i..].chars().next().map_or(0, char::len_utf8)
+                });
+            chunks.push(&rest[..split]);
+            chunk_start += split;
+            chunk_chars = text[chunk_start..offset].chars().count();
+        }
+    }
+    if chunk_start < text.len() {
+        chunks.push(&text[chunk_start..]);
+    }
+
+    let mut chunks: Vec<&str> = chunks
+        .into_iter()
+        .map(str::trim)
+        .filter(|chunk| !chunk.is_empty())
+        .collect();
+    if chunks.is_empty() {
+        chunks.push("");
+    }
+    chunks
+}
+
+// upsert_documents_batch의 문서별 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    // 같은 ID의 문서가 없어 새로 추가됨
+    Inserted,
+    // 같은 ID의 기존 문서를 교체함
+    Replaced,
+    // 문서를 변환할 수 없어 건너뜀 (기존 문서는 그대로 유지)
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpsertResult {
+    pub id: String,
+    pub outcome: UpsertOutcome,
+    // Failed인 경우의 오류 메시지
+    pub error: Option<String>,
+}
+
+// [BATCH] 여러 문서를 추가하거나 교체하고 문서별 결과를 반환하는 함수
+// add_documents_batch와 달리 변환할 수 없는 문서(필드 타입 불일치 등)가 있어도 나머지 문서는 저장하며,
+// 결과는 docs와 같은 순서이므로 동기화 엔진은 Failed인 문서만 다시 시도하면 됩니다.
+// commit된 문서와 이 배치에서 앞서 추가한 문서를 기준으로 Inserted/Replaced를 구분하며,
+// commit되지 않은 *_no_commit 변경은 확인할 수 없어 Inserted로 표시됩니다.
+pub fn upsert_documents_batch(
+    docs: Vec<Document>,
+    index_name: Option<String>,
+) -> Result<Vec<UpsertResult>, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
+    api.enforce_quota(&mut writer)?;
+
+    // 리로드 정책과 관계없이 마지막 commit 기준으로 기존 문서를 확인
+    api.reader.reload()?;
+    let searcher = api.reader.searcher();
+    let mut added: HashSet<String> = HashSet::new();
+    let mut results = Vec::with_capacity(docs.len());
+
+    for doc in docs {
+        let tantivy_doc = match api.to_tantivy_doc(&doc) {
+            Ok(tantivy_doc) => tantivy_doc,
+            Err(e) => {
+                results.push(UpsertResult {
+                    id: doc.id,
+                    outcome: UpsertOutcome::Failed,
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+
+        let id_term = Term::from_field_text(api.id_field, &doc.id);
+        let exists = added.contains(&doc.id)
+            || searcher.search(
+                &TermQuery::new(id_term.clone(), IndexRecordOption::Basic),
+                &Count,
+            )? > 0;
+        writer.delete_term(id_term);
+        writer.add_document(tantivy_doc)?;
+
+        let outcome = if exists {
+            UpsertOutcome::Replaced
+        } else {
+            UpsertOutcome::Inserted
+        };
+        added.insert(doc.id.clone());
+        results.push(UpsertResult {
+            id: doc.id,
+            outcome,
+            error: None,
+        });
+    }
+
+    writer.commit()?;
+
+    Ok(results)
+}
+
+// 대량 색인 진행 상황
+#[derive(Debug, Clone)]
+pub struct BatchProgress {
+    // 지금까지 색인된 문서 수
+    pub processed: usize,
+    pub total: usize,
+    // commit까지 완료되었는지 여부 (마지막 이벤트에서만 true)
+    pub done: bool,
 }
 
-// Tantivy의 핵심 로직을 관리하는 구조체
-struct TantivyApi {
-    index: Index,
-    writer: Mutex<IndexWriter>,
-    reader: IndexReader,
-    schema: Schema,
-    id_field: Field,
-    text_field: Field,
+// 진행 상황을 보고하는 문서 단위
+const BATCH_PROGRESS_INTERVAL: usize = 1000;
+
+// 핸들로 문서를 가져올 수 있도록 유지하는 최근 searcher 수
+// 오래된 searcher는 병합되어 삭제된 세그먼트 파일을 계속 열어 두므로 개수를 제한합니다.
+const HANDLE_SEARCHER_LIMIT: usize = 4;
+
+// search_documents_stream에서 한 번에 보내는 기본 결과 수
+const DEFAULT_STREAM_CHUNK_SIZE: usize = 50;
+
+// [BATCH] 여러 문서를 추가하면서 진행 상황을 Stream으로 보고하는 함수
+// 10만 건 이상의 대량 가져오기에서 UI에 진행률을 표시할 때 사용합니다.
+// 1000건마다 진행 상황을 보내고, commit이 끝나면 done = true인 이벤트를 보낸 뒤 Stream이 닫힙니다.
+// 중간에 실패하거나 cancel_operation으로 취소하면 추가하던 문서를 모두 되돌리고 Stream에 에러가 전달됩니다.
+// *_no_commit 함수로 쌓인 변경은 시작할 때 먼저 commit하므로 되돌려도 함께 버려지지 않습니다.
+pub fn add_documents_batch_streamed(
+    docs: Vec<Document>,
+    sink: StreamSink<BatchProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    let operation = Operation::start(operation_id);
+    let total = docs.len();
+
+    let mut writer = api.commit_writer()?;
+    api.commit_pending_locked(&mut writer)?;
+    api.enforce_quota(&mut writer)?;
+
+    let result = (|| -> Result<()> {
+        for (i, doc) in docs.iter().enumerate() {
+            operation.check()?;
+            let tantivy_doc = api.to_tantivy_doc(doc)?;
+
+            // 기존 문서가 있다면 삭제 (Update-or-Insert)
+            let id_term = Term::from_field_text(api.id_field, &doc.id);
+            writer.delete_term(id_term);
+
+            writer.add_document(tantivy_doc)?;
+
+            let processed = i + 1;
+            if processed % BATCH_PROGRESS_INTERVAL == 0 && processed < total {
+                // Dart 쪽에서 구독을 취소해도 색인은 끝까지 진행
+                let _ = sink.add(BatchProgress {
+                    processed,
+                    total,
+                    done: false,
+                });
+            }
+        }
+
+        // 모든 문서를 추가한 후 한 번만 commit
+        writer.commit()?;
+        Ok(())
+    })();
+
+    // 일부 문서만 남아 다음 commit에 반영되지 않도록 되돌림
+    if let Err(e) = result {
+        writer.rollback()?;
+        api.pending.lock().unwrap().clear();
+        return Err(e.into());
+    }
+
+    let _ = sink.add(BatchProgress {
+        processed: total,
+        total,
+        done: true,
+    });
+
+    Ok(())
+}
+
+// JSON Lines 가져오기 진행 상황
+// 파일을 끝까지 읽기 전에는 문서 수를 알 수 없으므로 진행률은 바이트 단위로 계산합니다.
+#[derive(Debug, Clone)]
+pub struct ImportProgress {
+    // 지금까지 색인된 문서 수
+    pub imported: usize,
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    // commit까지 완료되었는지 여부 (마지막 이벤트에서만 true)
+    pub done: bool,
 }
 
-// 전역 상태를 Lazy와 Arc<Mutex<...>>로 안전하게 관리
-static STATE: Lazy<Arc<Mutex<Option<TantivyApi>>>> = Lazy::new(|| Arc::new(Mutex::new(None)));
+// [BATCH] JSON Lines 파일을 Rust에서 직접 읽어 색인하는 함수
+// 한 줄에 JSON 문서 하나 (export_documents의 출력 형식)이며, 빈 줄은 건너뜁니다.
+// 문서를 Dart에서 Rust로 넘기지 않으므로 10만 건 이상의 가져오기에 적합합니다.
+// 1000건마다 진행 상황을 보내고, 모두 commit되면 done = true인 이벤트를 보냅니다.
+// 잘못된 줄이 있거나 취소되면 지금까지 추가한 문서를 되돌리고 Stream에 에러가 전달됩니다.
+// 되돌릴 때 이전의 *_no_commit 변경까지 버리지 않도록 쌓인 변경은 시작할 때 먼저 commit합니다.
+pub fn import_documents(
+    path: String,
+    sink: StreamSink<ImportProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    let operation = Operation::start(operation_id);
 
-// Tantivy 인덱스를 초기화하는 함수
-// 초기화는 빠른 작업이므로 sync로 처리
-#[flutter_rust_bridge::frb(sync)]
-pub fn init_tantivy(dir_path: String) -> Result<()> {
-    let mut state_lock = STATE.lock().unwrap();
-    if state_lock.is_some() {
-        // 이미 초기화된 경우
-        return Ok(());
+    let file = File::open(&path)?;
+    let total_bytes = file.metadata()?.len();
+    let reader = BufReader::new(file);
+
+    let mut writer = api.commit_writer()?;
+    api.commit_pending_locked(&mut writer)?;
+    api.enforce_quota(&mut writer)?;
+
+    let mut imported = 0;
+    let mut bytes_read = 0;
+    let result = (|| -> Result<()> {
+        for (i, line) in reader.lines().enumerate() {
+            operation.check()?;
+            let line = line?;
+            // 줄바꿈 문자 포함
+            bytes_read += line.len() as u64 + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (id_term, tantivy_doc) = api
+                .parse_json_doc(&line)
+                .map_err(|e| anyhow!("Invalid JSON document at line {}: {}", i + 1, e))?;
+            writer.delete_term(id_term);
+            writer.add_document(tantivy_doc)?;
+
+            imported += 1;
+            if imported % BATCH_PROGRESS_INTERVAL == 0 {
+                // Dart 쪽에서 구독을 취소해도 색인은 끝까지 진행
+                let _ = sink.add(ImportProgress {
+                    imported,
+                    bytes_read: bytes_read.min(total_bytes),
+                    total_bytes,
+                    done: false,
+                });
+            }
+        }
+        writer.commit()?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        writer.rollback()?;
+        api.pending.lock().unwrap().clear();
+        return Err(e.into());
     }
 
-    let index_dir = PathBuf::from(dir_path);
-    std::fs::create_dir_all(&index_dir)?;
+    let _ = sink.add(ImportProgress {
+        imported,
+        bytes_read: total_bytes,
+        total_bytes,
+        done: true,
+    });
 
-    let (index, schema) = if index_dir.join("meta.json").exists() {
-        // 기존 인덱스 열기
-        let index = Index::open_in_dir(&index_dir)?;
-        let schema = index.schema();
-        (index, schema)
-    } else {
-        // 새 인덱스 생성
-        let mut schema_builder = Schema::builder();
-        // ID 필드는 고유 식별자로 사용되며, 검색 가능하고 저장됩니다.
-        schema_builder.add_text_field("id", STRING | STORED);
-        // Text 필드는 전문 검색을 위해 사용됩니다.
-        schema_builder.add_text_field("text", TEXT | STORED);
-        let schema = schema_builder.build();
-        let index = Index::create_in_dir(&index_dir, schema.clone())?;
-        (index, schema)
-    };
+    Ok(())
+}
 
-    let id_field = schema.get_field("id").map_err(|_| anyhow!("'id' field not found"))?;
-    let text_field = schema.get_field("text").map_err(|_| anyhow!("'text' field not found"))?;
+// 디렉터리 색인 진행 상황
+#[derive(Debug, Clone)]
+pub struct DirectoryIndexProgress {
+    // 지금까지 색인된 파일 수
+    pub indexed: usize,
+    // 텍스트를 추출하지 못해 건너뛴 파일 수
+    pub skipped: usize,
+    // 색인할 전체 파일 수
+    pub total: usize,
+    // 마지막으로 처리한 파일 경로 (done 이벤트에서는 None)
+    pub path: Option<String>,
+    // commit까지 완료되었는지 여부 (마지막 이벤트에서만 true)
+    pub done: bool,
+}
 
-    let writer = index.writer(50_000_000)?; // 50MB heap
+// [BATCH] 디렉터리의 파일을 Rust에서 직접 읽어 색인하는 함수 (로컬 파일 검색용)
+// 하위 디렉터리까지 globs와 일치하는 파일을 FileDocument로 색인하며, 인덱스는 file_schema_fields()의 필드를 포함해야 합니다.
+// globs가 비어 있으면 index_file이 지원하는 형식의 파일만 색인하고, globs와 일치한 그 외 형식의 파일은 일반 텍스트로 읽습니다.
+// 파일마다 진행 상황을 보내고, 모두 commit되면 done = true인 이벤트를 보냅니다.
+// 읽을 수 없거나 텍스트를 추출하지 못한 파일은 건너뛰며, 취소되면 지금까지 추가한 문서를 되돌리고 Stream에 에러가 전달됩니다.
+// *_no_commit 함수로 쌓인 변경은 시작할 때 먼저 commit하므로 취소해도 함께 버려지지 않습니다.
+pub fn index_directory(
+    path: String,
+    globs: Vec<String>,
+    sink: StreamSink<DirectoryIndexProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    api.check_file_fields()?;
+    let operation = Operation::start(operation_id);
 
-    // Reader를 생성하고 OnCommit 정책으로 자동 리로드
-    let reader = index
-        .reader_builder()
-        .reload_policy(ReloadPolicy::Manual)
-        .try_into()?;
+    let files = directory_files(&path, &globs)?;
+    let total = files.len();
 
-    let api = TantivyApi {
-        index,
-        writer: Mutex::new(writer),
-        reader,
-        schema,
-        id_field,
-        text_field,
-    };
+    let mut writer = api.commit_writer()?;
+    api.commit_pending_locked(&mut writer)?;
+    api.enforce_quota(&mut writer)?;
+
+    let mut indexed = 0;
+    let mut skipped = 0;
+    let result = (|| -> Result<()> {
+        for (file, mime_type) in &files {
+            operation.check()?;
+            match FileDocument::read(file, mime_type) {
+                Ok(file_doc) => {
+                    let doc = file_doc.into_document();
+                    let tantivy_doc = api.to_tantivy_doc(&doc)?;
+                    writer.delete_term(Term::from_field_text(api.id_field, &doc.id));
+                    writer.add_document(tantivy_doc)?;
+                    indexed += 1;
+                }
+                Err(_) => skipped += 1,
+            }
+            // Dart 쪽에서 구독을 취소해도 색인은 끝까지 진행
+            let _ = sink.add(DirectoryIndexProgress {
+                indexed,
+                skipped,
+                total,
+                path: Some(file.to_string_lossy().into_owned()),
+                done: false,
+            });
+        }
+        writer.commit()?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        writer.rollback()?;
+        api.pending.lock().unwrap().clear();
+        return Err(e.into());
+    }
 
-    *state_lock = Some(api);
+    let _ = sink.add(DirectoryIndexProgress {
+        indexed,
+        skipped,
+        total,
+        path: None,
+        done: true,
+    });
 
     Ok(())
 }
 
-// [CREATE] 새 문서를 추가하는 함수
-// 즉시 commit하므로 단일 문서 추가에 적합
-// 대량 추가는 add_documents_batch 사용 권장
-pub fn add_document(doc: Document) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// 디렉터리 동기화 진행 상황
+#[derive(Debug, Clone)]
+pub struct DirectorySyncProgress {
+    // 새로 색인된 파일 수
+    pub added: usize,
+    // 내용이 바뀌어 다시 색인된 파일 수
+    pub updated: usize,
+    // 파일이 없어져 삭제된 문서 수 (done 이벤트에서만 채워짐)
+    pub removed: usize,
+    // 바뀌지 않아 건너뛴 파일 수
+    pub unchanged: usize,
+    // 텍스트를 추출하지 못해 건너뛴 파일 수 (기존 문서는 유지)
+    pub skipped: usize,
+    // 디렉터리에서 찾은 전체 파일 수
+    pub total: usize,
+    // 마지막으로 처리한 파일 경로 (done 이벤트에서는 None)
+    pub path: Option<String>,
+    // commit까지 완료되었는지 여부 (마지막 이벤트에서만 true)
+    pub done: bool,
+}
 
-    let mut writer = api.writer.lock().unwrap();
+// [BATCH] index_directory로 색인한 디렉터리를 현재 파일들과 맞추는 함수
+// 크기와 수정 시각이 저장된 값과 같은 파일은 건너뛰고, 다르면 내용 해시를 비교하여 바뀐 파일만 다시 추출하여 색인합니다.
+// 내용이 같고 수정 시각만 바뀐 파일은 저장된 본문으로 파일 정보만 갱신합니다.
+// path 아래의 문서 중 이번 탐색에서 찾지 못한 파일(삭제되었거나 globs와 일치하지 않는 파일)의 문서는 삭제합니다.
+// 진행 상황, 취소와 쌓인 변경의 처리는 index_directory와 같으며, 모든 변경은 마지막에 한 번에 commit됩니다.
+pub fn sync_directory(
+    path: String,
+    globs: Vec<String>,
+    sink: StreamSink<DirectorySyncProgress>,
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    api.check_file_fields()?;
+    let operation = Operation::start(operation_id);
 
-    // 추가하기 전에 동일한 ID의 문서가 있다면 삭제 (Update-or-Insert)
-    let id_term = Term::from_field_text(api.id_field, &doc.id);
-    writer.delete_term(id_term.clone());
+    let files = directory_files(&path, &globs)?;
+    let mut stored = api.file_documents_under(Path::new(&path))?;
 
-    let mut tantivy_doc = TantivyDocument::new();
-    tantivy_doc.add_text(api.id_field, &doc.id);
-    tantivy_doc.add_text(api.text_field, &doc.text);
+    let mut writer = api.commit_writer()?;
+    api.commit_pending_locked(&mut writer)?;
+    api.enforce_quota(&mut writer)?;
 
-    writer.add_document(tantivy_doc)?;
-    writer.commit()?;
+    let mut progress = DirectorySyncProgress {
+        added: 0,
+        updated: 0,
+        removed: 0,
+        unchanged: 0,
+        skipped: 0,
+        total: files.len(),
+        path: None,
+        done: false,
+    };
+    let result = (|| -> Result<()> {
+        for (file, mime_type) in &files {
+            operation.check()?;
+            let file_path = file.to_string_lossy().into_owned();
+            // 추출하지 못한 파일은 기존 문서를 그대로 유지
+            let doc = match compare_file(file, mime_type, stored.remove(&file_path)) {
+                Ok(FileChange::Added(doc)) => {
+                    progress.added += 1;
+                    Some(doc)
+                }
+                Ok(FileChange::Updated(doc)) => {
+                    progress.updated += 1;
+                    Some(doc)
+                }
+                Ok(FileChange::Unchanged(doc)) => {
+                    progress.unchanged += 1;
+                    doc
+                }
+                Err(_) => {
+                    progress.skipped += 1;
+                    None
+                }
+            };
+            if let Some(doc) = doc {
+                let doc = doc.into_document();
+                let tantivy_doc = api.to_tantivy_doc(&doc)?;
+                writer.delete_term(Term::from_field_text(api.id_field, &doc.id));
+                writer.add_document(tantivy_doc)?;
+            }
+            progress.path = Some(file_path);
+            // Dart 쪽에서 구독을 취소해도 동기화는 끝까지 진행
+            let _ = sink.add(progress.clone());
+        }
+        for id in stored.keys() {
+            writer.delete_term(Term::from_field_text(api.id_field, id));
+        }
+        progress.removed = stored.len();
+        writer.commit()?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        writer.rollback()?;
+        api.pending.lock().unwrap().clear();
+        return Err(e.into());
+    }
+
+    progress.path = None;
+    progress.done = true;
+    let _ = sink.add(progress);
 
     Ok(())
 }
 
-// [READ] 쿼리로 문서를 검색하는 함수
-pub fn search_documents(query: String, top_k: usize) -> Result<Vec<SearchResult>> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// sync_directory에서 파일을 저장된 문서와 비교한 결과
+enum FileChange {
+    Added(FileDocument),
+    Updated(FileDocument),
+    // 내용이 바뀌지 않은 파일 (크기나 수정 시각만 바뀌었으면 갱신할 문서)
+    Unchanged(Option<FileDocument>),
+}
 
-    // reader를 리로드하여 최신 변경사항을 반영
-    api.reader.reload()?;
+fn compare_file(
+    file: &Path,
+    mime_type: &str,
+    previous: Option<FileDocument>,
+) -> Result<FileChange> {
+    let Some(mut previous) = previous else {
+        return Ok(FileChange::Added(FileDocument::read(file, mime_type)?));
+    };
+    let (size, modified) = file_size_and_modified(file)?;
+    if size == previous.size && modified == previous.modified {
+        return Ok(FileChange::Unchanged(None));
+    }
+    if content_hash(file)? == previous.content_hash {
+        previous.size = size;
+        previous.modified = modified;
+        return Ok(FileChange::Unchanged(Some(previous)));
+    }
+    Ok(FileChange::Updated(FileDocument::read(file, mime_type)?))
+}
 
-    // 전역 reader 재사용
-    let searcher = api.reader.searcher();
+// [BATCH] 여러 문서를 한 번에 삭제하는 함수 (성능 최적화)
+pub fn delete_documents_batch(
+    ids: Vec<String>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
-    let query_parser = QueryParser::for_index(&api.index, vec![api.text_field]);
-    let query = query_parser.parse_query(&query)?;
+    let mut writer = api.commit_writer()?;
 
-    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k))?;
+    for id in ids {
+        let id_term = Term::from_field_text(api.id_field, &id);
+        writer.delete_term(id_term);
+    }
 
-    let mut results = Vec::new();
-    for (score, doc_address) in top_docs {
-        let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
-        let id = retrieved_doc.get_first(api.id_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
-        let text = retrieved_doc.get_first(api.text_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
+    // 모든 삭제 작업 후 한 번만 commit
+    writer.commit()?;
 
-        results.push(SearchResult {
-            score,
-            doc: Document { id, text },
-        });
+    Ok(())
+}
+
+// [DELETE] 쿼리와 일치하는 모든 문서를 삭제하는 함수
+// ID를 먼저 조회하지 않고 "source:imported AND date:<2020-01-01T00:00:00Z"와 같은 조건으로 한 번에 삭제합니다.
+// *_no_commit 함수로 쌓인 변경을 먼저 commit한 뒤 삭제하며, 삭제한 문서 수를 반환합니다.
+// 실수로 모든 문서를 지우지 않도록 빈 쿼리는 허용하지 않으며, 전체 삭제는 clear_index를 사용합니다.
+pub fn delete_by_query(query: String, index_name: Option<String>) -> Result<usize, TantivyError> {
+    if query.trim().is_empty() {
+        return Err(
+            anyhow!("Query must not be empty; use clear_index to delete all documents").into(),
+        );
     }
+    let api = get_index(index_name)?;
 
-    Ok(results)
+    let query = api.parse_query(&query)?;
+
+    let mut writer = api.commit_writer()?;
+    api.commit_pending_locked(&mut writer)?;
+
+    // writer 잠금을 잡고 있으므로 센 뒤 삭제할 때까지 다른 호출자가 문서를 추가할 수 없음
+    api.reader.reload()?;
+    let deleted = api.reader.searcher().search(&*query, &Count)?;
+    if deleted > 0 {
+        writer.delete_query(query)?;
+        writer.commit()?;
+    }
+
+    Ok(deleted)
 }
 
-// [READ] ID로 특정 문서를 가져오는 함수
-// ID 조회는 비교적 빠른 작업이므로 sync로 처리
+// [UTILITY] 열린 인덱스의 스키마를 FieldDef 목록으로 조회하는 함수
+// 디스크에 있는 인덱스의 스키마를 그대로 보여주므로, 앱이 기대하는 스키마와 비교하여
+// 이전 버전의 스키마로 만들어진 인덱스를 감지하거나 문서를 추가하기 전에 검증할 수 있습니다.
+// 반환된 목록은 init_tantivy_with_schema / reindex_with_schema에 그대로 전달할 수 있습니다.
+// 메타데이터, 버전, 원본 ID용 내부 필드는 포함되지 않습니다.
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_document_by_id(id: String) -> Result<Option<Document>> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+pub fn get_schema(index_name: Option<String>) -> Result<Vec<FieldDef>, TantivyError> {
+    let api = get_index(index_name)?;
 
-    // 전역 reader 재사용
-    let searcher = api.reader.searcher();
+    // 섀도 필드와 언어 감지 필드는 별도 항목 대신 원본 필드의 shadow_tokenizers, detect_languages로 표시
+    let shadows = shadow_fields(&api.schema);
+    let languages = language_fields(&api.schema);
+    let mut fields = Vec::new();
+    for (field, entry) in api.schema.fields() {
+        if entry.name() == METADATA_FIELD
+            || entry.name() == VERSION_FIELD
+            || entry.name() == PARENT_FIELD
+            || shadows.iter().any(|&(_, shadow)| shadow == field)
+            || languages.iter().any(|language_field| {
+                language_field
+                    .targets
+                    .iter()
+                    .any(|&(_, target)| target == field)
+            })
+        {
+            continue;
+        }
+        let Some(mut field_def) = field_def(entry) else {
+            continue;
+        };
+        for &(_, shadow) in shadows.iter().filter(|&&(source, _)| source == field) {
+            if let Some(tokenizer) = text_tokenizer(api.schema.get_field_entry(shadow)) {
+                field_def.shadow_tokenizers.push(tokenizer);
+            }
+        }
+        for language_field in languages.iter().filter(|l| l.source == field) {
+            for &(lang, _) in &language_field.targets {
+                if let Some(language) = TextLanguage::ALL
+                    .into_iter()
+                    .find(|language| language.detected_lang() == lang)
+                {
+                    field_def.detect_languages.push(language);
+                }
+            }
+        }
+        fields.push(field_def);
+    }
 
-    let id_term = Term::from_field_text(api.id_field, &id);
-    let query = tantivy::query::TermQuery::new(id_term, IndexRecordOption::Basic);
+    Ok(fields)
+}
 
-    let top_docs = searcher.search(&query, &TopDocs::with_limit(1))?;
+// Tantivy 필드 정의를 Flutter의 FieldDef로 변환 (build_schema의 역변환)
+fn field_def(entry: &FieldEntry) -> Option<FieldDef> {
+    let mut tokenizer = None;
+    let mut record_option = None;
+    let field_type = match entry.field_type() {
+        tantivy::schema::FieldType::Str(options) => match options.get_indexing_options() {
+            Some(indexing) if indexing.tokenizer() == GEO_TOKENIZER => FieldType::GeoPoint,
+            Some(indexing) => {
+                let (field_type, default_option) = if indexing.tokenizer() == "raw" {
+                    (FieldType::String, IndexRecordOption::Basic)
+                } else {
+                    if indexing.tokenizer() != "default" {
+                        tokenizer = Some(indexing.tokenizer().to_string());
+                    }
+                    (FieldType::Text, IndexRecordOption::WithFreqsAndPositions)
+                };
+                // 기본값과 같으면 생략하여 build_schema에 그대로 전달할 수 있게 함
+                if indexing.index_option() != default_option {
+                    record_option = Some(RecordOption::from_index_option(indexing.index_option()));
+                }
+                field_type
+            }
+            None => FieldType::Text,
+        },
+        tantivy::schema::FieldType::I64(_) => FieldType::I64,
+        tantivy::schema::FieldType::U64(_) => FieldType::U64,
+        tantivy::schema::FieldType::F64(_) => FieldType::F64,
+        tantivy::schema::FieldType::Date(_) => FieldType::Date,
+        tantivy::schema::FieldType::Facet(_) => FieldType::Facet,
+        tantivy::schema::FieldType::Bytes(options) if options.is_fast() => FieldType::Vector,
+        tantivy::schema::FieldType::Bytes(_) => FieldType::Bytes,
+        tantivy::schema::FieldType::IpAddr(_) => FieldType::IpAddr,
+        tantivy::schema::FieldType::Bool(_) => FieldType::Bool,
+        _ => return None,
+    };
 
-    if let Some((_, doc_address)) = top_docs.first() {
-        let retrieved_doc = searcher.doc::<TantivyDocument>(*doc_address)?;
-        let text = retrieved_doc.get_first(api.text_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
+    Some(FieldDef {
+        name: entry.name().to_string(),
+        field_type,
+        stored: entry.is_stored(),
+        indexed: entry.is_indexed(),
+        // Facet, GeoPoint 필드는 항상 fast field로 저장되므로 FieldDef.fast와 무관
+        fast: !matches!(field_type, FieldType::Facet | FieldType::GeoPoint) && entry.is_fast(),
+        tokenizer,
+        record_option,
+        shadow_tokenizers: Vec::new(),
+        detect_languages: Vec::new(),
+    })
+}
 
-        return Ok(Some(Document { id, text }));
-    }
+// [UTILITY] 인덱스 통계를 조회하는 함수
+pub fn get_index_stats(index_name: Option<String>) -> Result<IndexStats, TantivyError> {
+    let api = get_index(index_name)?;
 
-    Ok(None)
+    api.reader.reload()?;
+    let searcher = api.reader.searcher();
+
+    let num_deleted_docs = searcher
+        .segment_readers()
+        .iter()
+        .map(|segment_reader| u64::from(segment_reader.num_deleted_docs()))
+        .sum();
+
+    Ok(IndexStats {
+        num_docs: searcher.num_docs(),
+        num_deleted_docs,
+        num_segments: searcher.segment_readers().len() as u64,
+        size_bytes: api.size_bytes()?,
+    })
 }
 
+// [DELETE] 인덱스의 모든 문서를 삭제하는 함수
+// 디렉토리를 지우고 다시 초기화하지 않아도 스키마를 유지한 채 인덱스를 비울 수 있습니다.
+pub fn clear_index(index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
-// [UPDATE] 문서를 업데이트하는 함수
-pub fn update_document(doc: Document) -> Result<()> {
-    // add_document가 내부적으로 delete & add 로직을 수행하므로 그대로 호출
-    add_document(doc)
+    let mut writer = api.commit_writer()?;
+    writer.delete_all_documents()?;
+    writer.commit()?;
+
+    Ok(())
 }
 
-// [DELETE] 문서를 삭제하는 함수
-pub fn delete_document(id: String) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// [UTILITY] 모든 segment를 하나로 병합하는 함수
+// 대량 가져오기 후 호출하면 삭제된 문서가 정리되어 디스크 사용량이 줄고 검색이 빨라집니다.
+// commit된 segment만 병합되며, 병합과 이전 파일 정리가 끝날 때까지 기다립니다.
+// 취소는 병합 시작 전과 파일 정리 전에 확인하며, 이미 시작된 병합은 끝까지 진행됩니다.
+pub fn optimize_index(
+    operation_id: Option<u64>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+    let operation = Operation::start(operation_id);
 
     let mut writer = api.writer.lock().unwrap();
-    let id_term = Term::from_field_text(api.id_field, &id);
+    operation.check()?;
+    let segment_ids = api.index.searchable_segment_ids()?;
+    if segment_ids.len() > 1 {
+        writer.merge(&segment_ids).wait()?;
+    }
+    operation.check()?;
+    writer.garbage_collect_files().wait()?;
 
-    writer.delete_term(id_term);
+    Ok(())
+}
+
+// [UTILITY] 명시적으로 commit을 수행하는 함수
+// add_document_no_commit과 함께 사용하여 수동으로 트랜잭션 제어
+#[flutter_rust_bridge::frb(sync)]
+pub fn commit(index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
+
+    let mut writer = api.commit_writer()?;
     writer.commit()?;
 
     Ok(())
 }
 
-// [BATCH] 여러 문서를 한 번에 추가하는 함수 (성능 최적화)
-pub fn add_documents_batch(docs: Vec<Document>) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// [UTILITY] 마지막 commit 이후의 변경사항을 취소하는 함수
+// add_document_no_commit 등으로 쌓인 작업을 사용자가 가져오기를 취소했을 때 버릴 수 있습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rollback(index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
-    let mut writer = api.writer.lock().unwrap();
+    let mut writer = api.commit_writer()?;
+    writer.rollback()?;
+    api.pending.lock().unwrap().clear();
 
-    for doc in docs {
-        // 기존 문서가 있다면 삭제 (Update-or-Insert)
-        let id_term = Term::from_field_text(api.id_field, &doc.id);
-        writer.delete_term(id_term);
+    Ok(())
+}
 
-        let mut tantivy_doc = TantivyDocument::new();
-        tantivy_doc.add_text(api.id_field, &doc.id);
-        tantivy_doc.add_text(api.text_field, &doc.text);
+// [UTILITY] 검색에 마지막 commit을 반영하는 함수
+// reload_policy가 Manual이면 commit 후 이 함수를 호출해야 검색 결과에 반영됩니다.
+// 여러 문서를 commit하는 동안에는 이전 결과를 유지하고, 모두 끝난 뒤 한 번에 반영할 수 있습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn refresh_reader(index_name: Option<String>) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
-        writer.add_document(tantivy_doc)?;
+    api.reader.reload()?;
+
+    Ok(())
+}
+
+// 자동 commit 조건 (생략한 조건은 사용하지 않으며, 둘 중 먼저 만족하는 조건으로 commit)
+#[derive(Debug, Clone)]
+pub struct AutoCommitPolicy {
+    // commit되지 않은 변경이 이 개수에 도달하면 commit
+    pub max_pending_docs: Option<usize>,
+    // commit되지 않은 첫 변경 후 이 시간(밀리초)이 지나면 commit
+    pub max_delay_ms: Option<u64>,
+}
+
+// *_no_commit 함수로 쌓인, 아직 commit되지 않은 변경
+struct PendingChanges {
+    policy: Option<AutoCommitPolicy>,
+    count: usize,
+    // 첫 변경 시각
+    since: Option<Instant>,
+    // 마지막 변경의 opstamp (writer의 commit opstamp가 이 이상이면 다른 함수에서 commit된 것)
+    last_opstamp: u64,
+    // set_auto_commit을 호출할 때마다 증가 (이전 설정의 자동 commit 스레드를 종료)
+    generation: u64,
+}
+
+impl PendingChanges {
+    fn new() -> Self {
+        PendingChanges {
+            policy: None,
+            count: 0,
+            since: None,
+            last_opstamp: 0,
+            generation: 0,
+        }
     }
 
-    // 모든 문서를 추가한 후 한 번만 commit
-    writer.commit()?;
+    // 다른 함수의 commit으로 변경이 이미 반영되었으면 초기화
+    fn sync(&mut self, writer: &IndexWriter) {
+        if writer.commit_opstamp() >= self.last_opstamp {
+            self.clear();
+        }
+    }
 
-    Ok(())
+    fn clear(&mut self) {
+        self.count = 0;
+        self.since = None;
+    }
 }
 
-// [BATCH] 여러 문서를 한 번에 삭제하는 함수 (성능 최적화)
-pub fn delete_documents_batch(ids: Vec<String>) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// 자동 commit 스레드가 시간 조건을 확인하는 최소 간격
+const AUTO_COMMIT_MIN_TICK: Duration = Duration::from_millis(10);
 
-    let mut writer = api.writer.lock().unwrap();
+// [UTILITY] 자동 commit을 설정하는 함수 (None이면 자동 commit 해제)
+// add_document_no_commit, delete_document_no_commit으로 쌓인 변경을 개수나 시간 조건에 따라 commit하므로
+// Dart에서 직접 타이머로 commit하지 않아도 됩니다. batch 중에는 batch가 끝날 때까지 commit하지 않습니다.
+// 설정은 인덱스를 닫거나 다시 열면 해제됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_auto_commit(
+    policy: Option<AutoCommitPolicy>,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
-    for id in ids {
-        let id_term = Term::from_field_text(api.id_field, &id);
-        writer.delete_term(id_term);
+    if let Some(policy) = &policy {
+        if policy.max_pending_docs == Some(0) {
+            return Err(anyhow!("max_pending_docs must be greater than 0").into());
+        }
+        if policy.max_delay_ms == Some(0) {
+            return Err(anyhow!("max_delay_ms must be greater than 0").into());
+        }
     }
 
-    // 모든 삭제 작업 후 한 번만 commit
-    writer.commit()?;
+    let mut pending = api.pending.lock().unwrap();
+    pending.generation += 1;
+    let generation = pending.generation;
+    let delay = policy
+        .as_ref()
+        .and_then(|policy| policy.max_delay_ms)
+        .map(Duration::from_millis);
+    pending.policy = policy;
+    drop(pending);
+
+    // 인덱스가 닫히거나 설정이 바뀌면 스레드가 종료됨
+    if let Some(delay) = delay {
+        let index = Arc::downgrade(&api);
+        let tick = (delay / 10).max(AUTO_COMMIT_MIN_TICK);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(tick);
+            let Some(api) = index.upgrade() else {
+                return;
+            };
+            if api.pending.lock().unwrap().generation != generation {
+                return;
+            }
+            // 실패하면 변경이 남아 있으므로 다음 확인이나 다음 commit에서 다시 시도
+            let _ = api.commit_if_due(delay);
+        });
+    }
 
     Ok(())
 }
 
-// [UTILITY] 명시적으로 commit을 수행하는 함수
-// add_document_no_commit과 함께 사용하여 수동으로 트랜잭션 제어
+// batch ID 발급용 카운터
+static NEXT_BATCH_ID: AtomicU64 = AtomicU64::new(1);
+
+// [UTILITY] batch를 시작하는 함수
+// commit_batch 또는 abort_batch를 호출할 때까지 다른 호출자의 commit(add_document, commit 등)을 거부하므로
+// *_no_commit 함수로 쌓은 변경사항이 중간에 commit되지 않습니다. 반환된 batch ID로 batch를 종료합니다.
+// begin_batch 이전에 commit되지 않은 변경사항도 이 batch에 포함됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn begin_batch(index_name: Option<String>) -> Result<u64, TantivyError> {
+    let api = get_index(index_name)?;
+
+    let _writer = api.commit_writer()?;
+    let batch_id = NEXT_BATCH_ID.fetch_add(1, Ordering::Relaxed);
+    *api.batch.lock().unwrap() = Some(batch_id);
+
+    Ok(batch_id)
+}
+
+// [UTILITY] batch의 변경사항을 commit하고 batch를 종료하는 함수
+#[flutter_rust_bridge::frb(sync)]
+pub fn commit_batch(batch_id: u64, index_name: Option<String>) -> Result<(), TantivyError> {
+    end_batch(batch_id, index_name, true)
+}
+
+// [UTILITY] batch의 변경사항을 버리고 batch를 종료하는 함수
 #[flutter_rust_bridge::frb(sync)]
-pub fn commit() -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+pub fn abort_batch(batch_id: u64, index_name: Option<String>) -> Result<(), TantivyError> {
+    end_batch(batch_id, index_name, false)
+}
+
+fn end_batch(batch_id: u64, index_name: Option<String>, commit: bool) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
     let mut writer = api.writer.lock().unwrap();
-    writer.commit()?;
+    let mut batch = api.batch.lock().unwrap();
+    if *batch != Some(batch_id) {
+        return Err(TantivyError::new(
+            TantivyErrorKind::InvalidArgument,
+            format!("Batch {} is not active", batch_id),
+        ));
+    }
+
+    if commit {
+        writer.commit()?;
+    } else {
+        writer.rollback()?;
+        api.pending.lock().unwrap().clear();
+    }
+    *batch = None;
 
     Ok(())
 }
 
+// 작업 ID 발급용 카운터
+static NEXT_OPERATION_ID: AtomicU64 = AtomicU64::new(1);
+
+// 진행 중이거나 시작 전인 작업의 취소 여부
+static OPERATIONS: Lazy<Mutex<HashMap<u64, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// [UTILITY] 취소할 수 있는 작업에 전달할 ID를 발급하는 함수
+// 가져오기, 재색인, 병합, 스트리밍 검색 등에 operation_id로 전달한 뒤 cancel_operation으로 취소합니다.
+// 작업마다 새 ID를 사용하며, 작업이 끝나면 ID는 더 이상 유효하지 않습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn new_operation_id() -> u64 {
+    let operation_id = NEXT_OPERATION_ID.fetch_add(1, Ordering::Relaxed);
+    OPERATIONS
+        .lock()
+        .unwrap()
+        .insert(operation_id, Arc::new(AtomicBool::new(false)));
+    operation_id
+}
+
+// [UTILITY] 작업을 취소하는 함수
+// 작업은 다음 확인 지점(문서 하나, 결과 묶음 하나 등)에서 멈추고 Cancelled 오류로 끝납니다.
+// 시작 전에 취소하면 작업이 시작하자마자 취소되며, 이미 끝난 작업의 ID는 무시합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn cancel_operation(operation_id: u64) {
+    if let Some(cancelled) = OPERATIONS.lock().unwrap().get(&operation_id) {
+        cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+// 작업 중에 취소 여부를 확인하는 토큰 (작업이 끝나 drop되면 ID가 해제됨)
+struct Operation {
+    id: Option<u64>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Operation {
+    // operation_id가 없으면 취소되지 않는 작업
+    fn start(operation_id: Option<u64>) -> Self {
+        let cancelled = match operation_id {
+            Some(id) => OPERATIONS
+                .lock()
+                .unwrap()
+                .entry(id)
+                .or_insert_with(|| Arc::new(AtomicBool::new(false)))
+                .clone(),
+            None => Arc::new(AtomicBool::new(false)),
+        };
+        Operation {
+            id: operation_id,
+            cancelled,
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(TantivyError::new(
+                TantivyErrorKind::Cancelled,
+                format!("Operation {} was cancelled", self.id.unwrap_or_default()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Operation {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            OPERATIONS.lock().unwrap().remove(&id);
+        }
+    }
+}
+
 // [CREATE] commit 없이 문서를 추가하는 함수 (고급 사용자용)
 // 여러 작업을 수행한 후 commit()을 호출하여 성능 최적화
-pub fn add_document_no_commit(doc: Document) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+// set_auto_commit으로 자동 commit을 설정하면 조건에 따라 commit됩니다.
+pub fn add_document_no_commit(
+    doc: Document,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
     let mut writer = api.writer.lock().unwrap();
+    api.enforce_quota(&mut writer)?;
+
+    let tantivy_doc = api.to_tantivy_doc(&doc)?;
 
     let id_term = Term::from_field_text(api.id_field, &doc.id);
     writer.delete_term(id_term);
 
-    let mut tantivy_doc = TantivyDocument::new();
-    tantivy_doc.add_text(api.id_field, &doc.id);
-    tantivy_doc.add_text(api.text_field, &doc.text);
-
-    writer.add_document(tantivy_doc)?;
+    let opstamp = writer.add_document(tantivy_doc)?;
+    api.record_pending(&mut writer, opstamp)?;
 
     Ok(())
 }
 
 // [DELETE] commit 없이 문서를 삭제하는 함수 (고급 사용자용)
-pub fn delete_document_no_commit(id: String) -> Result<()> {
-    let state_lock = STATE.lock().unwrap();
-    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+pub fn delete_document_no_commit(
+    id: String,
+    index_name: Option<String>,
+) -> Result<(), TantivyError> {
+    let api = get_index(index_name)?;
 
     let mut writer = api.writer.lock().unwrap();
     let id_term = Term::from_field_text(api.id_field, &id);
 
-    writer.delete_term(id_term);
+    let opstamp = writer.delete_term(id_term);
+    api.record_pending(&mut writer, opstamp)?;
 
     Ok(())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_chunks_ends_sentences_at_full_width_punctuation() {
+        assert_eq!(
+            split_chunks("첫 문장。둘째 문장！셋째？", 6),
+            vec!["첫 문장。", "둘째 문장！", "셋째？"]
+        );
+        assert_eq!(
+            split_chunks("첫 문장。둘째 문장！셋째？", 20),
+            vec!["첫 문장。둘째 문장！셋째？"]
+        );
+    }
+
+    #[test]
+    fn split_chunks_splits_long_sentences() {
+        // 공백이 있으면 max_chars 안의 마지막 공백에서 나눔
+        assert_eq!(
+            split_chunks("aaa bbb ccc ddd eee", 8),
+            vec!["aaa bbb", "ccc ddd", "eee"]
+        );
+        // 공백이 없으면 max_chars 위치에서 나눔
+        assert_eq!(split_chunks("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn split_chunks_counts_characters_not_bytes() {
+        assert_eq!(
+            split_chunks("가나다라마바사", 3),
+            vec!["가나다", "라마바", "사"]
+        );
+        assert_eq!(split_chunks("😀😀😀😀😀", 2), vec!["😀😀", "😀😀", "😀"]);
+        assert_eq!(
+            split_chunks("한국어 문장입니다. 다음 문장.", 11),
+            vec!["한국어 문장입니다.", "다음 문장."]
+        );
+    }
+
+    #[test]
+    fn split_chunks_keeps_empty_text_as_one_chunk() {
+        assert_eq!(split_chunks("", 10), vec![""]);
+        assert_eq!(split_chunks("  \n ", 10), vec![""]);
+    }
+
+    fn millis(year: i64, month: i64, day: i64) -> i64 {
+        days_from_civil(year, month, day) * MILLIS_PER_DAY
+    }
+
+    fn buckets(interval: DateInterval, utc_offset_minutes: i64) -> DateBuckets {
+        DateBuckets {
+            interval,
+            offset_ms: utc_offset_minutes * 60_000,
+        }
+    }
+
+    #[test]
+    fn civil_days_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1900, 1, 1), -25_567);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        for days in (-800_000..800_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn date_buckets_follow_month_and_year_boundaries() {
+        let month = buckets(DateInterval::Month, 0);
+        assert_eq!(
+            month.bucket_start(millis(2024, 2, 29) + 13 * MILLIS_PER_HOUR),
+            millis(2024, 2, 1)
+        );
+        assert_eq!(
+            month.bucket_start(millis(2024, 3, 1) - 1),
+            millis(2024, 2, 1)
+        );
+        assert_eq!(month.next_bucket(millis(2024, 2, 1)), millis(2024, 3, 1));
+        assert_eq!(month.next_bucket(millis(2023, 12, 1)), millis(2024, 1, 1));
+
+        let year = buckets(DateInterval::Year, 0);
+        assert_eq!(
+            year.bucket_start(millis(2024, 1, 1) - 1),
+            millis(2023, 1, 1)
+        );
+        assert_eq!(year.bucket_start(millis(2024, 1, 1)), millis(2024, 1, 1));
+        assert_eq!(year.next_bucket(millis(2023, 1, 1)), millis(2024, 1, 1));
+
+        // 현지 시각(UTC+9)으로는 이미 다음 달, 다음 해
+        let offset = 9 * MILLIS_PER_HOUR;
+        let local_new_year = millis(2024, 1, 1) - offset;
+        assert_eq!(
+            buckets(DateInterval::Month, 540).bucket_start(local_new_year),
+            local_new_year
+        );
+        assert_eq!(
+            buckets(DateInterval::Year, 540).bucket_start(local_new_year - 1),
+            millis(2023, 1, 1) - offset
+        );
+        assert_eq!(
+            buckets(DateInterval::Month, 540).next_bucket(millis(2023, 12, 1) - offset),
+            local_new_year
+        );
+    }
+
+    #[test]
+    fn date_buckets_before_1970() {
+        let date = millis(1969, 12, 31) + MILLIS_PER_HOUR;
+        assert_eq!(
+            buckets(DateInterval::Hour, 0).bucket_start(-1),
+            -MILLIS_PER_HOUR
+        );
+        assert_eq!(
+            buckets(DateInterval::Day, 0).bucket_start(date),
+            millis(1969, 12, 31)
+        );
+        // 1969-12-31은 수요일
+        assert_eq!(
+            buckets(DateInterval::Week, 0).bucket_start(date),
+            millis(1969, 12, 29)
+        );
+        assert_eq!(
+            buckets(DateInterval::Month, 0).bucket_start(date),
+            millis(1969, 12, 1)
+        );
+        assert_eq!(
+            buckets(DateInterval::Year, 0).bucket_start(date),
+            millis(1969, 1, 1)
+        );
+        assert_eq!(
+            buckets(DateInterval::Month, 0).next_bucket(millis(1969, 12, 1)),
+            0
+        );
+        assert_eq!(
+            buckets(DateInterval::Month, 0).bucket_start(millis(1900, 3, 1) - 1),
+            millis(1900, 2, 1)
+        );
+        assert_eq!(
+            buckets(DateInterval::Year, 0).next_bucket(millis(1899, 1, 1)),
+            millis(1900, 1, 1)
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use jieba_rs::{Jieba, TokenizeMode};
+use once_cell::sync::Lazy;
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+// 사전을 불러오는 데 시간이 걸리므로 처음 사용할 때 한 번만 생성
+static JIEBA: Lazy<Jieba> = Lazy::new(Jieba::new);
+
+// jieba로 중국어 문장을 단어 단위로 분리하는 토크나이저 (간체/번체 모두 지원)
+// 검색 모드로 분리하므로 긴 단어는 그 안의 짧은 단어도 함께 색인됩니다.
+// 예: "中华人民共和国" -> "中华", "华人", "人民", "共和", "共和国", "中华人民共和国"
+#[derive(Clone, Default)]
+pub(crate) struct ChineseTokenizer {
+    tokens: Vec<Token>,
+}
+
+pub(crate) struct ChineseTokenStream<'a> {
+    tokens: &'a mut Vec<Token>,
+    next: usize,
+}
+
+impl Tokenizer for ChineseTokenizer {
+    type TokenStream<'a> = ChineseTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> ChineseTokenStream<'a> {
+        self.tokens.clear();
+        tokenize(text, &mut self.tokens);
+        ChineseTokenStream {
+            tokens: &mut self.tokens,
+            next: 0,
+        }
+    }
+}
+
+impl TokenStream for ChineseTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        if self.next < self.tokens.len() {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.next - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.next - 1]
+    }
+}
+
+fn tokenize(text: &str, tokens: &mut Vec<Token>) {
+    // jieba는 문자 단위 위치를 반환하므로 바이트 위치로 변환
+    let mut byte_offsets: Vec<usize> = text.char_indices().map(|(offset, _)| offset).collect();
+    byte_offsets.push(text.len());
+
+    // HMM으로 새 단어를 추정하면 문맥에 따라 분리 결과가 달라져 검색어와 일치하지 않으므로 사용하지 않음
+    // 사전에 없는 단어(대부분의 번체 단어 포함)는 한 글자씩 분리되어 구문 검색으로 일치합니다.
+    for word in JIEBA.tokenize(text, TokenizeMode::Search, false) {
+        // 공백, 구두점만으로 된 토큰은 제외
+        if !word.word.chars().any(char::is_alphanumeric) {
+            continue;
+        }
+        tokens.push(Token {
+            offset_from: byte_offsets[word.start],
+            offset_to: byte_offsets[word.end],
+            position: tokens.len(),
+            text: word.word.to_string(),
+            ..Token::default()
+        });
+    }
+}
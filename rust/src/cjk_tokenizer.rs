@@ -0,0 +1,105 @@
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+// 한중일(CJK) 문자는 겹치는 2-gram으로, 그 외 문자는 공백/구두점 기준 단어로 분리하는 토크나이저
+// 예: "한국어 검색 Flutter" -> "한국", "국어", "검색", "Flutter"
+// 공백 없이 이어지는 한국어/일본어/중국어 문장도 부분 일치로 검색할 수 있습니다.
+#[derive(Clone, Default)]
+pub(crate) struct CjkBigramTokenizer {
+    tokens: Vec<Token>,
+}
+
+pub(crate) struct CjkBigramTokenStream<'a> {
+    tokens: &'a mut Vec<Token>,
+    next: usize,
+}
+
+impl Tokenizer for CjkBigramTokenizer {
+    type TokenStream<'a> = CjkBigramTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> CjkBigramTokenStream<'a> {
+        self.tokens.clear();
+        tokenize(text, &mut self.tokens);
+        CjkBigramTokenStream {
+            tokens: &mut self.tokens,
+            next: 0,
+        }
+    }
+}
+
+impl TokenStream for CjkBigramTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        if self.next < self.tokens.len() {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.next - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.next - 1]
+    }
+}
+
+// 한글, 히라가나, 가타카나, 한자 여부
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11FF}'     // 한글 자모
+        | '\u{3040}'..='\u{30FF}'   // 히라가나, 가타카나
+        | '\u{3130}'..='\u{318F}'   // 한글 호환 자모
+        | '\u{3400}'..='\u{4DBF}'   // 한자 확장 A
+        | '\u{4E00}'..='\u{9FFF}'   // 한자
+        | '\u{AC00}'..='\u{D7AF}'   // 한글 음절
+        | '\u{F900}'..='\u{FAFF}'   // 한자 호환
+        | '\u{FF66}'..='\u{FF9F}'   // 반각 가타카나
+    )
+}
+
+fn tokenize(text: &str, tokens: &mut Vec<Token>) {
+    let mut push = |offset_from: usize, offset_to: usize| {
+        tokens.push(Token {
+            offset_from,
+            offset_to,
+            position: tokens.len(),
+            text: text[offset_from..offset_to].to_string(),
+            ..Token::default()
+        });
+    };
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        if is_cjk(c) {
+            // 연속된 CJK 구간을 모아 겹치는 2-gram 생성 (한 글자뿐이면 그대로 사용)
+            let mut run = vec![(offset, c)];
+            while let Some(&(next_offset, next_c)) = chars.peek() {
+                if !is_cjk(next_c) {
+                    break;
+                }
+                run.push((next_offset, next_c));
+                chars.next();
+            }
+            if run.len() == 1 {
+                push(offset, offset + c.len_utf8());
+            } else {
+                for pair in run.windows(2) {
+                    push(pair[0].0, pair[1].0 + pair[1].1.len_utf8());
+                }
+            }
+        } else if c.is_alphanumeric() {
+            // CJK 이외의 문자는 공백/구두점 기준 단어로 분리
+            let mut offset_to = offset + c.len_utf8();
+            while let Some(&(next_offset, next_c)) = chars.peek() {
+                if !next_c.is_alphanumeric() || is_cjk(next_c) {
+                    break;
+                }
+                offset_to = next_offset + next_c.len_utf8();
+                chars.next();
+            }
+            push(offset, offset_to);
+        }
+    }
+}
@@ -0,0 +1,558 @@
+use std::fmt;
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use tantivy::directory::error::{DeleteError, LockError, OpenReadError, OpenWriteError};
+use tantivy::directory::{
+    AntiCallToken, Directory, DirectoryLock, FileHandle, FileSlice, Lock, OwnedBytes,
+    TerminatingWrite, WatchCallback, WatchHandle, WritePtr,
+};
+use tantivy::HasLen;
+
+// 암호화 키의 길이 (AES-256)
+pub(crate) const KEY_LEN: usize = 32;
+
+// 암호화된 파일의 시작을 표시하는 값
+pub(crate) const MAGIC: &[u8; 4] = b"FTE1";
+
+// 파일마다 무작위로 만드는 nonce 앞부분의 길이 (나머지 4바이트는 청크 번호)
+const NONCE_PREFIX_LEN: usize = NONCE_LEN - 4;
+
+// 파일 헤더: MAGIC + nonce 앞부분
+const HEADER_LEN: usize = MAGIC.len() + NONCE_PREFIX_LEN;
+
+// 한 번에 암호화하는 평문의 크기 (작은 범위를 읽어도 이 크기만큼 복호화)
+const CHUNK_LEN: usize = 16 * 1024;
+
+// 청크마다 붙는 인증 태그의 길이
+const TAG_LEN: usize = 16;
+
+// 파일을 AES-256-GCM으로 암호화해서 inner 디렉토리에 저장하는 디렉토리
+// 파일은 헤더 뒤에 CHUNK_LEN 단위로 암호화한 청크가 이어지는 형식이며, 청크마다 파일 경로와
+// 마지막 청크 여부를 인증하므로 파일을 바꿔치기하거나 잘라내면 읽을 때 오류가 납니다.
+// 잠금 파일은 inner 디렉토리의 잠금을 그대로 사용하므로 암호화하지 않습니다.
+#[derive(Clone)]
+pub(crate) struct EncryptedDirectory {
+    inner: Box<dyn Directory>,
+    cipher: Arc<Cipher>,
+}
+
+impl EncryptedDirectory {
+    pub(crate) fn new(inner: Box<dyn Directory>, key: &[u8]) -> Result<EncryptedDirectory, String> {
+        if key.len() != KEY_LEN {
+            return Err(format!(
+                "Encryption key must be {} bytes, got {}",
+                KEY_LEN,
+                key.len()
+            ));
+        }
+        let key = UnboundKey::new(&AES_256_GCM, key).map_err(|_| "Invalid encryption key")?;
+        Ok(EncryptedDirectory {
+            inner,
+            cipher: Arc::new(Cipher {
+                key: LessSafeKey::new(key),
+                random: SystemRandom::new(),
+            }),
+        })
+    }
+}
+
+// 키는 출력하지 않음
+impl fmt::Debug for EncryptedDirectory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptedDirectory")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+// 파일의 첫 바이트가 암호화된 파일 형식인지 확인
+pub(crate) fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+struct Cipher {
+    key: LessSafeKey,
+    random: SystemRandom,
+}
+
+impl Cipher {
+    fn nonce_prefix(&self) -> io::Result<[u8; NONCE_PREFIX_LEN]> {
+        let mut prefix = [0; NONCE_PREFIX_LEN];
+        self.random
+            .fill(&mut prefix)
+            .map_err(|_| io::Error::other("Failed to generate a random nonce"))?;
+        Ok(prefix)
+    }
+
+    // 청크의 nonce (파일의 nonce 앞부분 + 청크 번호)와 인증 데이터 (마지막 청크 여부 + 파일 경로)
+    fn chunk_params(
+        prefix: &[u8; NONCE_PREFIX_LEN],
+        index: usize,
+        is_last: bool,
+        path: &Path,
+    ) -> io::Result<(Nonce, Vec<u8>)> {
+        let index = u32::try_from(index).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Encrypted file is too large")
+        })?;
+        let mut nonce = [0; NONCE_LEN];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(prefix);
+        nonce[NONCE_PREFIX_LEN..].copy_from_slice(&index.to_be_bytes());
+        let mut aad = vec![u8::from(is_last)];
+        aad.extend_from_slice(path.to_string_lossy().as_bytes());
+        Ok((Nonce::assume_unique_for_key(nonce), aad))
+    }
+
+    fn seal_chunk(
+        &self,
+        prefix: &[u8; NONCE_PREFIX_LEN],
+        index: usize,
+        is_last: bool,
+        path: &Path,
+        plaintext: &[u8],
+    ) -> io::Result<Vec<u8>> {
+        let (nonce, aad) = Self::chunk_params(prefix, index, is_last, path)?;
+        let mut chunk = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(nonce, Aad::from(aad), &mut chunk)
+            .map_err(|_| io::Error::other("Failed to encrypt index file"))?;
+        Ok(chunk)
+    }
+
+    fn open_chunk(
+        &self,
+        prefix: &[u8; NONCE_PREFIX_LEN],
+        index: usize,
+        is_last: bool,
+        path: &Path,
+        ciphertext: &[u8],
+    ) -> io::Result<Vec<u8>> {
+        let (nonce, aad) = Self::chunk_params(prefix, index, is_last, path)?;
+        let mut chunk = ciphertext.to_vec();
+        let len = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut chunk)
+            .map_err(|_| decrypt_error(path))?
+            .len();
+        chunk.truncate(len);
+        Ok(chunk)
+    }
+
+    // 파일 전체를 한 번에 암호화 (atomic_write용)
+    fn seal_file(&self, path: &Path, data: &[u8]) -> io::Result<Vec<u8>> {
+        let prefix = self.nonce_prefix()?;
+        let mut encrypted = Vec::with_capacity(HEADER_LEN + encrypted_body_len(data.len()));
+        encrypted.extend_from_slice(MAGIC);
+        encrypted.extend_from_slice(&prefix);
+        let num_chunks = data.len().div_ceil(CHUNK_LEN).max(1);
+        for index in 0..num_chunks {
+            let start = index * CHUNK_LEN;
+            let end = (start + CHUNK_LEN).min(data.len());
+            let is_last = index + 1 == num_chunks;
+            encrypted.extend(self.seal_chunk(&prefix, index, is_last, path, &data[start..end])?);
+        }
+        Ok(encrypted)
+    }
+
+    // 파일 전체를 한 번에 복호화 (atomic_read용)
+    fn open_file(&self, path: &Path, encrypted: &[u8]) -> io::Result<Vec<u8>> {
+        let layout = Layout::parse(
+            path,
+            encrypted.len(),
+            &encrypted[..HEADER_LEN.min(encrypted.len())],
+        )?;
+        let mut data = Vec::with_capacity(layout.len);
+        for index in 0..layout.num_chunks {
+            let range = layout.chunk_range(index);
+            let is_last = index + 1 == layout.num_chunks;
+            data.extend(self.open_chunk(
+                &layout.prefix,
+                index,
+                is_last,
+                path,
+                &encrypted[range],
+            )?);
+        }
+        Ok(data)
+    }
+}
+
+fn decrypt_error(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "Failed to decrypt {}: the encryption key is wrong or the file is damaged",
+            path.display()
+        ),
+    )
+}
+
+// 평문 len바이트를 암호화한 본문(헤더 제외)의 길이
+fn encrypted_body_len(len: usize) -> usize {
+    len + len.div_ceil(CHUNK_LEN).max(1) * TAG_LEN
+}
+
+// 암호화된 파일의 청크 배치
+struct Layout {
+    prefix: [u8; NONCE_PREFIX_LEN],
+    // 평문 길이
+    len: usize,
+    num_chunks: usize,
+}
+
+impl Layout {
+    fn parse(path: &Path, encrypted_len: usize, header: &[u8]) -> io::Result<Layout> {
+        if encrypted_len < HEADER_LEN + TAG_LEN || !is_encrypted(header) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not an encrypted index file", path.display()),
+            ));
+        }
+        let body_len = encrypted_len - HEADER_LEN;
+        // 태그보다 짧은 청크가 남으면 잘린 파일
+        let remainder = body_len % (CHUNK_LEN + TAG_LEN);
+        if remainder > 0 && remainder < TAG_LEN {
+            return Err(decrypt_error(path));
+        }
+        // 마지막 청크를 제외한 청크는 모두 CHUNK_LEN이고, 마지막 청크는 0 ~ CHUNK_LEN바이트
+        let num_chunks = body_len.div_ceil(CHUNK_LEN + TAG_LEN);
+        let mut prefix = [0; NONCE_PREFIX_LEN];
+        prefix.copy_from_slice(&header[MAGIC.len()..HEADER_LEN]);
+        Ok(Layout {
+            prefix,
+            len: body_len - num_chunks * TAG_LEN,
+            num_chunks,
+        })
+    }
+
+    // index번째 청크의 암호문 위치 (헤더 포함 파일 기준)
+    fn chunk_range(&self, index: usize) -> Range<usize> {
+        let start = HEADER_LEN + index * (CHUNK_LEN + TAG_LEN);
+        let plain_len = (self.len - index * CHUNK_LEN).min(CHUNK_LEN);
+        start..start + plain_len + TAG_LEN
+    }
+}
+
+// 읽는 범위에 해당하는 청크만 복호화하는 파일 핸들
+// 바로 전에 읽은 청크를 보관하므로 같은 청크 안을 연속해서 읽을 때는 다시 복호화하지 않습니다.
+struct EncryptedFileHandle {
+    path: PathBuf,
+    inner: FileSlice,
+    cipher: Arc<Cipher>,
+    layout: Layout,
+    last_chunk: Mutex<Option<(usize, OwnedBytes)>>,
+}
+
+impl EncryptedFileHandle {
+    fn chunk(&self, index: usize) -> io::Result<OwnedBytes> {
+        if let Some((cached, chunk)) = &*self.last_chunk.lock().unwrap() {
+            if *cached == index {
+                return Ok(chunk.clone());
+            }
+        }
+        let ciphertext = self
+            .inner
+            .read_bytes_slice(self.layout.chunk_range(index))?;
+        let is_last = index + 1 == self.layout.num_chunks;
+        let chunk = OwnedBytes::new(self.cipher.open_chunk(
+            &self.layout.prefix,
+            index,
+            is_last,
+            &self.path,
+            ciphertext.as_slice(),
+        )?);
+        *self.last_chunk.lock().unwrap() = Some((index, chunk.clone()));
+        Ok(chunk)
+    }
+}
+
+impl fmt::Debug for EncryptedFileHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptedFileHandle")
+            .field("path", &self.path)
+            .field("len", &self.layout.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HasLen for EncryptedFileHandle {
+    fn len(&self) -> usize {
+        self.layout.len
+    }
+}
+
+impl FileHandle for EncryptedFileHandle {
+    fn read_bytes(&self, range: Range<usize>) -> io::Result<OwnedBytes> {
+        if range.start > range.end || range.end > self.layout.len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Range {:?} is out of bounds for {} ({} bytes)",
+                    range,
+                    self.path.display(),
+                    self.layout.len
+                ),
+            ));
+        }
+        if range.is_empty() {
+            return Ok(OwnedBytes::empty());
+        }
+        let first = range.start / CHUNK_LEN;
+        let last = (range.end - 1) / CHUNK_LEN;
+        // 한 청크 안의 범위는 복호화한 청크를 그대로 공유
+        if first == last {
+            let chunk = self.chunk(first)?;
+            let offset = first * CHUNK_LEN;
+            return Ok(chunk.slice(range.start - offset..range.end - offset));
+        }
+        let mut data = Vec::with_capacity(range.len());
+        for index in first..=last {
+            let chunk = self.chunk(index)?;
+            let offset = index * CHUNK_LEN;
+            let start = range.start.max(offset) - offset;
+            let end = range.end.min(offset + chunk.len()) - offset;
+            data.extend_from_slice(&chunk.as_slice()[start..end]);
+        }
+        Ok(OwnedBytes::new(data))
+    }
+}
+
+// 평문을 CHUNK_LEN 단위로 암호화해서 inner에 쓰는 writer
+// 마지막 청크 여부를 terminate할 때 알 수 있으므로 가득 찬 청크도 다음 데이터가 올 때까지 보관합니다.
+struct EncryptedWriter {
+    path: PathBuf,
+    inner: WritePtr,
+    cipher: Arc<Cipher>,
+    prefix: [u8; NONCE_PREFIX_LEN],
+    buffer: Vec<u8>,
+    next_chunk: usize,
+}
+
+impl EncryptedWriter {
+    fn write_chunk(&mut self, is_last: bool) -> io::Result<()> {
+        let len = self.buffer.len().min(CHUNK_LEN);
+        let chunk = self.cipher.seal_chunk(
+            &self.prefix,
+            self.next_chunk,
+            is_last,
+            &self.path,
+            &self.buffer[..len],
+        )?;
+        self.inner.write_all(&chunk)?;
+        self.buffer.drain(..len);
+        self.next_chunk += 1;
+        Ok(())
+    }
+}
+
+impl Write for EncryptedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        while self.buffer.len() > CHUNK_LEN {
+            self.write_chunk(false)?;
+        }
+        Ok(buf.len())
+    }
+
+    // 보관 중인 청크는 마지막 청크일 수 있으므로 terminate할 때까지 쓰지 않음
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl TerminatingWrite for EncryptedWriter {
+    fn terminate_ref(&mut self, token: AntiCallToken) -> io::Result<()> {
+        self.write_chunk(true)?;
+        self.inner.terminate_ref(token)
+    }
+}
+
+impl Directory for EncryptedDirectory {
+    fn get_file_handle(&self, path: &Path) -> Result<Arc<dyn FileHandle>, OpenReadError> {
+        let inner = FileSlice::new(self.inner.get_file_handle(path)?);
+        let header = inner
+            .slice_to(HEADER_LEN.min(inner.len()))
+            .read_bytes()
+            .map_err(|e| OpenReadError::wrap_io_error(e, path.to_path_buf()))?;
+        let layout = Layout::parse(path, inner.len(), header.as_slice())
+            .map_err(|e| OpenReadError::wrap_io_error(e, path.to_path_buf()))?;
+        Ok(Arc::new(EncryptedFileHandle {
+            path: path.to_path_buf(),
+            inner,
+            cipher: self.cipher.clone(),
+            layout,
+            last_chunk: Mutex::new(None),
+        }))
+    }
+
+    fn delete(&self, path: &Path) -> Result<(), DeleteError> {
+        self.inner.delete(path)
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool, OpenReadError> {
+        self.inner.exists(path)
+    }
+
+    fn open_write(&self, path: &Path) -> Result<WritePtr, OpenWriteError> {
+        let mut inner = self.inner.open_write(path)?;
+        let prefix = self
+            .cipher
+            .nonce_prefix()
+            .map_err(|e| OpenWriteError::wrap_io_error(e, path.to_path_buf()))?;
+        inner
+            .write_all(MAGIC)
+            .and_then(|_| inner.write_all(&prefix))
+            .map_err(|e| OpenWriteError::wrap_io_error(e, path.to_path_buf()))?;
+        Ok(BufWriter::new(Box::new(EncryptedWriter {
+            path: path.to_path_buf(),
+            inner,
+            cipher: self.cipher.clone(),
+            prefix,
+            buffer: Vec::with_capacity(2 * CHUNK_LEN),
+            next_chunk: 0,
+        })))
+    }
+
+    fn atomic_read(&self, path: &Path) -> Result<Vec<u8>, OpenReadError> {
+        let encrypted = self.inner.atomic_read(path)?;
+        self.cipher
+            .open_file(path, &encrypted)
+            .map_err(|e| OpenReadError::wrap_io_error(e, path.to_path_buf()))
+    }
+
+    fn atomic_write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let encrypted = self.cipher.seal_file(path, data)?;
+        self.inner.atomic_write(path, &encrypted)
+    }
+
+    fn sync_directory(&self) -> io::Result<()> {
+        self.inner.sync_directory()
+    }
+
+    fn acquire_lock(&self, lock: &Lock) -> Result<DirectoryLock, LockError> {
+        self.inner.acquire_lock(lock)
+    }
+
+    fn watch(&self, watch_callback: WatchCallback) -> tantivy::Result<WatchHandle> {
+        self.inner.watch(watch_callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tantivy::directory::RamDirectory;
+
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn write_file(directory: &EncryptedDirectory, path: &Path, data: &[u8]) {
+        let mut writer = directory.open_write(path).unwrap();
+        writer.write_all(data).unwrap();
+        writer.terminate().unwrap();
+    }
+
+    fn read_error(directory: &EncryptedDirectory, path: &Path) -> io::ErrorKind {
+        let error = match directory.get_file_handle(path) {
+            Ok(handle) => return handle.read_bytes(0..handle.len()).unwrap_err().kind(),
+            Err(error) => error,
+        };
+        match error {
+            OpenReadError::IoError { io_error, .. } => io_error.kind(),
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn round_trip_at_chunk_boundaries() {
+        let inner = RamDirectory::create();
+        let directory = EncryptedDirectory::new(Box::new(inner.clone()), &[1; KEY_LEN]).unwrap();
+        for len in [0, 1, CHUNK_LEN, CHUNK_LEN + 1] {
+            let data = data(len);
+            let path = PathBuf::from(format!("{len}.bin"));
+            write_file(&directory, &path, &data);
+            let encrypted = inner.atomic_read(&path).unwrap();
+            assert_eq!(encrypted.len(), HEADER_LEN + encrypted_body_len(len));
+            assert!(is_encrypted(&encrypted));
+            let handle = directory.get_file_handle(&path).unwrap();
+            assert_eq!(handle.len(), len);
+            assert_eq!(handle.read_bytes(0..len).unwrap().as_slice(), &data[..]);
+
+            let path = PathBuf::from(format!("{len}.json"));
+            directory.atomic_write(&path, &data).unwrap();
+            assert_eq!(directory.atomic_read(&path).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn read_range_across_chunks() {
+        let directory =
+            EncryptedDirectory::new(Box::new(RamDirectory::create()), &[1; KEY_LEN]).unwrap();
+        let data = data(3 * CHUNK_LEN + 5);
+        let path = Path::new("file.bin");
+        write_file(&directory, path, &data);
+        let handle = directory.get_file_handle(path).unwrap();
+        for range in [
+            CHUNK_LEN - 10..CHUNK_LEN + 10,
+            CHUNK_LEN - 10..2 * CHUNK_LEN + 10,
+            10..20,
+            3 * CHUNK_LEN..3 * CHUNK_LEN + 5,
+        ] {
+            assert_eq!(
+                handle.read_bytes(range.clone()).unwrap().as_slice(),
+                &data[range]
+            );
+        }
+        assert_eq!(
+            handle.read_bytes(0..data.len() + 1).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn truncated_file_is_rejected() {
+        let inner = RamDirectory::create();
+        let directory = EncryptedDirectory::new(Box::new(inner.clone()), &[1; KEY_LEN]).unwrap();
+        let path = Path::new("file.bin");
+        write_file(&directory, path, &data(CHUNK_LEN + 100));
+        let encrypted = inner.atomic_read(path).unwrap();
+        // 마지막 청크 중간, 태그보다 짧게 남은 청크, 청크 경계에서 잘린 경우
+        for len in [
+            encrypted.len() - 1,
+            HEADER_LEN + CHUNK_LEN + TAG_LEN + 1,
+            HEADER_LEN + CHUNK_LEN + TAG_LEN,
+            HEADER_LEN,
+        ] {
+            inner.atomic_write(path, &encrypted[..len]).unwrap();
+            assert_eq!(read_error(&directory, path), io::ErrorKind::InvalidData);
+            assert!(directory.atomic_read(path).is_err());
+        }
+    }
+
+    #[test]
+    fn wrong_key_is_invalid_data() {
+        let inner = RamDirectory::create();
+        let directory = EncryptedDirectory::new(Box::new(inner.clone()), &[1; KEY_LEN]).unwrap();
+        write_file(&directory, Path::new("file.bin"), &data(100));
+        directory
+            .atomic_write(Path::new("meta.json"), &data(100))
+            .unwrap();
+
+        let directory = EncryptedDirectory::new(Box::new(inner), &[2; KEY_LEN]).unwrap();
+        assert_eq!(
+            read_error(&directory, Path::new("file.bin")),
+            io::ErrorKind::InvalidData
+        );
+        match directory.atomic_read(Path::new("meta.json")) {
+            Err(OpenReadError::IoError { io_error, .. }) => {
+                assert_eq!(io_error.kind(), io::ErrorKind::InvalidData)
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+}
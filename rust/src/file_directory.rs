@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use tantivy::directory::error::{
+    DeleteError, LockError, OpenDirectoryError, OpenReadError, OpenWriteError,
+};
+use tantivy::directory::{
+    Directory, DirectoryLock, FileHandle, Lock, MmapDirectory, OwnedBytes, WatchCallback,
+    WatchHandle, WritePtr,
+};
+use tantivy::HasLen;
+
+// mmap 대신 파일 읽기로 인덱스 파일을 여는 디렉토리
+// 쓰기, 잠금, 변경 감지는 MmapDirectory를 그대로 사용하고 읽는 방식만 바꿉니다.
+// preload가 true이면 파일을 처음 열 때 전체를 메모리에 읽어 두고 삭제될 때까지 재사용합니다.
+#[derive(Clone, Debug)]
+pub(crate) struct FileDirectory {
+    inner: MmapDirectory,
+    root: PathBuf,
+    preload: bool,
+    loaded: Arc<RwLock<HashMap<PathBuf, OwnedBytes>>>,
+}
+
+impl FileDirectory {
+    pub(crate) fn open(root: &Path, preload: bool) -> Result<FileDirectory, OpenDirectoryError> {
+        Ok(FileDirectory {
+            inner: MmapDirectory::open(root)?,
+            root: root.to_path_buf(),
+            preload,
+            loaded: Arc::default(),
+        })
+    }
+}
+
+// 필요한 범위만 읽는 파일 핸들
+#[derive(Debug)]
+struct FileReadHandle {
+    file: File,
+    len: usize,
+}
+
+impl HasLen for FileReadHandle {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl FileHandle for FileReadHandle {
+    fn read_bytes(&self, range: Range<usize>) -> io::Result<OwnedBytes> {
+        if range.start > range.end || range.end > self.len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Range {:?} is out of bounds ({} bytes)", range, self.len),
+            ));
+        }
+        if range.is_empty() {
+            return Ok(OwnedBytes::empty());
+        }
+        let mut buffer = vec![0; range.len()];
+        read_exact_at(&self.file, &mut buffer, range.start as u64)?;
+        Ok(OwnedBytes::new(buffer))
+    }
+}
+
+#[cfg(unix)]
+fn read_exact_at(file: &File, buffer: &mut [u8], offset: u64) -> io::Result<()> {
+    use std::os::unix::fs::FileExt;
+    file.read_exact_at(buffer, offset)
+}
+
+// 여러 스레드가 같은 파일을 읽으므로 파일 위치를 공유하지 않는 seek_read 사용
+#[cfg(windows)]
+fn read_exact_at(file: &File, mut buffer: &mut [u8], mut offset: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    while !buffer.is_empty() {
+        match file.seek_read(buffer, offset)? {
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            n => {
+                buffer = &mut buffer[n..];
+                offset += n as u64;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Directory for FileDirectory {
+    fn get_file_handle(&self, path: &Path) -> Result<Arc<dyn FileHandle>, OpenReadError> {
+        if let Some(data) = self.loaded.read().unwrap().get(path) {
+            return Ok(Arc::new(data.clone()));
+        }
+        let open_error = |e: io::Error| {
+            if e.kind() == io::ErrorKind::NotFound {
+                OpenReadError::FileDoesNotExist(path.to_path_buf())
+            } else {
+                OpenReadError::wrap_io_error(e, path.to_path_buf())
+            }
+        };
+        let mut file = File::open(self.root.join(path)).map_err(open_error)?;
+        if self.preload {
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).map_err(open_error)?;
+            let data = OwnedBytes::new(data);
+            self.loaded
+                .write()
+                .unwrap()
+                .insert(path.to_path_buf(), data.clone());
+            return Ok(Arc::new(data));
+        }
+        let len = file.metadata().map_err(open_error)?.len() as usize;
+        Ok(Arc::new(FileReadHandle { file, len }))
+    }
+
+    fn delete(&self, path: &Path) -> Result<(), DeleteError> {
+        self.loaded.write().unwrap().remove(path);
+        self.inner.delete(path)
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool, OpenReadError> {
+        self.inner.exists(path)
+    }
+
+    fn open_write(&self, path: &Path) -> Result<WritePtr, OpenWriteError> {
+        self.loaded.write().unwrap().remove(path);
+        self.inner.open_write(path)
+    }
+
+    fn atomic_read(&self, path: &Path) -> Result<Vec<u8>, OpenReadError> {
+        self.inner.atomic_read(path)
+    }
+
+    fn atomic_write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        self.loaded.write().unwrap().remove(path);
+        self.inner.atomic_write(path, data)
+    }
+
+    fn sync_directory(&self) -> io::Result<()> {
+        self.inner.sync_directory()
+    }
+
+    fn acquire_lock(&self, lock: &Lock) -> Result<DirectoryLock, LockError> {
+        self.inner.acquire_lock(lock)
+    }
+
+    fn watch(&self, watch_callback: WatchCallback) -> tantivy::Result<WatchHandle> {
+        self.inner.watch(watch_callback)
+    }
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+// root 아래에서 globs 중 하나와 일치하는 파일 목록 (경로 순)
+// glob은 root 기준의 '/' 구분 경로와 비교하며, '/'가 없는 glob은 모든 깊이의 파일 이름과 비교합니다.
+// 예: "*.md" -> "a.md", "notes/b.md" / "notes/*.md" -> "notes/b.md" / "notes/**/*.md" -> "notes/b.md", "notes/2024/c.md"
+// 숨김 파일과 숨김 디렉터리(이름이 '.'으로 시작)는 건너뛰고, 심볼릭 링크는 따라가지 않습니다.
+// 읽을 수 없는 하위 디렉터리는 건너뛰며, root를 읽을 수 없으면 에러를 반환합니다.
+pub(crate) fn walk_files(root: &Path, globs: &[String]) -> Result<Vec<PathBuf>> {
+    let globs: Vec<Vec<char>> = globs.iter().map(|glob| glob.chars().collect()).collect();
+    let mut files = Vec::new();
+    let mut directories = vec![(root.to_path_buf(), std::fs::read_dir(root)?)];
+    while let Some((directory, entries)) = directories.pop() {
+        let mut entries: Vec<_> = entries.filter_map(|entry| entry.ok()).collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries.into_iter().rev() {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = directory.join(entry.file_name());
+            if file_type.is_dir() {
+                if let Ok(entries) = std::fs::read_dir(&path) {
+                    directories.push((path, entries));
+                }
+            } else if file_type.is_file() && matches_any(&globs, root, &path) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// globs가 비어 있으면 모든 파일과 일치
+fn matches_any(globs: &[Vec<char>], root: &Path, path: &Path) -> bool {
+    if globs.is_empty() {
+        return true;
+    }
+    let relative: Vec<char> = path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+        .chars()
+        .collect();
+    let name_start = relative
+        .iter()
+        .rposition(|&c| c == '/')
+        .map_or(0, |i| i + 1);
+    globs.iter().any(|glob| {
+        if glob.contains(&'/') {
+            glob_match(glob, &relative)
+        } else {
+            glob_match(glob, &relative[name_start..])
+        }
+    })
+}
+
+// '*'는 '/'를 제외한 0개 이상의 문자, '?'는 '/'를 제외한 한 문자, "**/"는 0개 이상의 디렉터리와 일치
+fn glob_match(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        ['*', '*', '/', rest @ ..] => (0..=path.len())
+            .filter(|&i| i == 0 || path[i - 1] == '/')
+            .any(|i| glob_match(rest, &path[i..])),
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|i| glob_match(rest, &path[i..])),
+        ['*', rest @ ..] => (0..=path.len())
+            .take_while(|&i| i == 0 || path[i - 1] != '/')
+            .any(|i| glob_match(rest, &path[i..])),
+        ['?', rest @ ..] => path.first().is_some_and(|&c| c != '/') && glob_match(rest, &path[1..]),
+        [c, rest @ ..] => path.first() == Some(c) && glob_match(rest, &path[1..]),
+    }
+}
+
+// 파일 내용이 바뀌었는지 확인하기 위한 64비트 FNV-1a 해시 (16자리 16진수)
+// 수정 시각만 바뀌고 내용이 같은 파일을 다시 추출하지 않기 위해 사용하며, 암호학적으로 안전하지 않습니다.
+pub(crate) fn content_hash(path: &Path) -> Result<String> {
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path)?;
+    let mut buffer = [0u8; 64 * 1024];
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        for &byte in &buffer[..read] {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    Ok(format!("{:016x}", hash))
+}
@@ -0,0 +1,230 @@
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+// 색인하는 지오해시의 최대 길이 (8자리 셀은 약 38m x 19m)
+pub(crate) const GEOHASH_PRECISION: usize = 8;
+
+// 영역 검색에서 후보를 찾는 셀 수의 상한 (넘으면 더 큰 셀을 사용)
+const MAX_COVERING_CELLS: usize = 32;
+
+// 평균 지구 반지름 (미터)
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+// 위치 값 "위도,경도"를 (위도, 경도)로 해석 (범위를 벗어나면 None)
+pub(crate) fn parse_point(text: &str) -> Option<(f64, f64)> {
+    let (lat, lon) = text.split_once(',')?;
+    let point = (lat.trim().parse().ok()?, lon.trim().parse().ok()?);
+    is_valid_point(point).then_some(point)
+}
+
+pub(crate) fn format_point((lat, lon): (f64, f64)) -> String {
+    format!("{},{}", lat, lon)
+}
+
+pub(crate) fn is_valid_point((lat, lon): (f64, f64)) -> bool {
+    (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)
+}
+
+// 두 지점 사이의 거리 (미터, 하버사인 공식)
+pub(crate) fn distance_meters((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+}
+
+// 위도/경도 사각형 영역 (경도가 180도를 넘는 영역은 min_lon > max_lon)
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BoundingBox {
+    pub(crate) min_lat: f64,
+    pub(crate) min_lon: f64,
+    pub(crate) max_lat: f64,
+    pub(crate) max_lon: f64,
+}
+
+impl BoundingBox {
+    // center에서 radius_meters 이내의 지점을 모두 포함하는 사각형
+    pub(crate) fn around((lat, lon): (f64, f64), radius_meters: f64) -> BoundingBox {
+        let angle = radius_meters / EARTH_RADIUS_METERS;
+        let d_lat = angle.to_degrees();
+        let (min_lat, max_lat) = (lat - d_lat, lat + d_lat);
+        // 극점을 포함하거나 반경이 경도 전체를 덮으면 모든 경도
+        let sin_ratio = angle.sin() / lat.to_radians().cos();
+        if min_lat <= -90.0 || max_lat >= 90.0 || angle >= std::f64::consts::PI || sin_ratio >= 1.0
+        {
+            return BoundingBox {
+                min_lat: min_lat.max(-90.0),
+                min_lon: -180.0,
+                max_lat: max_lat.min(90.0),
+                max_lon: 180.0,
+            };
+        }
+        let d_lon = sin_ratio.asin().to_degrees();
+        let wrap = |lon: f64| {
+            if lon < -180.0 {
+                lon + 360.0
+            } else if lon > 180.0 {
+                lon - 360.0
+            } else {
+                lon
+            }
+        };
+        BoundingBox {
+            min_lat,
+            min_lon: wrap(lon - d_lon),
+            max_lat,
+            max_lon: wrap(lon + d_lon),
+        }
+    }
+
+    pub(crate) fn contains(&self, (lat, lon): (f64, f64)) -> bool {
+        let lon_inside = if self.min_lon <= self.max_lon {
+            self.min_lon <= lon && lon <= self.max_lon
+        } else {
+            lon >= self.min_lon || lon <= self.max_lon
+        };
+        self.min_lat <= lat && lat <= self.max_lat && lon_inside
+    }
+
+    // 영역을 덮는 지오해시 셀 목록 (셀 수가 MAX_COVERING_CELLS 이하가 되는 가장 작은 셀)
+    // 셀은 영역보다 넓을 수 있으므로 후보를 찾는 데만 사용하고, 정확한 위치는 contains로 확인해야 합니다.
+    pub(crate) fn covering_cells(&self) -> Vec<String> {
+        let lon_ranges = if self.min_lon <= self.max_lon {
+            vec![(self.min_lon, self.max_lon)]
+        } else {
+            vec![(self.min_lon, 180.0), (-180.0, self.max_lon)]
+        };
+        for precision in (1..=GEOHASH_PRECISION).rev() {
+            let (cell_height, cell_width) = cell_size(precision);
+            let cell_range = |min: f64, max: f64, origin: f64, size: f64, count: f64| {
+                let first = ((min - origin) / size).floor().clamp(0.0, count - 1.0) as i64;
+                let last = ((max - origin) / size).floor().clamp(0.0, count - 1.0) as i64;
+                first..=last
+            };
+            let rows = cell_range(
+                self.min_lat,
+                self.max_lat,
+                -90.0,
+                cell_height,
+                180.0 / cell_height,
+            );
+            let columns: Vec<_> = lon_ranges
+                .iter()
+                .map(|&(min, max)| cell_range(min, max, -180.0, cell_width, 360.0 / cell_width))
+                .collect();
+            let count =
+                rows.clone().count() * columns.iter().map(|c| c.clone().count()).sum::<usize>();
+            if count > MAX_COVERING_CELLS && precision > 1 {
+                continue;
+            }
+            let mut cells = Vec::with_capacity(count);
+            for row in rows {
+                for column in columns.iter().flat_map(|c| c.clone()) {
+                    // 셀의 중심으로 해당 셀의 지오해시를 계산
+                    let lat = -90.0 + (row as f64 + 0.5) * cell_height;
+                    let lon = -180.0 + (column as f64 + 0.5) * cell_width;
+                    cells.push(geohash((lat, lon), precision));
+                }
+            }
+            cells.sort();
+            cells.dedup();
+            return cells;
+        }
+        Vec::new()
+    }
+}
+
+// precision자리 지오해시 셀의 (위도, 경도) 크기 (도)
+fn cell_size(precision: usize) -> (f64, f64) {
+    let bits = 5 * precision as i32;
+    let lon_bits = (bits + 1) / 2;
+    let lat_bits = bits / 2;
+    (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(lon_bits))
+}
+
+// 위치의 precision자리 지오해시 (경도, 위도 순서로 비트를 번갈아 사용)
+pub(crate) fn geohash((lat, lon): (f64, f64), precision: usize) -> String {
+    let (mut lat_range, mut lon_range) = ((-90.0, 90.0), (-180.0, 180.0));
+    let mut hash = String::with_capacity(precision);
+    let mut is_lon = true;
+    for _ in 0..precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = if is_lon {
+                (&mut lon_range, lon)
+            } else {
+                (&mut lat_range, lat)
+            };
+            let middle = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= middle {
+                index |= 1;
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_lon = !is_lon;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    hash
+}
+
+// "위도,경도" 값을 1 ~ GEOHASH_PRECISION자리의 모든 지오해시 접두어로 분리하는 토크나이저
+// 예: "37.5665,126.978" -> "w", "wy", "wyd", ..., "wydm9qw5"
+// 영역 검색은 영역을 덮는 셀의 지오해시와 일치하는 문서를 후보로 찾습니다.
+#[derive(Clone, Default)]
+pub(crate) struct GeoHashTokenizer {
+    tokens: Vec<Token>,
+}
+
+pub(crate) struct GeoHashTokenStream<'a> {
+    tokens: &'a mut Vec<Token>,
+    next: usize,
+}
+
+impl Tokenizer for GeoHashTokenizer {
+    type TokenStream<'a> = GeoHashTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> GeoHashTokenStream<'a> {
+        self.tokens.clear();
+        // 잘못된 값은 색인할 토큰이 없는 것으로 취급
+        if let Some(point) = parse_point(text) {
+            let hash = geohash(point, GEOHASH_PRECISION);
+            for len in 1..=GEOHASH_PRECISION {
+                self.tokens.push(Token {
+                    offset_from: 0,
+                    offset_to: text.len(),
+                    position: 0,
+                    text: hash[..len].to_string(),
+                    ..Token::default()
+                });
+            }
+        }
+        GeoHashTokenStream {
+            tokens: &mut self.tokens,
+            next: 0,
+        }
+    }
+}
+
+impl TokenStream for GeoHashTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        if self.next < self.tokens.len() {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.next - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.next - 1]
+    }
+}
@@ -0,0 +1,131 @@
+use std::mem;
+
+use tantivy::tokenizer::{Token, TokenFilter, TokenStream, Tokenizer};
+
+// 한글 음절을 자모(초성, 중성, 종성)로 분해하는 필터
+// 예: "한글" -> "ㅎㅏㄴㄱㅡㄹ", 겹모음/겹받침도 나누므로 "과" -> "ㄱㅗㅏ"
+// 입력 중인 "한ㄱ", "하" 같은 조합 중인 글자도 접두어 검색으로 "한글"과 일치합니다.
+#[derive(Clone)]
+pub(crate) struct HangulJamoFilter;
+
+impl TokenFilter for HangulJamoFilter {
+    type Tokenizer<T: Tokenizer> = HangulJamoFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> HangulJamoFilterWrapper<T> {
+        HangulJamoFilterWrapper {
+            inner: tokenizer,
+            buffer: String::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct HangulJamoFilterWrapper<T> {
+    inner: T,
+    buffer: String,
+}
+
+impl<T: Tokenizer> Tokenizer for HangulJamoFilterWrapper<T> {
+    type TokenStream<'a> = HangulJamoTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        self.buffer.clear();
+        HangulJamoTokenStream {
+            tail: self.inner.token_stream(text),
+            buffer: &mut self.buffer,
+        }
+    }
+}
+
+pub(crate) struct HangulJamoTokenStream<'a, T> {
+    tail: T,
+    buffer: &'a mut String,
+}
+
+impl<T: TokenStream> TokenStream for HangulJamoTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+        // 한글이 없는 토큰은 그대로 사용
+        if !self.tail.token().text.is_ascii() {
+            decompose(&self.tail.token().text, self.buffer);
+            mem::swap(&mut self.tail.token_mut().text, self.buffer);
+        }
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
+
+// 한글 음절 범위와 조합 규칙 (음절 = 0xAC00 + (초성 * 21 + 중성) * 28 + 종성)
+const SYLLABLE_BASE: u32 = 0xAC00;
+const SYLLABLE_COUNT: u32 = 11172;
+const MEDIAL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+
+// 초성, 중성, 종성을 사용자가 직접 입력하는 호환용 자모로 표현
+const INITIALS: [&str; 19] = [
+    "ㄱ", "ㄲ", "ㄴ", "ㄷ", "ㄸ", "ㄹ", "ㅁ", "ㅂ", "ㅃ", "ㅅ", "ㅆ", "ㅇ", "ㅈ", "ㅉ", "ㅊ", "ㅋ",
+    "ㅌ", "ㅍ", "ㅎ",
+];
+const MEDIALS: [&str; 21] = [
+    "ㅏ", "ㅐ", "ㅑ", "ㅒ", "ㅓ", "ㅔ", "ㅕ", "ㅖ", "ㅗ", "ㅗㅏ", "ㅗㅐ", "ㅗㅣ", "ㅛ", "ㅜ",
+    "ㅜㅓ", "ㅜㅔ", "ㅜㅣ", "ㅠ", "ㅡ", "ㅡㅣ", "ㅣ",
+];
+const FINALS: [&str; 28] = [
+    "", "ㄱ", "ㄲ", "ㄱㅅ", "ㄴ", "ㄴㅈ", "ㄴㅎ", "ㄷ", "ㄹ", "ㄹㄱ", "ㄹㅁ", "ㄹㅂ", "ㄹㅅ",
+    "ㄹㅌ", "ㄹㅍ", "ㄹㅎ", "ㅁ", "ㅂ", "ㅂㅅ", "ㅅ", "ㅆ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ",
+    "ㅎ",
+];
+
+// 단독으로 입력된 겹모음/겹받침 자모도 같은 형태로 분해
+fn compound_jamo(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ㅘ' => "ㅗㅏ",
+        'ㅙ' => "ㅗㅐ",
+        'ㅚ' => "ㅗㅣ",
+        'ㅝ' => "ㅜㅓ",
+        'ㅞ' => "ㅜㅔ",
+        'ㅟ' => "ㅜㅣ",
+        'ㅢ' => "ㅡㅣ",
+        'ㄳ' => "ㄱㅅ",
+        'ㄵ' => "ㄴㅈ",
+        'ㄶ' => "ㄴㅎ",
+        'ㄺ' => "ㄹㄱ",
+        'ㄻ' => "ㄹㅁ",
+        'ㄼ' => "ㄹㅂ",
+        'ㄽ' => "ㄹㅅ",
+        'ㄾ' => "ㄹㅌ",
+        'ㄿ' => "ㄹㅍ",
+        'ㅀ' => "ㄹㅎ",
+        'ㅄ' => "ㅂㅅ",
+        _ => return None,
+    })
+}
+
+fn decompose(text: &str, output: &mut String) {
+    output.clear();
+    output.reserve(text.len() * 2);
+    for c in text.chars() {
+        let index = (c as u32).wrapping_sub(SYLLABLE_BASE);
+        if index < SYLLABLE_COUNT {
+            let initial = index / (MEDIAL_COUNT * FINAL_COUNT);
+            let medial = index % (MEDIAL_COUNT * FINAL_COUNT) / FINAL_COUNT;
+            let final_ = index % FINAL_COUNT;
+            output.push_str(INITIALS[initial as usize]);
+            output.push_str(MEDIALS[medial as usize]);
+            output.push_str(FINALS[final_ as usize]);
+        } else if let Some(jamo) = compound_jamo(c) {
+            output.push_str(jamo);
+        } else {
+            output.push(c);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use lindera::dictionary::{load_embedded_dictionary, DictionaryKind};
+use lindera::mode::Mode;
+use lindera::segmenter::Segmenter;
+use once_cell::sync::Lazy;
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+// 포함된 IPADIC 사전은 크기가 커서 처음 사용할 때 한 번만 불러옴
+static SEGMENTER: Lazy<Segmenter> = Lazy::new(|| {
+    let dictionary = load_embedded_dictionary(DictionaryKind::IPADIC)
+        .expect("embedded IPADIC dictionary is missing");
+    Segmenter::new(Mode::Normal, dictionary, None)
+});
+
+// Lindera 형태소 분석기로 일본어 문장을 단어 단위로 분리하는 토크나이저
+// 예: "東京都に住んでいます" -> "東京", "都", "に", "住ん", "で", "い", "ます"
+// 공백 없이 이어지는 일본어 문장도 단어 단위로 검색할 수 있습니다.
+#[derive(Clone, Default)]
+pub(crate) struct JapaneseTokenizer {
+    tokens: Vec<Token>,
+}
+
+pub(crate) struct JapaneseTokenStream<'a> {
+    tokens: &'a mut Vec<Token>,
+    next: usize,
+}
+
+impl Tokenizer for JapaneseTokenizer {
+    type TokenStream<'a> = JapaneseTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> JapaneseTokenStream<'a> {
+        self.tokens.clear();
+        tokenize(text, &mut self.tokens);
+        JapaneseTokenStream {
+            tokens: &mut self.tokens,
+            next: 0,
+        }
+    }
+}
+
+impl TokenStream for JapaneseTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        if self.next < self.tokens.len() {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.next - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.next - 1]
+    }
+}
+
+fn tokenize(text: &str, tokens: &mut Vec<Token>) {
+    // 형태소 분석은 사전이 올바르면 실패하지 않으며, 실패하면 색인할 토큰이 없는 것으로 취급
+    let Ok(words) = SEGMENTER.segment(Cow::Borrowed(text)) else {
+        return;
+    };
+    for word in words {
+        // 구두점, 기호만으로 된 토큰은 제외
+        if !word.surface.chars().any(char::is_alphanumeric) {
+            continue;
+        }
+        tokens.push(Token {
+            offset_from: word.byte_start,
+            offset_to: word.byte_end,
+            position: tokens.len(),
+            text: word.surface.into_owned(),
+            ..Token::default()
+        });
+    }
+}
@@ -1,2 +1,16 @@
 pub mod api;
+#[cfg(feature = "chinese")]
+mod chinese_tokenizer;
+mod cjk_tokenizer;
+mod encrypted_directory;
+mod file_directory;
+mod file_walk;
 mod frb_generated;
+mod geo;
+mod hangul_jamo;
+#[cfg(feature = "japanese")]
+mod japanese_tokenizer;
+mod markup_tokenizer;
+mod protected_directory;
+mod synonym_filter;
+mod text_extract;
@@ -0,0 +1,80 @@
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+use crate::text_extract::{strip_html_with_sources, strip_markdown_with_sources};
+
+// 분리하기 전에 제거할 마크업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Markup {
+    Html,
+    // 마크다운 문법과 함께 본문에 포함된 HTML 태그도 제거
+    Markdown,
+}
+
+// 마크업을 제거하고 엔티티를 디코딩한 본문을 inner 토크나이저로 분리하는 토크나이저
+// 예: "<p>Tom &amp; <b>Jer</b>ry</p>" -> "Tom", "Jerry" (태그 이름과 속성 값은 색인되지 않음)
+// 예: "See [docs](https://example.com)" -> "See", "docs" (링크 주소는 색인되지 않음)
+// 토큰 위치는 원본 기준으로 되돌리므로 저장된 원본에서 일치한 부분을 강조할 수 있습니다.
+#[derive(Clone)]
+pub(crate) struct MarkupStripTokenizer<T> {
+    inner: T,
+    markup: Markup,
+    text: String,
+    // text의 바이트마다 원본에서의 (시작, 끝) 바이트 위치
+    sources: Vec<(usize, usize)>,
+}
+
+impl<T> MarkupStripTokenizer<T> {
+    pub(crate) fn new(inner: T, markup: Markup) -> Self {
+        MarkupStripTokenizer {
+            inner,
+            markup,
+            text: String::new(),
+            sources: Vec::new(),
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for MarkupStripTokenizer<T> {
+    type TokenStream<'a> = MarkupStripTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        match self.markup {
+            Markup::Html => strip_html_with_sources(text, &mut self.text, &mut self.sources),
+            Markup::Markdown => {
+                strip_markdown_with_sources(text, &mut self.text, &mut self.sources)
+            }
+        }
+        MarkupStripTokenStream {
+            tail: self.inner.token_stream(&self.text),
+            sources: &self.sources,
+        }
+    }
+}
+
+pub(crate) struct MarkupStripTokenStream<'a, T> {
+    tail: T,
+    sources: &'a [(usize, usize)],
+}
+
+impl<T: TokenStream> TokenStream for MarkupStripTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+        // 토큰의 첫 글자 시작부터 마지막 글자 끝까지를 원본 위치로 사용
+        let token = self.tail.token_mut();
+        if token.offset_from < token.offset_to && token.offset_to <= self.sources.len() {
+            token.offset_from = self.sources[token.offset_from].0;
+            token.offset_to = self.sources[token.offset_to - 1].1;
+        }
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tantivy::directory::error::{DeleteError, LockError, OpenReadError, OpenWriteError};
+use tantivy::directory::{
+    AntiCallToken, Directory, DirectoryLock, FileHandle, Lock, OwnedBytes, TerminatingWrite,
+    WatchCallback, WatchHandle, WritePtr,
+};
+use tantivy::HasLen;
+
+// 다시 시도하는 간격 (처음 간격에서 두 배씩 늘려 최대 간격까지)
+const FIRST_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+const MAX_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+// 파일 접근이 거부되면 wait 동안 다시 시도하는 디렉토리
+// iOS 데이터 보호(NSFileProtectionComplete)가 적용된 파일은 기기가 잠긴 동안 열거나 읽고 쓸 수 없으므로,
+// 잠금이 풀릴 때까지 작업을 기다리게 해서 백그라운드 색인이 바로 실패하지 않도록 합니다.
+#[derive(Clone, Debug)]
+pub(crate) struct ProtectedDirectory {
+    inner: Box<dyn Directory>,
+    wait: Duration,
+}
+
+impl ProtectedDirectory {
+    pub(crate) fn new(inner: Box<dyn Directory>, wait: Duration) -> ProtectedDirectory {
+        ProtectedDirectory { inner, wait }
+    }
+}
+
+pub(crate) fn is_permission_denied(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::PermissionDenied
+}
+
+// op가 접근 거부(denied)로 실패하면 wait가 지날 때까지 다시 시도
+fn retry<T, E>(
+    wait: Duration,
+    denied: impl Fn(&E) -> bool,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let deadline = Instant::now() + wait;
+    let mut interval = FIRST_RETRY_INTERVAL;
+    loop {
+        match op() {
+            Err(e) if denied(&e) && Instant::now() < deadline => {
+                thread::sleep(interval.min(deadline.saturating_duration_since(Instant::now())));
+                interval = (interval * 2).min(MAX_RETRY_INTERVAL);
+            }
+            result => return result,
+        }
+    }
+}
+
+fn read_denied(error: &OpenReadError) -> bool {
+    matches!(error, OpenReadError::IoError { io_error, .. } if is_permission_denied(io_error))
+}
+
+fn write_denied(error: &OpenWriteError) -> bool {
+    matches!(error, OpenWriteError::IoError { io_error, .. } if is_permission_denied(io_error))
+}
+
+fn delete_denied(error: &DeleteError) -> bool {
+    matches!(error, DeleteError::IoError { io_error, .. } if is_permission_denied(io_error))
+}
+
+fn lock_denied(error: &LockError) -> bool {
+    matches!(error, LockError::IoError(io_error) if is_permission_denied(io_error))
+}
+
+#[derive(Debug)]
+struct ProtectedFileHandle {
+    inner: Arc<dyn FileHandle>,
+    wait: Duration,
+}
+
+impl HasLen for ProtectedFileHandle {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl FileHandle for ProtectedFileHandle {
+    fn read_bytes(&self, range: Range<usize>) -> io::Result<OwnedBytes> {
+        retry(self.wait, is_permission_denied, || {
+            self.inner.read_bytes(range.clone())
+        })
+    }
+}
+
+struct ProtectedWriter {
+    inner: Box<dyn TerminatingWrite>,
+    wait: Duration,
+}
+
+impl Write for ProtectedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        retry(self.wait, is_permission_denied, || self.inner.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        retry(self.wait, is_permission_denied, || self.inner.flush())
+    }
+}
+
+impl TerminatingWrite for ProtectedWriter {
+    // terminate는 한 번만 호출할 수 있으므로 먼저 flush를 다시 시도해서 남은 데이터를 씀
+    fn terminate_ref(&mut self, token: AntiCallToken) -> io::Result<()> {
+        self.flush()?;
+        self.inner.terminate_ref(token)
+    }
+}
+
+impl Directory for ProtectedDirectory {
+    fn get_file_handle(&self, path: &Path) -> Result<Arc<dyn FileHandle>, OpenReadError> {
+        let inner = retry(self.wait, read_denied, || self.inner.get_file_handle(path))?;
+        Ok(Arc::new(ProtectedFileHandle {
+            inner,
+            wait: self.wait,
+        }))
+    }
+
+    fn delete(&self, path: &Path) -> Result<(), DeleteError> {
+        retry(self.wait, delete_denied, || self.inner.delete(path))
+    }
+
+    fn exists(&self, path: &Path) -> Result<bool, OpenReadError> {
+        retry(self.wait, read_denied, || self.inner.exists(path))
+    }
+
+    fn open_write(&self, path: &Path) -> Result<WritePtr, OpenWriteError> {
+        let writer = retry(self.wait, write_denied, || self.inner.open_write(path))?;
+        // 새로 연 writer는 버퍼가 비어 있으므로 바로 꺼낼 수 있음
+        let inner = writer
+            .into_inner()
+            .map_err(|e| OpenWriteError::wrap_io_error(e.into_error(), path.to_path_buf()))?;
+        Ok(BufWriter::new(Box::new(ProtectedWriter {
+            inner,
+            wait: self.wait,
+        })))
+    }
+
+    fn atomic_read(&self, path: &Path) -> Result<Vec<u8>, OpenReadError> {
+        retry(self.wait, read_denied, || self.inner.atomic_read(path))
+    }
+
+    fn atomic_write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        retry(self.wait, is_permission_denied, || {
+            self.inner.atomic_write(path, data)
+        })
+    }
+
+    fn sync_directory(&self) -> io::Result<()> {
+        retry(self.wait, is_permission_denied, || {
+            self.inner.sync_directory()
+        })
+    }
+
+    fn acquire_lock(&self, lock: &Lock) -> Result<DirectoryLock, LockError> {
+        retry(self.wait, lock_denied, || self.inner.acquire_lock(lock))
+    }
+
+    fn watch(&self, watch_callback: WatchCallback) -> tantivy::Result<WatchHandle> {
+        self.inner.watch(watch_callback)
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tantivy::tokenizer::{Token, TokenFilter, TokenStream, Tokenizer};
+
+// 동의어 그룹의 단어가 나오면 그룹의 다른 단어들을 같은 위치에 함께 내보내는 필터
+// 예: [["couch", "sofa"]] -> "couch"는 "couch", "sofa" 두 토큰이 됨
+// 색인과 검색에 같은 분석기를 사용하므로 "couch"로 검색해도 "sofa"가 들어간 문서를 찾습니다.
+#[derive(Clone)]
+pub(crate) struct SynonymFilter {
+    synonyms: Arc<HashMap<String, Vec<String>>>,
+}
+
+impl SynonymFilter {
+    // 그룹 안의 모든 단어를 서로의 동의어로 등록 (여러 그룹에 속한 단어는 모든 그룹의 단어로 확장)
+    pub(crate) fn new(groups: &[Vec<String>]) -> Self {
+        let mut synonyms: HashMap<String, Vec<String>> = HashMap::new();
+        for group in groups {
+            for word in group {
+                let entry = synonyms.entry(word.clone()).or_default();
+                for synonym in group {
+                    if synonym != word && !entry.contains(synonym) {
+                        entry.push(synonym.clone());
+                    }
+                }
+            }
+        }
+        SynonymFilter {
+            synonyms: Arc::new(synonyms),
+        }
+    }
+}
+
+impl TokenFilter for SynonymFilter {
+    type Tokenizer<T: Tokenizer> = SynonymFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> SynonymFilterWrapper<T> {
+        SynonymFilterWrapper {
+            synonyms: self.synonyms,
+            inner: tokenizer,
+            pending: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct SynonymFilterWrapper<T> {
+    synonyms: Arc<HashMap<String, Vec<String>>>,
+    inner: T,
+    pending: Vec<Token>,
+}
+
+impl<T: Tokenizer> Tokenizer for SynonymFilterWrapper<T> {
+    type TokenStream<'a> = SynonymTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        self.pending.clear();
+        SynonymTokenStream {
+            synonyms: &self.synonyms,
+            tail: self.inner.token_stream(text),
+            pending: &mut self.pending,
+        }
+    }
+}
+
+pub(crate) struct SynonymTokenStream<'a, T> {
+    synonyms: &'a HashMap<String, Vec<String>>,
+    tail: T,
+    // 내보낼 토큰 (pop 순서대로 원래 토큰, 동의어 순서가 되도록 역순으로 보관)
+    pending: &'a mut Vec<Token>,
+}
+
+impl<T: TokenStream> TokenStream for SynonymTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        self.pending.pop();
+        if !self.pending.is_empty() {
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        // 동의어가 없으면 pending을 비워 두고 tail의 토큰을 그대로 사용
+        let token = self.tail.token();
+        if let Some(synonyms) = self.synonyms.get(&token.text) {
+            for synonym in synonyms.iter().rev() {
+                self.pending.push(Token {
+                    text: synonym.clone(),
+                    ..token.clone()
+                });
+            }
+            self.pending.push(token.clone());
+        }
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.pending.last().unwrap_or_else(|| self.tail.token())
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.pending
+            .last_mut()
+            .unwrap_or_else(|| self.tail.token_mut())
+    }
+}
@@ -0,0 +1,792 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+// index_file에서 텍스트를 추출할 수 있는 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileFormat {
+    PlainText,
+    Html,
+    Markdown,
+    Pdf,
+    Epub,
+}
+
+impl FileFormat {
+    // MIME 타입으로 형식을 결정 ("text/html; charset=utf-8"과 같은 매개변수는 무시)
+    fn from_mime_type(mime_type: &str) -> Result<Self> {
+        let essence = mime_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        Ok(match essence.as_str() {
+            "text/plain" | "text/csv" => FileFormat::PlainText,
+            "text/markdown" => FileFormat::Markdown,
+            "text/html" | "application/xhtml+xml" => FileFormat::Html,
+            "application/pdf" => FileFormat::Pdf,
+            "application/epub+zip" => FileFormat::Epub,
+            _ => return Err(anyhow!("Unsupported mime type '{}'", mime_type)),
+        })
+    }
+
+    // MIME 타입을 지정하지 않은 경우 확장자로 형식을 결정
+    fn from_extension(path: &Path) -> Result<Self> {
+        let mime_type = mime_type_from_extension(path).ok_or_else(|| {
+            anyhow!(
+                "Cannot detect the file type of '{}'; pass mime_type",
+                path.display()
+            )
+        })?;
+        FileFormat::from_mime_type(mime_type)
+    }
+}
+
+// 확장자로 추출을 지원하는 파일의 MIME 타입을 결정 (지원하지 않는 확장자는 None)
+pub(crate) fn mime_type_from_extension(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "txt" | "text" | "log" => "text/plain",
+        "csv" => "text/csv",
+        "md" | "markdown" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "xhtml" => "application/xhtml+xml",
+        "pdf" => "application/pdf",
+        "epub" => "application/epub+zip",
+        _ => return None,
+    })
+}
+
+// 파일에서 색인할 본문 텍스트를 추출
+// PDF, EPUB은 각각 pdf, epub cargo feature를 활성화한 경우에만 지원합니다.
+pub(crate) fn extract_file_text(path: &Path, mime_type: Option<&str>) -> Result<String> {
+    let format = match mime_type {
+        Some(mime_type) => FileFormat::from_mime_type(mime_type)?,
+        None => FileFormat::from_extension(path)?,
+    };
+    match format {
+        FileFormat::PlainText => read_text(path),
+        FileFormat::Html => Ok(strip_html(&read_text(path)?)),
+        FileFormat::Markdown => Ok(strip_markdown(&read_text(path)?)),
+        FileFormat::Pdf => pdf_text(path),
+        FileFormat::Epub => epub_text(path),
+    }
+}
+
+// UTF-8이 아닌 바이트는 대체 문자로 바꾸어 읽음
+fn read_text(path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(feature = "pdf")]
+fn pdf_text(path: &Path) -> Result<String> {
+    pdf_extract::extract_text(path)
+        .map(|text| text.trim().to_string())
+        .map_err(|error| anyhow!("Cannot extract text from '{}': {}", path.display(), error))
+}
+
+#[cfg(not(feature = "pdf"))]
+fn pdf_text(_: &Path) -> Result<String> {
+    Err(anyhow!("PDF extraction requires the 'pdf' cargo feature"))
+}
+
+// EPUB은 XHTML 문서를 묶은 zip 파일이므로, 목차(spine) 순서대로 각 문서의 태그를 제거하여 이어 붙임
+#[cfg(feature = "epub")]
+fn epub_text(path: &Path) -> Result<String> {
+    use std::io::Read;
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path)?)
+        .map_err(|error| anyhow!("'{}' is not a valid EPUB file: {}", path.display(), error))?;
+    let mut read_entry = |name: &str| -> Result<String> {
+        let mut entry = archive
+            .by_name(name)
+            .map_err(|_| anyhow!("'{}' is missing from the EPUB file", name))?;
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    };
+
+    // META-INF/container.xml이 가리키는 패키지 문서(OPF)에 문서 목록과 읽는 순서가 있음
+    let container = read_entry("META-INF/container.xml")?;
+    let package_path = xml_tags(&container, "rootfile")
+        .find_map(|tag| xml_attribute(tag, "full-path"))
+        .ok_or_else(|| anyhow!("EPUB container has no package document"))?;
+    let package = read_entry(&package_path)?;
+    let base = match package_path.rfind('/') {
+        Some(i) => &package_path[..=i],
+        None => "",
+    };
+
+    let manifest: Vec<(String, String)> = xml_tags(&package, "item")
+        .filter_map(|tag| Some((xml_attribute(tag, "id")?, xml_attribute(tag, "href")?)))
+        .collect();
+    let mut texts = Vec::new();
+    for idref in xml_tags(&package, "itemref").filter_map(|tag| xml_attribute(tag, "idref")) {
+        let Some((_, href)) = manifest.iter().find(|(id, _)| *id == idref) else {
+            continue;
+        };
+        let href = href.split('#').next().unwrap_or_default();
+        let text = strip_html(&read_entry(&format!("{}{}", base, href))?);
+        if !text.is_empty() {
+            texts.push(text);
+        }
+    }
+    Ok(texts.join("\n"))
+}
+
+#[cfg(not(feature = "epub"))]
+fn epub_text(_: &Path) -> Result<String> {
+    Err(anyhow!("EPUB extraction requires the 'epub' cargo feature"))
+}
+
+// XML 문서에서 이름이 name인 시작 태그들 (네임스페이스 접두어 포함, 예: "<opf:item ...>")
+#[cfg(feature = "epub")]
+fn xml_tags<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    xml.split('<').filter_map(move |part| {
+        let tag = &part[..part.find('>')?];
+        let tag_name = tag.split(|c: char| c.is_whitespace() || c == '/').next()?;
+        let local_name = tag_name.rsplit(':').next()?;
+        (local_name == name).then_some(tag)
+    })
+}
+
+// 태그에서 속성 값을 찾아 엔티티를 디코딩
+#[cfg(feature = "epub")]
+fn xml_attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(i) = rest.find(name) {
+        let before = rest[..i].chars().next_back();
+        let after = rest[i + name.len()..].trim_start();
+        rest = &rest[i + name.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+        let value = &value[1..];
+        let end = value.find(quote)?;
+        return Some(decode_entities(&value[..end]));
+    }
+    None
+}
+
+// 줄바꿈으로 바꾸는 블록 요소 (문단 경계가 문장 끝으로 취급되도록 함)
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "title",
+    "tr",
+    "ul",
+];
+
+// 내용까지 제거하는 요소
+const SKIPPED_TAGS: &[&str] = &["script", "style", "template", "noscript"];
+
+// HTML 태그를 제거하고 엔티티를 디코딩하여 본문 텍스트만 남김
+// 예: "<p>Tom &amp; <b>Jerry</b></p><p>2</p>" -> "Tom & Jerry\n2"
+// 연속된 공백은 하나로 줄이고, 블록 요소의 경계는 줄바꿈으로 바꿉니다.
+pub(crate) fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    strip_html_into(html, &mut text, None);
+    text
+}
+
+// strip_html과 같은 결과를 text에 쓰고, text의 바이트마다 원본 HTML에서의 (시작, 끝) 바이트 위치를 sources에 기록
+pub(crate) fn strip_html_with_sources(
+    html: &str,
+    text: &mut String,
+    sources: &mut Vec<(usize, usize)>,
+) {
+    strip_html_into(html, text, Some(sources));
+}
+
+fn strip_html_into(html: &str, text: &mut String, mut sources: Option<&mut Vec<(usize, usize)>>) {
+    text.clear();
+    if let Some(sources) = sources.as_deref_mut() {
+        sources.clear();
+    }
+    let mut output = StrippedText {
+        text,
+        sources,
+        pending: None,
+    };
+
+    let mut pos = 0;
+    while let Some(offset) = html[pos..].find('<') {
+        let start = pos + offset;
+        output.push_text(html, pos, start, true);
+        let rest = &html[start..];
+
+        // 주석, <!DOCTYPE>, <?xml ?>은 건너뛰고 CDATA는 내용을 텍스트로 유지
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            pos = comment
+                .find("-->")
+                .map_or(html.len(), |end| start + 4 + end + 3);
+            continue;
+        }
+        if rest.starts_with("<![CDATA[") {
+            let content = start + 9;
+            let end = html[content..]
+                .find("]]>")
+                .map_or(html.len(), |end| content + end);
+            output.push_text(html, content, end, false);
+            pos = (end + 3).min(html.len());
+            continue;
+        }
+
+        let Some(end) = tag_end(rest) else {
+            // 닫히지 않은 '<'는 태그가 아닌 텍스트로 취급
+            output.push('<', start, start + 1);
+            pos = start + 1;
+            continue;
+        };
+        let tag = &rest[1..end];
+        pos = start + end + 1;
+
+        let name = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+        if !tag.starts_with('/') && !tag.ends_with('/') && SKIPPED_TAGS.contains(&name.as_str()) {
+            let closing = format!("</{}", name);
+            pos = find_ignore_ascii_case(&html[pos..], &closing)
+                .and_then(|i| html[pos + i..].find('>').map(|end| pos + i + end + 1))
+                .unwrap_or(html.len());
+            continue;
+        }
+        if BLOCK_TAGS.contains(&name.as_str()) {
+            output.push('\n', start, pos);
+        }
+    }
+    output.push_text(html, pos, html.len(), true);
+}
+
+// strip_html의 결과를 만드는 버퍼 (공백 정리와 원본 위치 기록)
+struct StrippedText<'a> {
+    text: &'a mut String,
+    sources: Option<&'a mut Vec<(usize, usize)>>,
+    // 다음 글자 앞에 넣을 공백 (연속된 공백은 하나로, 줄바꿈이 포함되면 줄바꿈으로)
+    pending: Option<(char, usize, usize)>,
+}
+
+impl StrippedText<'_> {
+    // 원본 html[start..end]의 글자를 추가 (decode가 true면 엔티티를 디코딩)
+    fn push_text(&mut self, html: &str, start: usize, end: usize, decode: bool) {
+        let mut i = start;
+        while let Some(c) = html[i..end].chars().next() {
+            if decode && c == '&' {
+                if let Some((decoded, len)) = entity_at(&html[i..end]) {
+                    self.push(decoded, i, i + len);
+                    i += len;
+                    continue;
+                }
+            }
+            self.push(c, i, i + c.len_utf8());
+            i += c.len_utf8();
+        }
+    }
+
+    fn push(&mut self, c: char, start: usize, end: usize) {
+        if c.is_whitespace() {
+            if c == '\n' || self.pending.is_none() {
+                self.pending = Some((if c == '\n' { '\n' } else { ' ' }, start, end));
+            }
+            return;
+        }
+        if let Some((space, space_start, space_end)) = self.pending.take() {
+            if !self.text.is_empty() {
+                self.push_char(space, space_start, space_end);
+            }
+        }
+        self.push_char(c, start, end);
+    }
+
+    fn push_char(&mut self, c: char, start: usize, end: usize) {
+        self.text.push(c);
+        if let Some(sources) = self.sources.as_deref_mut() {
+            sources.extend(std::iter::repeat_n((start, end), c.len_utf8()));
+        }
+    }
+}
+
+// 따옴표 안의 '>'는 속성 값으로 보고 태그를 닫는 '>'의 위치를 찾음
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            // 태그 이름 없이 '<' 뒤에 공백이 오면 태그가 아님 (예: "a < b")
+            (None, _)
+                if i == 1 && !(c.is_ascii_alphabetic() || c == '/' || c == '!' || c == '?') =>
+            {
+                return None
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+// text가 '&'로 시작하는 엔티티이면 (디코딩한 글자, 엔티티 길이)
+fn entity_at(text: &str) -> Option<(char, usize)> {
+    let end = text[1..].find(';').filter(|&end| end <= 32)?;
+    Some((decode_entity(&text[1..end + 1])?, end + 2))
+}
+
+// 엔티티를 디코딩 (알 수 없는 엔티티는 그대로 유지)
+#[cfg(feature = "epub")]
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut i = 0;
+    while let Some(c) = text[i..].chars().next() {
+        if let Some((entity, len)) = (c == '&').then(|| entity_at(&text[i..])).flatten() {
+            decoded.push(entity);
+            i += len;
+        } else {
+            decoded.push(c);
+            i += c.len_utf8();
+        }
+    }
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "middot" => '·',
+        "bull" => '•',
+        _ => return None,
+    })
+}
+
+// 마크다운 문서의 제목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Heading {
+    pub(crate) level: u32,
+    // 마크다운 문법을 제거한 제목
+    pub(crate) title: String,
+    // 제목이 있는 줄 번호 (1부터 시작)
+    pub(crate) line: usize,
+}
+
+// 마크다운 문법을 제거하여 본문 텍스트만 남김
+// 예: "# Title\n- See [docs](https://example.com) **now**" -> "Title\nSee docs now"
+// 코드 블록과 인라인 코드의 내용은 유지하고, 링크/이미지 주소와 HTML 태그는 제거합니다.
+pub(crate) fn strip_markdown(markdown: &str) -> String {
+    let mut text = String::with_capacity(markdown.len());
+    strip_markdown_into(markdown, &mut text, None, None);
+    text
+}
+
+// strip_markdown과 같은 결과를 text에 쓰고, text의 바이트마다 원본에서의 (시작, 끝) 바이트 위치를 sources에 기록
+pub(crate) fn strip_markdown_with_sources(
+    markdown: &str,
+    text: &mut String,
+    sources: &mut Vec<(usize, usize)>,
+) {
+    strip_markdown_into(markdown, text, Some(sources), None);
+}
+
+// 문서의 제목 목록 (# 제목과 ===, --- 밑줄 제목, 코드 블록 안의 줄은 제외)
+pub(crate) fn markdown_headings(markdown: &str) -> Vec<Heading> {
+    let mut text = String::new();
+    let mut headings = Vec::new();
+    strip_markdown_into(markdown, &mut text, None, Some(&mut headings));
+    headings
+}
+
+fn strip_markdown_into(
+    markdown: &str,
+    text: &mut String,
+    mut sources: Option<&mut Vec<(usize, usize)>>,
+    mut headings: Option<&mut Vec<Heading>>,
+) {
+    text.clear();
+    if let Some(sources) = sources.as_deref_mut() {
+        sources.clear();
+    }
+    let mut output = StrippedText {
+        text,
+        sources,
+        pending: None,
+    };
+
+    // 열려 있는 코드 블록의 울타리 (예: "```", "~~~~")
+    let mut fence: Option<&str> = None;
+    // 직전 줄이 문단이면 (본문이 시작된 text 위치, 줄 번호), 다음 줄이 ===, ---이면 제목
+    let mut paragraph: Option<(usize, usize)> = None;
+    let mut line_start = 0;
+    for (i, line) in markdown.split_inclusive('\n').enumerate() {
+        let start = line_start;
+        line_start += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let end = start + content.len();
+        let trimmed = content.trim_start();
+        let previous = paragraph.take();
+        let mut pos = end - trimmed.len();
+
+        if let Some(marker) = fence {
+            if is_closing_fence(trimmed, marker) {
+                fence = None;
+            } else {
+                output.push_text(markdown, start, end, false);
+            }
+        } else if let Some(marker) = opening_fence(trimmed) {
+            fence = Some(marker);
+        } else if trimmed.is_empty() {
+            // 빈 줄은 문단을 끝냄
+        } else if let (Some((title_start, line)), Some(level)) = (previous, setext_level(trimmed)) {
+            if let Some(headings) = headings.as_deref_mut() {
+                headings.push(Heading {
+                    level,
+                    title: output.text[title_start..].trim().to_string(),
+                    line,
+                });
+            }
+        } else if is_thematic_break(trimmed) || is_link_definition(trimmed) {
+            // 구분선과 참조 링크 정의는 본문이 아님
+        } else {
+            // 인용 '>'와 목록 기호, 체크박스를 제거
+            loop {
+                let rest = &markdown[pos..end];
+                if let Some(quoted) = rest.strip_prefix('>') {
+                    pos = end - quoted.trim_start().len();
+                } else if let Some(len) = list_marker_len(rest) {
+                    pos += len;
+                } else {
+                    break;
+                }
+            }
+            let rest = &markdown[pos..end];
+            if ["[ ] ", "[x] ", "[X] "]
+                .iter()
+                .any(|box_| rest.starts_with(box_))
+            {
+                pos += 4;
+            }
+
+            let level = atx_level(&markdown[pos..end]);
+            let mut body_end = end;
+            if let Some(level) = level {
+                pos += level as usize;
+                // 닫는 '#'도 제거 (예: "## Title ##")
+                let body = markdown[pos..end].trim_end();
+                let without_hashes = body.trim_end_matches('#');
+                if without_hashes.is_empty() || without_hashes.ends_with([' ', '\t']) {
+                    body_end = pos + without_hashes.len();
+                }
+            }
+
+            let title_start = output.text.len();
+            push_markdown_inline(markdown, pos, body_end, &mut output);
+            match level {
+                Some(level) => {
+                    if let Some(headings) = headings.as_deref_mut() {
+                        headings.push(Heading {
+                            level,
+                            title: output.text[title_start..].trim().to_string(),
+                            line: i + 1,
+                        });
+                    }
+                }
+                None => paragraph = Some((title_start, i + 1)),
+            }
+        }
+        output.push('\n', end, line_start);
+    }
+}
+
+// 코드 블록을 여는 울타리 ("```" 또는 "~~~" 이상, 백틱 울타리의 정보 문자열에는 백틱이 없어야 함)
+fn opening_fence(line: &str) -> Option<&str> {
+    let c = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let len = line.len() - line.trim_start_matches(c).len();
+    if len < 3 || (c == '`' && line[len..].contains('`')) {
+        return None;
+    }
+    Some(&line[..len])
+}
+
+// 여는 울타리와 같은 문자로 같은 길이 이상이면 닫는 울타리
+fn is_closing_fence(line: &str, marker: &str) -> bool {
+    let c = marker.chars().next().unwrap_or('`');
+    let rest = line.trim_start_matches(c);
+    line.len() - rest.len() >= marker.len() && rest.trim().is_empty()
+}
+
+// "# 제목" ~ "###### 제목"의 수준
+fn atx_level(line: &str) -> Option<u32> {
+    let level = line.len() - line.trim_start_matches('#').len();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with([' ', '\t'])))
+        .then_some(level as u32)
+}
+
+// 문단 아래의 "===" 줄은 1수준, "---" 줄은 2수준 제목
+fn setext_level(line: &str) -> Option<u32> {
+    let line = line.trim_end();
+    if !line.is_empty() && line.chars().all(|c| c == '=') {
+        Some(1)
+    } else if !line.is_empty() && line.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+// "---", "***", "___" (사이의 공백 허용)
+fn is_thematic_break(line: &str) -> bool {
+    let marks: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&c| marks.iter().all(|&m| m == c))
+}
+
+// 참조 링크 정의 "[id]: https://..." (각주 "[^1]: 내용"은 본문으로 유지)
+fn is_link_definition(line: &str) -> bool {
+    line.starts_with('[') && !line.starts_with("[^") && line.contains("]:")
+}
+
+// 목록 기호 ("- ", "* ", "+ ", "1. ", "1) ")와 뒤의 공백 길이
+fn list_marker_len(line: &str) -> Option<usize> {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let marker = match digits {
+        0 if line.starts_with(['-', '*', '+']) => 1,
+        1..=9 if line[digits..].starts_with(['.', ')']) => digits + 1,
+        _ => return None,
+    };
+    let rest = &line[marker..];
+    let spaces = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    (spaces > 0 || rest.is_empty()).then_some(marker + spaces)
+}
+
+// 한 줄 안의 강조, 코드, 링크, 이미지, HTML 태그 문법을 제거
+fn push_markdown_inline(markdown: &str, start: usize, end: usize, output: &mut StrippedText) {
+    let mut i = start;
+    while let Some(c) = markdown[i..end].chars().next() {
+        let rest = &markdown[i..end];
+        match c {
+            // 이스케이프된 구두점은 문자 그대로 사용
+            '\\' => {
+                if let Some(next) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                    output.push(next, i, i + 2);
+                    i += 2;
+                    continue;
+                }
+            }
+            // 인라인 코드는 내용을 그대로 유지
+            '`' => {
+                let ticks = rest.len() - rest.trim_start_matches('`').len();
+                let code = &rest[ticks..];
+                // 문자 경계에서만 비교해야 한글 같은 멀티바이트 문자에서 panic하지 않음
+                let close = code.char_indices().map(|(j, _)| j).find(|&j| {
+                    code[j..].starts_with(&rest[..ticks])
+                        && !code[j + ticks..].starts_with('`')
+                        && (j == 0 || !code[..j].ends_with('`'))
+                });
+                if let Some(close) = close {
+                    output.push_text(markdown, i + ticks, i + ticks + close, false);
+                    i += ticks + close + ticks;
+                } else {
+                    i += ticks;
+                }
+                continue;
+            }
+            // 이미지는 대체 텍스트만 남김
+            '!' if rest[1..].starts_with('[') => {
+                i += 1;
+                continue;
+            }
+            // [텍스트](주소), [텍스트][참조], [텍스트]는 텍스트만 남김
+            '[' => {
+                if let Some(close) = matching_bracket(rest, '[', ']') {
+                    let after = &rest[close + 1..];
+                    let skipped = if after.starts_with('(') {
+                        matching_bracket(after, '(', ')').map_or(0, |paren| paren + 1)
+                    } else if after.starts_with('[') {
+                        after.find(']').map_or(0, |bracket| bracket + 1)
+                    } else {
+                        0
+                    };
+                    push_markdown_inline(markdown, i + 1, i + close, output);
+                    i += close + 1 + skipped;
+                    continue;
+                }
+            }
+            // <https://...> 자동 링크는 주소를 유지하고, HTML 태그는 제거
+            '<' => {
+                if let Some(close) = tag_end(rest) {
+                    let inner = &rest[1..close];
+                    if (inner.contains("://") || inner.starts_with("mailto:"))
+                        && !inner.contains(char::is_whitespace)
+                    {
+                        output.push_text(markdown, i + 1, i + close, false);
+                    }
+                    i += close + 1;
+                    continue;
+                }
+            }
+            '*' => {
+                i += 1;
+                continue;
+            }
+            '~' if rest.starts_with("~~") => {
+                i += 2;
+                continue;
+            }
+            // 단어 안의 '_'는 강조가 아님 (예: snake_case)
+            '_' => {
+                let previous = markdown[..i].chars().next_back();
+                let next = rest[1..].chars().next();
+                if !(previous.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric))
+                {
+                    i += 1;
+                    continue;
+                }
+            }
+            '&' => {
+                if let Some((decoded, len)) = entity_at(rest) {
+                    output.push(decoded, i, i + len);
+                    i += len;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        output.push(c, i, i + c.len_utf8());
+        i += c.len_utf8();
+    }
+}
+
+// text가 open으로 시작할 때 짝이 맞는 close의 위치 (백슬래시로 이스케이프된 괄호는 무시)
+fn matching_bracket(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+// GitHub과 같은 방식으로 제목의 앵커를 만듦 (소문자, 공백은 '-', 구두점 제거, 중복되면 뒤에 -1, -2, ...)
+pub(crate) fn heading_anchors(headings: &[Heading]) -> Vec<String> {
+    let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    headings
+        .iter()
+        .map(|heading| {
+            let mut anchor = String::new();
+            for c in heading.title.chars() {
+                if c.is_alphanumeric() || c == '-' || c == '_' {
+                    anchor.extend(c.to_lowercase());
+                } else if c == ' ' {
+                    anchor.push('-');
+                }
+            }
+            let count = seen.entry(anchor.clone()).or_insert(0);
+            let unique = match *count {
+                0 => anchor,
+                n => format!("{}-{}", anchor, n),
+            };
+            *count += 1;
+            unique
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_markdown_keeps_non_ascii_inline_code() {
+        assert_eq!(strip_markdown("`한글` 메모"), "한글 메모");
+        assert_eq!(strip_markdown("``코드 ` 안``와 `끝"), "코드 ` 안와 끝");
+        assert_eq!(markdown_headings("# 제목 `한글`")[0].title, "제목 한글");
+    }
+}